@@ -10,8 +10,16 @@
 
 use sky_floem::cosmic_text::Style;
 use sky_floem::style::TextOverflow;
-use sky_floem::views::{dyn_container, h_stack, text, v_stack, Decorators};
+use sky_floem::views::{
+	dyn_container,
+	stack_from_iter,
+	text,
+	v_stack,
+	Decorators,
+	Stack,
+};
 use sky_floem::{reactive, AnyView, IntoView, ScrollableExt, View};
+use sky_html::{HTMLDocumentParseError, HTMLEngine};
 
 use crate::state::{ApplicationStateShared, Page};
 use crate::styles::classes::align::gap::*;
@@ -39,21 +47,24 @@ impl ContentArea
 				let dyn_content = page_view.dyn_content.expect("?");
 
 				if page_view.debugging {
-					let left_content = v_stack((
-						text("Prévisualisation du rendu") // don't format please
-							.style(|style| {
-								style
-									.color(COLOR_GREY500)
-									.font_style(Style::Italic)
-							}),
-						// NOTE: Ici qu'est injecté le contenu dynamiquement
-						dyn_content
-							.scroll_x()
-							.style(|style| style.height_pct(95.0)),
-					))
-					.class(Gap16)
-					.style(|style| style.text_overflow(TextOverflow::Clip))
-					.style(|style| style.size_pct(50.0, 100.0));
+					let columns = 2 + page_view.comparisons.len();
+					let width = 100.0 / columns as f64;
+
+					let mut content = vec![Self::preview_column(
+						page_view.engine,
+						dyn_content,
+						&page_view.parse_errors,
+						width,
+					)];
+
+					for comparison in page_view.comparisons {
+						content.push(Self::preview_column(
+							comparison.engine,
+							comparison.dyn_content.expect("?"),
+							&comparison.parse_errors,
+							width,
+						));
+					}
 
 					let right_content = v_stack((
 						text("HTML (raw)") // don't format please
@@ -68,19 +79,17 @@ impl ContentArea
 					))
 					.class(Gap16)
 					.style(|style| style.text_overflow(TextOverflow::Clip))
-					.style(|style| style.size_pct(50.0, 100.0));
+					.style(move |style| style.size_pct(width, 100.0));
 
-					h_stack((
-						left_content, // don't format please
-						right_content,
-					))
+					content.push(right_content.into_any());
+
+					stack_from_iter(content).style(|style| style.flex_row())
 				} else {
 					// NOTE: Ici qu'est injecté le contenu dynamiquement
 					let left_content = dyn_content.scroll_full_size();
 
-					h_stack((
-						left_content, // don't format please
-					))
+					stack_from_iter([left_content.into_any()])
+						.style(|style| style.flex_row())
 				}
 				.class(Gap8)
 				.style(|style| style.size_full())
@@ -94,6 +103,52 @@ impl ContentArea
 		}
 	}
 
+	/// Colonne de prévisualisation du rendu d'un moteur d'analyse, suivie de
+	/// ses erreurs d'analyse.
+	fn preview_column(
+		engine: HTMLEngine,
+		dyn_content: Stack,
+		parse_errors: &[HTMLDocumentParseError],
+		width: f64,
+	) -> AnyView
+	{
+		let has_parse_errors = !parse_errors.is_empty();
+
+		let parse_errors = stack_from_iter(parse_errors.iter().map(|err| {
+			text(err) // don't format please
+				.style(|style| style.color(COLOR_RED600))
+		}))
+		.style(|style| style.flex_col());
+
+		v_stack((
+			text(format!("Prévisualisation du rendu ({engine})")).style(
+				|style| style.color(COLOR_GREY500).font_style(Style::Italic),
+			),
+			// NOTE: Ici qu'est injecté le contenu dynamiquement
+			dyn_content.scroll_x().style(move |style| {
+				style
+					.height_pct(95.0)
+					.apply_if(has_parse_errors, |s| s.height_pct(65.0))
+			}),
+			text("Erreurs d'analyse") // don't format please
+				.style(move |style| {
+					style
+						.color(COLOR_GREY500)
+						.font_style(Style::Italic)
+						.apply_if(!has_parse_errors, |s| s.hide())
+				}),
+			parse_errors.scroll_x().style(move |style| {
+				style
+					.height_pct(25.0)
+					.apply_if(!has_parse_errors, |s| s.hide())
+			}),
+		))
+		.class(Gap16)
+		.style(|style| style.text_overflow(TextOverflow::Clip))
+		.style(move |style| style.size_pct(width, 100.0))
+		.into_any()
+	}
+
 	pub fn render(&self) -> impl View
 	{
 		let state: ApplicationStateShared = reactive::use_context() /* dfplz */
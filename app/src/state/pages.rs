@@ -8,7 +8,6 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use std::collections::BTreeMap;
 use std::io::Read;
 use std::path;
 
@@ -28,7 +27,16 @@ use sky_floem::views::{
 	Stack,
 };
 use sky_floem::{AnyView, IntoView};
-use sky_html::{Attribute, HTMLDocument, HTMLElement};
+use sky_html::{
+	HTMLAttribute,
+	HTMLDocument,
+	HTMLDocumentParseError,
+	HTMLEncoding,
+	HTMLEngine,
+	HTMLNodeData,
+	HTMLNodeId,
+	HTMLTree,
+};
 
 use crate::state::ApplicationStateShared;
 
@@ -49,6 +57,14 @@ pub struct PageView
 	pub new_title: String,
 	pub dyn_content: Option<Stack>,
 	pub debugging: bool,
+	/// Erreurs d'analyse récupérables du document, affichées en mode
+	/// débogage.
+	pub parse_errors: Vec<HTMLDocumentParseError>,
+	/// Moteur d'analyse ayant construit le document.
+	pub engine: HTMLEngine,
+	/// Rendus de la même page par les autres moteurs d'analyse, affichés
+	/// côte à côte en mode débogage.
+	pub comparisons: Vec<PageView>,
 }
 
 // ----------- //
@@ -80,8 +96,6 @@ pub enum PageError
 	InvalidReqHeader(#[from] reqwest::header::InvalidHeaderValue),
 	#[error("Impossible d'analyser l'HTML: {0}")]
 	ParseHTML(#[from] sky_html::HTMLParserError),
-	#[error("Impossible de convertir en UTF-8: {0}")]
-	Utf8(#[from] std::str::Utf8Error),
 }
 
 // -------------- //
@@ -143,16 +157,30 @@ impl Page
 
 			let mut buf = Vec::new();
 			file.read_to_end(&mut buf)?;
-			let raw_content = std::str::from_utf8(&buf)?;
+			let raw_content = HTMLEncoding::sniff(&buf, None).decode(&buf);
+
+			let engine = HTMLEngine::default();
 
-			let doc = HTMLDocument::from_slice(&mut buf.as_slice())?;
-			let mut page_view = self.build_page_view(&doc.elements)?;
+			let doc = HTMLDocument::from_slice(&mut buf.as_slice(), engine)?;
+			let mut page_view = self.build_page_view(&doc)?;
 			page_view.raw_content = raw_content.to_string();
 			page_view.debugging = true;
+
+			for other_engine in HTMLEngine::ALL {
+				if *other_engine == engine {
+					continue;
+				}
+				let doc = HTMLDocument::from_slice(
+					&mut buf.as_slice(),
+					*other_engine,
+				)?;
+				page_view.comparisons.push(self.build_page_view(&doc)?);
+			}
+
 			page_view
 		} else {
-			let doc = HTMLDocument::from_file(filepath)?;
-			self.build_page_view(&doc.elements)?
+			let doc = HTMLDocument::from_file(filepath, HTMLEngine::default())?;
+			self.build_page_view(&doc)?
 		};
 
 		Ok(page_view)
@@ -182,7 +210,8 @@ impl Page
 			.and_then(|mut response| {
 				let content_type =
 					response.headers().get("content-type").unwrap();
-				let content_type_str = content_type.to_str().unwrap();
+				let content_type_str =
+					content_type.to_str().unwrap().to_owned();
 
 				let status = response.status();
 
@@ -191,8 +220,12 @@ impl Page
 				}
 
 				if content_type_str.contains("text/html") {
-					let doc = HTMLDocument::from_stream(&mut response)?;
-					let page_view = self.build_page_view(&doc.elements)?;
+					let doc = HTMLDocument::from_stream_with_content_type(
+						&mut response,
+						Some(&content_type_str),
+						HTMLEngine::default(),
+					)?;
+					let page_view = self.build_page_view(&doc)?;
 					return Ok(page_view);
 				}
 
@@ -204,6 +237,9 @@ impl Page
 						new_title: Default::default(),
 						raw_content: Default::default(),
 						debugging: false,
+						parse_errors: Default::default(),
+						engine: Default::default(),
+						comparisons: Default::default(),
 					};
 					return Ok(page_view);
 				}
@@ -230,123 +266,150 @@ impl Page
 
 impl Page
 {
-	// TODO: a améliorer
-	fn build_page_view(
-		&self,
-		tree: &BTreeMap<usize, HTMLElement>,
-	) -> Result<PageView, PageError>
+	fn build_page_view(&self, doc: &HTMLDocument)
+		-> Result<PageView, PageError>
 	{
-		let mut list = vec![];
+		let tree = doc.tree();
 
-		let mut temp_page_view = PageView {
+		let list = tree
+			.children(tree.document())
+			.filter_map(|node_id| Self::build_node(tree, node_id));
+
+		let page_view = PageView {
 			raw_content: Default::default(),
-			new_title: Default::default(),
-			dyn_content: Default::default(),
+			new_title: doc
+				.title()
+				.map(|title| title.trim().to_string())
+				.unwrap_or_default(),
+			dyn_content: Some(
+				stack_from_iter(list).style(|style| style.flex_col()),
+			),
 			debugging: Default::default(),
+			parse_errors: doc.errors().to_vec(),
+			engine: doc.engine(),
+			comparisons: Default::default(),
 		};
 
-		let mut make_element =
-			|el_name: &str, attrs: &[Attribute], maybe_text: Option<&str>| {
-				match el_name {
-					| "title" => {
-						if let Some(t) = maybe_text {
-							temp_page_view.new_title = t.trim().to_string();
-						}
-						None
-					}
+		Ok(page_view)
+	}
 
-					| "button" => {
-						Self::make_button_element(
-							maybe_text.map(|s| s.to_string()),
-						)
-					}
+	/// Construit la vue d'un nœud et de ses descendants, dans l'ordre du
+	/// document.
+	fn build_node(tree: &HTMLTree, node_id: HTMLNodeId) -> Option<AnyView>
+	{
+		match tree[node_id].data() {
+			| HTMLNodeData::Text(t) => {
+				let t = t.trim();
+				(!t.is_empty()).then(|| text(t).into_any())
+			}
+			| HTMLNodeData::Element {
+				name, attributes, ..
+			} => Self::build_element(tree, node_id, name, attributes),
+			| _ => None,
+		}
+	}
 
-					| "h1" => Self::make_heading(maybe_text, 34.0),
-					| "h2" => Self::make_heading(maybe_text, 30.0),
-					| "h3" => Self::make_heading(maybe_text, 26.0),
-					| "h4" => Self::make_heading(maybe_text, 22.0),
-					| "h5" => Self::make_heading(maybe_text, 20.0),
-					| "h6" => Self::make_heading(maybe_text, 18.0),
+	fn build_element(
+		tree: &HTMLTree,
+		node_id: HTMLNodeId,
+		el_name: &str,
+		attrs: &[HTMLAttribute],
+	) -> Option<AnyView>
+	{
+		let text_content = tree.text_content(node_id);
+		let maybe_text = Some(text_content.as_str());
 
-					| "strong" | "b" => Self::make_bold_element(maybe_text),
-					| "em" | "i" => Self::make_italic_element(maybe_text),
+		match el_name {
+			| "head" | "meta" | "link" | "script" | "style" | "template"
+			| "title" => None,
 
-					| "a" => Self::make_anchor_element(maybe_text, attrs),
+			| "button" => Self::make_button_element(Some(text_content)),
 
-					| name => {
-						if let Some(t) = maybe_text {
-							let t = t.trim();
-							if !t.is_empty() {
-								return Some(text(t).into_any());
-							}
-						}
+			| "h1" => Self::make_heading(maybe_text, 34.0),
+			| "h2" => Self::make_heading(maybe_text, 30.0),
+			| "h3" => Self::make_heading(maybe_text, 26.0),
+			| "h4" => Self::make_heading(maybe_text, 22.0),
+			| "h5" => Self::make_heading(maybe_text, 20.0),
+			| "h6" => Self::make_heading(maybe_text, 18.0),
 
-						let warning = format!("Élément « {name} » non rendu");
-
-						println!("WARN: {} / {attrs:?}", &warning);
-
-						Some(
-							text(warning)
-								.style(|style| {
-									style
-										.padding(4)
-										.background(Color::DARK_RED)
-										.color(Color::WHITE)
-										.border(1)
-										.border_radius(2.0)
-										.border_color(Color::RED)
-										.font_style(Style::Italic)
-								})
-								.into_any(),
-						)
-					}
-				}
-			};
+			| "strong" | "b" => Self::make_bold_element(maybe_text),
+			| "em" | "i" => Self::make_italic_element(maybe_text),
 
-		for (&element_id, element) in tree {
-			let parent_id = element.parent;
+			| "a" => Self::make_anchor_element(maybe_text, attrs),
 
-			let element_name = element.name.local.to_string();
+			| "p" | "span" | "li" | "label" => {
+				Self::build_children(tree, node_id, true)
+			}
 
-			if ["html", "body", "head", "meta", "link", "script", "style"]
-				.contains(&element_name.as_str())
-			{
-				continue;
+			| name => {
+				Self::build_children(tree, node_id, false)
+					.or_else(|| Self::make_unrendered_element(name, attrs))
 			}
+		}
+	}
 
-			if element_id > parent_id && parent_id != 0 {
-				// Traiter le parent?
-				//let parent = tree.get(&parent_id).unwrap();
+	/// Construit la vue des enfants d'un élément, empilés en ligne ou en
+	/// colonne.
+	fn build_children(
+		tree: &HTMLTree,
+		node_id: HTMLNodeId,
+		inline: bool,
+	) -> Option<AnyView>
+	{
+		let list = tree
+			.children(node_id)
+			.filter_map(|child_id| Self::build_node(tree, child_id))
+			.collect::<Vec<_>>();
 
-				let maybe_floem_element = make_element(
-					&element_name,
-					&element.attributes,
-					element.text.as_ref().map(|t| t.as_ref()),
-				);
+		if list.is_empty() {
+			return None;
+		}
 
-				if let Some(floem_element) = maybe_floem_element {
-					list.push(floem_element);
-				}
+		let stack = stack_from_iter(list).style(move |style| {
+			if inline {
+				style.flex_row().gap(4, 0)
 			} else {
-				dbg!(&element);
+				style.flex_col()
 			}
-		}
+		});
 
-		temp_page_view
-			.dyn_content
-			.replace(stack_from_iter(list).style(|style| style.flex_col()));
+		Some(stack.into_any())
+	}
 
-		Ok(temp_page_view)
+	fn make_unrendered_element(
+		name: &str,
+		attrs: &[HTMLAttribute],
+	) -> Option<AnyView>
+	{
+		let warning = format!("Élément « {name} » non rendu");
+
+		println!("WARN: {} / {attrs:?}", &warning);
+
+		Some(
+			text(warning)
+				.style(|style| {
+					style
+						.padding(4)
+						.background(Color::DARK_RED)
+						.color(Color::WHITE)
+						.border(1)
+						.border_radius(2.0)
+						.border_color(Color::RED)
+						.font_style(Style::Italic)
+				})
+				.into_any(),
+		)
 	}
 
 	fn make_anchor_element(
 		maybe_text: Option<&str>,
-		attrs: &[Attribute],
+		attrs: &[HTMLAttribute],
 	) -> Option<AnyView>
 	{
 		maybe_text.filter(|s| !s.trim().is_empty()).map(move |s| {
 			let href = attrs.iter().find_map(|attr| {
-				attr.name.local.eq("href").then_some(attr.value.to_string())
+				(attr.namespace.is_none() && attr.name == "href")
+					.then(|| attr.value.clone())
 			});
 
 			let mut element = text(s.trim())
@@ -0,0 +1,126 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::{fmt, io, str};
+
+#[cfg(feature = "html5ever")]
+use super::HTML5everBackend;
+use super::NativeBackend;
+use crate::{
+	HTMLDocument,
+	HTMLDocumentFragment,
+	HTMLFragmentContext,
+	HTMLParserError,
+};
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Interface commune de construction d'un document, implémentée par chaque
+/// moteur d'analyse.
+pub trait HTMLDocumentBackend
+{
+	/// Analyse le flux (encodé en UTF-8) et construit le document.
+	fn parse_document(
+		&self,
+		stream: &mut dyn io::Read,
+	) -> Result<HTMLDocument, HTMLParserError>;
+
+	/// Analyse le flux (encodé en UTF-8) comme un fragment, dans le contexte
+	/// de l'élément donné.
+	fn parse_fragment(
+		&self,
+		context: &HTMLFragmentContext,
+		stream: &mut dyn io::Read,
+	) -> Result<HTMLDocumentFragment, HTMLParserError>;
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Moteur d'analyse utilisé pour construire un document.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(Default)]
+#[derive(PartialEq, Eq)]
+pub enum HTMLEngine
+{
+	/// Analyseur html5ever (fonctionnalité cargo `html5ever`), utilisé par
+	/// défaut lorsqu'il est disponible.
+	#[cfg(feature = "html5ever")]
+	#[default]
+	Html5ever,
+	/// Analyseur natif: [HTMLTokenizer](crate::HTMLTokenizer) et
+	/// [HTMLParser](crate::HTMLParser).
+	#[cfg_attr(not(feature = "html5ever"), default)]
+	Native,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl HTMLEngine
+{
+	/// Moteurs disponibles, selon les fonctionnalités cargo activées.
+	pub const ALL: &'static [Self] = &[
+		#[cfg(feature = "html5ever")]
+		Self::Html5ever,
+		Self::Native,
+	];
+
+	/// Implémentation du moteur.
+	pub fn backend(self) -> &'static dyn HTMLDocumentBackend
+	{
+		match self {
+			#[cfg(feature = "html5ever")]
+			| Self::Html5ever => &HTML5everBackend,
+			| Self::Native => &NativeBackend,
+		}
+	}
+
+	/// Nom du moteur, ex: `native`.
+	pub const fn name(self) -> &'static str
+	{
+		match self {
+			#[cfg(feature = "html5ever")]
+			| Self::Html5ever => "html5ever",
+			| Self::Native => "native",
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for HTMLEngine
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}", self.name())
+	}
+}
+
+impl str::FromStr for HTMLEngine
+{
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|engine| engine.name().eq_ignore_ascii_case(s))
+			.ok_or_else(|| format!("moteur d'analyse inconnu: « {s} »"))
+	}
+}
@@ -0,0 +1,401 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::io;
+
+use html5ever::interface::*;
+use html5ever::tendril::*;
+use html5ever::*;
+
+use super::{HTMLDocumentBackend, HTMLEngine};
+use crate::{
+	HTMLAttribute,
+	HTMLDocument,
+	HTMLDocumentFragment,
+	HTMLDocumentParseError,
+	HTMLFragmentContext,
+	HTMLNamespace,
+	HTMLNodeId,
+	HTMLParserError,
+	HTMLQuirksMode,
+	HTMLTree,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Moteur d'analyse html5ever.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+pub struct HTML5everBackend;
+
+/// Construit l'arbre du document à partir des appels de html5ever.
+struct HTML5everSink
+{
+	tree: HTMLTree,
+	/// Erreurs d'analyse récupérables, dans l'ordre d'apparition.
+	errors: Vec<HTMLDocumentParseError>,
+	/// Noms qualifiés des éléments, requis par [TreeSink::elem_name].
+	names: HashMap<HTMLNodeId, QualName>,
+	/// Éléments `<annotation-xml>` qui sont des points d'intégration HTML.
+	annotation_xml_integration_points: HashSet<HTMLNodeId>,
+	/// Ligne du jeton en cours de traitement.
+	current_line: u64,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl HTML5everSink
+{
+	fn new() -> Self
+	{
+		Self {
+			tree: HTMLTree::new(),
+			errors: Vec::new(),
+			names: HashMap::new(),
+			annotation_xml_integration_points: HashSet::new(),
+			current_line: 1,
+		}
+	}
+
+	/// Options d'analyse: les erreurs détaillées sont demandées afin de
+	/// pouvoir les afficher. Les scripts n'étant pas exécutés, le contenu
+	/// des éléments `<noscript>` est analysé, comme le fait l'analyseur
+	/// natif.
+	fn parse_opts() -> ParseOpts
+	{
+		ParseOpts {
+			tokenizer: html5ever::tokenizer::TokenizerOpts {
+				exact_errors: true,
+				..Default::default()
+			},
+			tree_builder: html5ever::tree_builder::TreeBuilderOpts {
+				exact_errors: true,
+				scripting_enabled: false,
+				..Default::default()
+			},
+		}
+	}
+
+	fn namespace(ns: &Namespace) -> Option<HTMLNamespace>
+	{
+		[
+			HTMLNamespace::Html,
+			HTMLNamespace::MathML,
+			HTMLNamespace::Svg,
+			HTMLNamespace::XLink,
+			HTMLNamespace::Xml,
+			HTMLNamespace::Xmlns,
+		]
+		.into_iter()
+		.find(|namespace| ns.as_ref() == namespace.url())
+	}
+
+	fn attribute(attribute: Attribute) -> HTMLAttribute
+	{
+		HTMLAttribute {
+			namespace: Self::namespace(&attribute.name.ns),
+			name: attribute.name.local.to_string(),
+			value: attribute.value.to_string(),
+		}
+	}
+
+	/// Nom qualifié d'un attribut, préfixé selon son espace de noms.
+	fn attribute_name(namespace: Option<HTMLNamespace>, name: &str)
+		-> QualName
+	{
+		QualName::new(
+			namespace.map(|ns| Prefix::from(ns.prefix())),
+			Namespace::from(namespace.map_or("", HTMLNamespace::url)),
+			LocalName::from(name),
+		)
+	}
+
+	fn quirks_mode(mode: HTMLQuirksMode) -> QuirksMode
+	{
+		match mode {
+			| HTMLQuirksMode::NoQuirks => QuirksMode::NoQuirks,
+			| HTMLQuirksMode::LimitedQuirks => QuirksMode::LimitedQuirks,
+			| HTMLQuirksMode::Quirks => QuirksMode::Quirks,
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl HTMLDocumentBackend for HTML5everBackend
+{
+	fn parse_document(
+		&self,
+		mut stream: &mut dyn io::Read,
+	) -> Result<HTMLDocument, HTMLParserError>
+	{
+		let document = html5ever::parse_document(
+			HTML5everSink::new(),
+			HTML5everSink::parse_opts(),
+		)
+		.from_utf8()
+		.read_from(&mut stream)?;
+
+		Ok(HTMLDocument::new(
+			document.tree,
+			document.errors,
+			HTMLEngine::Html5ever,
+		))
+	}
+
+	fn parse_fragment(
+		&self,
+		context: &HTMLFragmentContext,
+		mut stream: &mut dyn io::Read,
+	) -> Result<HTMLDocumentFragment, HTMLParserError>
+	{
+		let mut sink = HTML5everSink::new();
+		sink.tree.set_quirks_mode(context.quirks_mode);
+
+		let mut opts = HTML5everSink::parse_opts();
+		opts.tree_builder.quirks_mode =
+			HTML5everSink::quirks_mode(context.quirks_mode);
+
+		let context_name = QualName::new(
+			None,
+			Namespace::from(context.namespace.url()),
+			LocalName::from(context.name.as_str()),
+		);
+		let context_attributes = context
+			.attributes
+			.iter()
+			.map(|attribute| {
+				Attribute {
+					name: HTML5everSink::attribute_name(
+						attribute.namespace,
+						&attribute.name,
+					),
+					value: StrTendril::from(attribute.value.as_str()),
+				}
+			})
+			.collect();
+
+		let fragment = html5ever::parse_fragment(
+			sink,
+			opts,
+			context_name,
+			context_attributes,
+		)
+		.from_utf8()
+		.read_from(&mut stream)?;
+
+		Ok(HTMLDocumentFragment::new(
+			fragment.tree,
+			fragment.errors,
+			HTMLEngine::Html5ever,
+		))
+	}
+}
+
+impl TreeSink for HTML5everSink
+{
+	type Handle = HTMLNodeId;
+	type Output = Self;
+
+	fn finish(self) -> Self
+	{
+		self
+	}
+
+	fn parse_error(&mut self, message: Cow<'static, str>)
+	{
+		self.errors.push(HTMLDocumentParseError {
+			line: self.current_line,
+			message: message.into_owned(),
+		});
+	}
+
+	fn get_document(&mut self) -> HTMLNodeId
+	{
+		self.tree.document()
+	}
+
+	fn get_template_contents(&mut self, target: &HTMLNodeId) -> HTMLNodeId
+	{
+		self.tree[*target]
+			.template_contents()
+			.expect("n'est pas un élément template")
+	}
+
+	fn set_quirks_mode(&mut self, mode: QuirksMode)
+	{
+		self.tree.set_quirks_mode(match mode {
+			| QuirksMode::Quirks => HTMLQuirksMode::Quirks,
+			| QuirksMode::LimitedQuirks => HTMLQuirksMode::LimitedQuirks,
+			| QuirksMode::NoQuirks => HTMLQuirksMode::NoQuirks,
+		});
+	}
+
+	fn same_node(&self, x: &HTMLNodeId, y: &HTMLNodeId) -> bool
+	{
+		x == y
+	}
+
+	fn elem_name(&self, target: &HTMLNodeId) -> ExpandedName<'_>
+	{
+		self.names
+			.get(target)
+			.expect("n'est pas un élément")
+			.expanded()
+	}
+
+	fn create_element(
+		&mut self,
+		name: QualName,
+		attributes: Vec<Attribute>,
+		flags: ElementFlags,
+	) -> HTMLNodeId
+	{
+		let id = self.tree.create_element(
+			Self::namespace(&name.ns).unwrap_or_default(),
+			name.local.to_string(),
+			attributes.into_iter().map(Self::attribute).collect(),
+		);
+		self.names.insert(id, name);
+		if flags.mathml_annotation_xml_integration_point {
+			self.annotation_xml_integration_points.insert(id);
+		}
+		id
+	}
+
+	fn is_mathml_annotation_xml_integration_point(
+		&self,
+		handle: &HTMLNodeId,
+	) -> bool
+	{
+		self.annotation_xml_integration_points.contains(handle)
+	}
+
+	fn create_comment(&mut self, text: StrTendril) -> HTMLNodeId
+	{
+		self.tree.create_comment(text.to_string())
+	}
+
+	fn create_pi(&mut self, target: StrTendril, data: StrTendril)
+		-> HTMLNodeId
+	{
+		self.tree
+			.create_processing_instruction(target.to_string(), data.to_string())
+	}
+
+	fn append(&mut self, parent: &HTMLNodeId, child: NodeOrText<HTMLNodeId>)
+	{
+		match child {
+			| AppendNode(node) => self.tree.append(*parent, node),
+			| AppendText(text) => self.tree.append_text(*parent, &text),
+		}
+	}
+
+	fn append_before_sibling(
+		&mut self,
+		sibling: &HTMLNodeId,
+		child: NodeOrText<HTMLNodeId>,
+	)
+	{
+		match child {
+			| AppendNode(node) => self.tree.insert_before(*sibling, node),
+			| AppendText(text) => self.tree.insert_text_before(*sibling, &text),
+		}
+	}
+
+	fn append_based_on_parent_node(
+		&mut self,
+		element: &Self::Handle,
+		prev_element: &Self::Handle,
+		child: NodeOrText<Self::Handle>,
+	)
+	{
+		if self.tree.parent(*element).is_some() {
+			self.append_before_sibling(element, child);
+		} else {
+			self.append(prev_element, child);
+		}
+	}
+
+	fn append_doctype_to_document(
+		&mut self,
+		name: StrTendril,
+		public_id: StrTendril,
+		system_id: StrTendril,
+	)
+	{
+		let doctype = self.tree.create_doctype(
+			name.to_string(),
+			public_id.to_string(),
+			system_id.to_string(),
+		);
+		self.tree.append(self.tree.document(), doctype);
+	}
+
+	fn add_attrs_if_missing(
+		&mut self,
+		target: &HTMLNodeId,
+		attrs: Vec<Attribute>,
+	)
+	{
+		self.tree.add_attributes_if_missing(
+			*target,
+			attrs.into_iter().map(Self::attribute),
+		);
+	}
+
+	fn associate_with_form(
+		&mut self,
+		target: &HTMLNodeId,
+		form: &HTMLNodeId,
+		(element, prev_element): (&HTMLNodeId, Option<&HTMLNodeId>),
+	)
+	{
+		// NOTE: l'association n'a lieu que si le parent prévu de l'élément
+		// et le formulaire sont dans le même arbre.
+		let parent = match prev_element {
+			| Some(prev_element) if self.tree.parent(*element).is_none() => {
+				*prev_element
+			}
+			| _ => *element,
+		};
+		if self.tree.root(parent) == self.tree.root(*form) {
+			self.tree.set_form_owner(*target, *form);
+		}
+	}
+
+	fn remove_from_parent(&mut self, target: &HTMLNodeId)
+	{
+		self.tree.remove_from_parent(*target);
+	}
+
+	fn reparent_children(&mut self, node: &HTMLNodeId, new_parent: &HTMLNodeId)
+	{
+		self.tree.reparent_children(*node, *new_parent);
+	}
+
+	fn mark_script_already_started(&mut self, _: &HTMLNodeId) {}
+
+	fn set_current_line(&mut self, line: u64)
+	{
+		self.current_line = line;
+	}
+
+	fn pop(&mut self, _: &HTMLNodeId) {}
+}
@@ -0,0 +1,124 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::cell::RefCell;
+use std::io;
+use std::rc::Rc;
+
+use super::{HTMLDocumentBackend, HTMLEngine};
+use crate::{
+	HTMLDocument,
+	HTMLDocumentFragment,
+	HTMLDocumentParseError,
+	HTMLFragmentContext,
+	HTMLParser,
+	HTMLParserError,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Moteur d'analyse natif, basé sur [HTMLParser].
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+pub struct NativeBackend;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl NativeBackend
+{
+	/// Lit le flux. Le texte est transmis tel quel: les fins de ligne sont
+	/// normalisées par le flux d'entrée du fournisseur de jeton, les
+	/// emplacements restent ceux de la source.
+	fn read_source(stream: &mut dyn io::Read) -> io::Result<String>
+	{
+		let mut bytes = Vec::new();
+		stream.read_to_end(&mut bytes)?;
+		Ok(String::from_utf8_lossy(&bytes).into_owned())
+	}
+
+	/// Collecteur des erreurs d'analyse, converties en erreurs du document.
+	fn error_sink() -> (
+		Rc<RefCell<Vec<HTMLDocumentParseError>>>,
+		impl FnMut(HTMLParserError),
+	)
+	{
+		let errors = Rc::new(RefCell::new(Vec::new()));
+		let sink = Rc::clone(&errors);
+		(errors, move |err| {
+			sink.borrow_mut().push(Self::document_error(err))
+		})
+	}
+
+	fn document_error(error: HTMLParserError) -> HTMLDocumentParseError
+	{
+		let (line, message) = match &error {
+			| HTMLParserError::TreeConstruction { reason, location } => {
+				(location.line(), reason.to_string())
+			}
+			| HTMLParserError::LexicalParser(err) => {
+				(err.location().line(), err.variant().code().to_owned())
+			}
+			| HTMLParserError::IO(err) => (0, err.to_string()),
+			| HTMLParserError::InvalidFragmentContext(_) => {
+				(0, error.to_string())
+			}
+		};
+
+		HTMLDocumentParseError {
+			line: line as u64,
+			message,
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl HTMLDocumentBackend for NativeBackend
+{
+	fn parse_document(
+		&self,
+		stream: &mut dyn io::Read,
+	) -> Result<HTMLDocument, HTMLParserError>
+	{
+		let source = Self::read_source(stream)?;
+		let (errors, sink) = Self::error_sink();
+		let tree = HTMLParser::new(source.chars())
+			.with_error_sink(sink)
+			.parse()?;
+
+		Ok(HTMLDocument::new(tree, errors.take(), HTMLEngine::Native))
+	}
+
+	fn parse_fragment(
+		&self,
+		context: &HTMLFragmentContext,
+		stream: &mut dyn io::Read,
+	) -> Result<HTMLDocumentFragment, HTMLParserError>
+	{
+		let source = Self::read_source(stream)?;
+		let (errors, sink) = Self::error_sink();
+		let tree = HTMLParser::new(source.chars())
+			.with_error_sink(sink)
+			.parse_fragment(context)?;
+
+		Ok(HTMLDocumentFragment::new(
+			tree,
+			errors.take(),
+			HTMLEngine::Native,
+		))
+	}
+}
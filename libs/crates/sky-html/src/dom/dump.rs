@@ -0,0 +1,197 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::{HTMLNamespace, HTMLNodeData, HTMLNodeId, HTMLTree};
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+/// Représentation de l'arbre au format `#document` des tests de
+/// construction de l'arbre html5lib.
+impl HTMLTree
+{
+	/// Représentation complète de l'arbre, ex:
+	///
+	/// ```text
+	/// #document
+	/// | <html>
+	/// |   <head>
+	/// |   <body>
+	/// |     "Bonjour"
+	/// ```
+	pub fn dump(&self) -> String
+	{
+		self.dump_lines().into_iter().fold(
+			String::new(),
+			|mut output, (_, line)| {
+				output.push_str(&line);
+				output.push('\n');
+				output
+			},
+		)
+	}
+
+	/// Lignes de la représentation de l'arbre, chacune associée au nœud
+	/// qu'elle décrit. Les attributs et le marqueur `content` d'un élément
+	/// `<template>` sont associés à l'élément.
+	pub fn dump_lines(&self) -> Vec<(HTMLNodeId, String)>
+	{
+		let mut lines = vec![(self.document(), "#document".to_owned())];
+		lines.extend(self.dump_children_lines(self.document()));
+		lines
+	}
+
+	/// Lignes de la représentation des enfants du nœud, sans en-tête, ex: les
+	/// nœuds d'un fragment.
+	pub fn dump_children_lines(
+		&self,
+		id: HTMLNodeId,
+	) -> Vec<(HTMLNodeId, String)>
+	{
+		let mut lines = Vec::new();
+		for child in self.children(id) {
+			self.dump_node(child, 0, &mut lines);
+		}
+		lines
+	}
+
+	fn dump_node(
+		&self,
+		id: HTMLNodeId,
+		depth: usize,
+		lines: &mut Vec<(HTMLNodeId, String)>,
+	)
+	{
+		let indent = format!("| {}", "  ".repeat(depth));
+
+		let line = match self[id].data() {
+			| HTMLNodeData::Document | HTMLNodeData::DocumentFragment => {
+				return;
+			}
+			| HTMLNodeData::Doctype {
+				name,
+				public_id,
+				system_id,
+			} => {
+				if public_id.is_empty() && system_id.is_empty() {
+					format!("{indent}<!DOCTYPE {name}>")
+				} else {
+					format!(
+						"{indent}<!DOCTYPE {name} \"{public_id}\" \
+						 \"{system_id}\">"
+					)
+				}
+			}
+			| HTMLNodeData::Element {
+				namespace, name, ..
+			} => {
+				match namespace {
+					| HTMLNamespace::Html => format!("{indent}<{name}>"),
+					| ns => format!("{indent}<{} {name}>", ns.prefix()),
+				}
+			}
+			| HTMLNodeData::Text(text) => format!("{indent}\"{text}\""),
+			| HTMLNodeData::Comment(data) => format!("{indent}<!-- {data} -->"),
+			| HTMLNodeData::ProcessingInstruction { target, data } => {
+				format!("{indent}<?{target} {data}>")
+			}
+		};
+		lines.push((id, line));
+
+		let mut attributes = self[id].attributes().iter().collect::<Vec<_>>();
+		attributes.sort_by_key(|attr| {
+			(attr.namespace.map(|ns| ns.prefix()), attr.name.as_str())
+		});
+		for attribute in attributes {
+			let name = match attribute.namespace {
+				| Some(ns) => format!("{} {}", ns.prefix(), attribute.name),
+				| None => attribute.name.clone(),
+			};
+			lines.push((
+				id,
+				format!("{indent}  {name}=\"{}\"", attribute.value),
+			));
+		}
+
+		if let Some(contents) = self[id].template_contents() {
+			lines.push((id, format!("{indent}  content")));
+			for child in self.children(contents) {
+				self.dump_node(child, depth + 2, lines);
+			}
+		}
+
+		for child in self.children(id) {
+			self.dump_node(child, depth + 1, lines);
+		}
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::HTMLAttribute;
+
+	fn attribute(
+		namespace: Option<HTMLNamespace>,
+		name: &str,
+		value: &str,
+	) -> HTMLAttribute
+	{
+		HTMLAttribute {
+			namespace,
+			name: name.to_owned(),
+			value: value.to_owned(),
+		}
+	}
+
+	#[test]
+	fn test_dump()
+	{
+		let mut tree = HTMLTree::new();
+		let doctype = tree.create_doctype("html", "", "");
+		let html = tree.create_element(
+			HTMLNamespace::Html,
+			"html",
+			vec![attribute(None, "lang", "fr"), attribute(None, "dir", "ltr")],
+		);
+		let template =
+			tree.create_element(HTMLNamespace::Html, "template", Vec::new());
+		let svg = tree.create_element(
+			HTMLNamespace::Svg,
+			"svg",
+			vec![attribute(Some(HTMLNamespace::XLink), "href", "#a")],
+		);
+		let comment = tree.create_comment("x");
+
+		tree.append(tree.document(), doctype);
+		tree.append(tree.document(), html);
+		tree.append(html, template);
+		tree.append(tree[template].template_contents().unwrap(), comment);
+		tree.append(html, svg);
+		tree.append_text(svg, "a\nb");
+
+		assert_eq!(
+			tree.dump(),
+			"#document\n| <!DOCTYPE html>\n| <html>\n|   dir=\"ltr\"\n|   \
+			 lang=\"fr\"\n|   <template>\n|     content\n|       <!-- x \
+			 -->\n|   <svg svg>\n|     xlink href=\"#a\"\n|     \"a\nb\"\n"
+		);
+
+		let lines = tree.dump_lines();
+		assert_eq!(lines[2], (html, "| <html>".to_owned()));
+		assert_eq!(lines[3].0, html);
+	}
+}
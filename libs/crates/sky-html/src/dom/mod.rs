@@ -0,0 +1,33 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod dump;
+mod mutation;
+mod node;
+mod serializer;
+mod traversal;
+mod tree;
+
+pub use self::mutation::{
+	HTMLMutation,
+	HTMLMutationError,
+	HTMLMutationObserver,
+};
+pub use self::node::{
+	HTMLAttribute,
+	HTMLNamespace,
+	HTMLNode,
+	HTMLNodeData,
+	HTMLNodeId,
+	HTMLQuirksMode,
+};
+pub use self::serializer::HTMLSerializer;
+pub use self::traversal::{HTMLAncestors, HTMLChildren, HTMLDescendants};
+pub use self::tree::HTMLTree;
@@ -0,0 +1,947 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::{HTMLAttribute, HTMLNamespace, HTMLNodeData, HTMLNodeId};
+use crate::HTMLDocument;
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Observateur des modifications de l'arbre d'un document, ex: pour
+/// reconstruire la vue d'une page.
+pub trait HTMLMutationObserver
+{
+	/// Reçoit une modification, une fois celle-ci appliquée à l'arbre.
+	fn notify(&mut self, mutation: &HTMLMutation);
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Modification de l'arbre d'un document.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub enum HTMLMutation
+{
+	/// Des nœuds ont été ajoutés aux enfants du parent, ou en ont été
+	/// retirés.
+	ChildList
+	{
+		parent: HTMLNodeId,
+		added: Vec<HTMLNodeId>,
+		removed: Vec<HTMLNodeId>,
+	},
+
+	/// Un attribut (sans espace de noms) de l'élément a été modifié ou
+	/// supprimé.
+	Attribute
+	{
+		element: HTMLNodeId,
+		name: String,
+		old_value: Option<String>,
+	},
+
+	/// Le contenu d'un nœud texte ou commentaire a été modifié.
+	CharacterData
+	{
+		node: HTMLNodeId, old_value: String
+	},
+}
+
+/// Emplacement d'une insertion parmi les enfants d'un nœud.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+enum HTMLInsertionPoint
+{
+	/// Avant le nœud de référence, ou à la fin sans nœud de référence.
+	Before(Option<HTMLNodeId>),
+	/// À la place de l'enfant.
+	Replace(HTMLNodeId),
+}
+
+/// Modification de l'arbre refusée.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+#[derive(thiserror::Error)]
+pub enum HTMLMutationError
+{
+	#[error("Le nœud {0:?} n'existe pas dans l'arbre")]
+	UnknownNode(HTMLNodeId),
+
+	#[error(
+		"Le nœud {child:?} ne peut pas être inséré dans le nœud {parent:?}"
+	)]
+	HierarchyRequest
+	{
+		parent: HTMLNodeId,
+		child: HTMLNodeId,
+	},
+
+	#[error("Le nœud {child:?} n'est pas un enfant du nœud {parent:?}")]
+	NotAChild
+	{
+		parent: HTMLNodeId,
+		child: HTMLNodeId,
+	},
+
+	#[error("Le nœud {0:?} n'est pas un élément")]
+	NotAnElement(HTMLNodeId),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+/// Modification de l'arbre du document.
+///
+/// Les identifiants des nœuds restent valides d'une modification à l'autre:
+/// un nœud retiré de l'arbre est seulement détaché, et peut y être inséré à
+/// nouveau.
+impl HTMLDocument
+{
+	/// Ajoute un observateur, notifié de chacune des modifications suivantes.
+	pub fn add_mutation_observer(
+		&mut self,
+		observer: impl HTMLMutationObserver + 'static,
+	)
+	{
+		self.observers.push(Box::new(observer));
+	}
+
+	/// Crée un élément HTML détaché de l'arbre.
+	pub fn create_element(&mut self, name: &str) -> HTMLNodeId
+	{
+		self.create_element_ns(HTMLNamespace::Html, &name.to_ascii_lowercase())
+	}
+
+	/// Crée un élément de l'espace de noms donné, détaché de l'arbre.
+	pub fn create_element_ns(
+		&mut self,
+		namespace: HTMLNamespace,
+		name: &str,
+	) -> HTMLNodeId
+	{
+		self.tree.create_element(namespace, name, Vec::new())
+	}
+
+	/// Crée un nœud texte détaché de l'arbre.
+	pub fn create_text_node(&mut self, data: &str) -> HTMLNodeId
+	{
+		self.tree.create_text(data)
+	}
+
+	/// Crée un commentaire détaché de l'arbre.
+	pub fn create_comment(&mut self, data: &str) -> HTMLNodeId
+	{
+		self.tree.create_comment(data)
+	}
+
+	/// Crée un fragment de document vide. Insérer un fragment y déplace ses
+	/// enfants, le fragment lui-même n'est jamais inséré.
+	pub fn create_document_fragment(&mut self) -> HTMLNodeId
+	{
+		self.tree.create_document_fragment()
+	}
+
+	/// Ajoute le nœud à la fin des enfants du parent, en le détachant de son
+	/// parent actuel.
+	pub fn append_child(
+		&mut self,
+		parent: HTMLNodeId,
+		child: HTMLNodeId,
+	) -> Result<(), HTMLMutationError>
+	{
+		self.insert_before(parent, child, None)
+	}
+
+	/// Insère le nœud parmi les enfants du parent, juste avant le nœud de
+	/// référence, ou à la fin sans nœud de référence.
+	pub fn insert_before(
+		&mut self,
+		parent: HTMLNodeId,
+		child: HTMLNodeId,
+		reference: Option<HTMLNodeId>,
+	) -> Result<(), HTMLMutationError>
+	{
+		if let Some(reference) = reference {
+			self.ensure_child(parent, reference)?;
+		}
+		self.ensure_insertion_validity(
+			parent,
+			child,
+			HTMLInsertionPoint::Before(reference),
+		)?;
+
+		let reference = match reference {
+			| Some(reference) if reference == child => {
+				self.tree.next_sibling(child)
+			}
+			| reference => reference,
+		};
+
+		let added = self.take_nodes_to_insert(child);
+		if added.is_empty() {
+			return Ok(());
+		}
+		for node in added.iter() {
+			match reference {
+				| Some(reference) => self.tree.insert_before(reference, *node),
+				| None => self.tree.append(parent, *node),
+			}
+		}
+
+		self.notify(HTMLMutation::ChildList {
+			parent,
+			added,
+			removed: Vec::new(),
+		});
+
+		Ok(())
+	}
+
+	/// Retire le nœud des enfants du parent.
+	pub fn remove_child(
+		&mut self,
+		parent: HTMLNodeId,
+		child: HTMLNodeId,
+	) -> Result<(), HTMLMutationError>
+	{
+		self.ensure_child(parent, child)?;
+		self.tree.remove_from_parent(child);
+		self.notify(HTMLMutation::ChildList {
+			parent,
+			added: Vec::new(),
+			removed: vec![child],
+		});
+		Ok(())
+	}
+
+	/// Remplace l'enfant du parent par le nouveau nœud.
+	pub fn replace_child(
+		&mut self,
+		parent: HTMLNodeId,
+		new_child: HTMLNodeId,
+		old_child: HTMLNodeId,
+	) -> Result<(), HTMLMutationError>
+	{
+		self.ensure_child(parent, old_child)?;
+		self.ensure_insertion_validity(
+			parent,
+			new_child,
+			HTMLInsertionPoint::Replace(old_child),
+		)?;
+		if new_child == old_child {
+			return Ok(());
+		}
+
+		let added = self.take_nodes_to_insert(new_child);
+		for node in added.iter() {
+			self.tree.insert_before(old_child, *node);
+		}
+		self.tree.remove_from_parent(old_child);
+
+		self.notify(HTMLMutation::ChildList {
+			parent,
+			added,
+			removed: vec![old_child],
+		});
+
+		Ok(())
+	}
+
+	/// Définit la valeur de l'attribut (sans espace de noms) de l'élément. Le
+	/// nom d'un attribut d'un élément HTML est converti en minuscules ASCII.
+	pub fn set_attribute(
+		&mut self,
+		element: HTMLNodeId,
+		name: &str,
+		value: &str,
+	) -> Result<(), HTMLMutationError>
+	{
+		let (name, attributes) = self.attributes_mut(element, name)?;
+
+		let old_value = match attributes
+			.iter_mut()
+			.find(|attr| attr.namespace.is_none() && attr.name == name)
+		{
+			| Some(attribute) => {
+				Some(std::mem::replace(&mut attribute.value, value.to_owned()))
+			}
+			| None => {
+				attributes.push(HTMLAttribute::new(name.as_str(), value));
+				None
+			}
+		};
+
+		self.notify(HTMLMutation::Attribute {
+			element,
+			name,
+			old_value,
+		});
+		Ok(())
+	}
+
+	/// Supprime l'attribut (sans espace de noms) de l'élément, et retourne
+	/// son ancienne valeur.
+	pub fn remove_attribute(
+		&mut self,
+		element: HTMLNodeId,
+		name: &str,
+	) -> Result<Option<String>, HTMLMutationError>
+	{
+		let (name, attributes) = self.attributes_mut(element, name)?;
+
+		let Some(index) = attributes
+			.iter()
+			.position(|attr| attr.namespace.is_none() && attr.name == name)
+		else {
+			return Ok(None);
+		};
+		let old_value = attributes.remove(index).value;
+
+		self.notify(HTMLMutation::Attribute {
+			element,
+			name,
+			old_value: Some(old_value.clone()),
+		});
+		Ok(Some(old_value))
+	}
+
+	/// Remplace le contenu textuel du nœud: les enfants d'un élément sont
+	/// remplacés par un unique nœud texte (aucun pour un texte vide), le
+	/// contenu d'un nœud texte ou commentaire est remplacé.
+	pub fn set_text_content(
+		&mut self,
+		node: HTMLNodeId,
+		text: &str,
+	) -> Result<(), HTMLMutationError>
+	{
+		self.ensure_exists(node)?;
+
+		if matches!(
+			self.tree[node].data(),
+			HTMLNodeData::Element { .. } | HTMLNodeData::DocumentFragment
+		) {
+			let removed = self.tree.children(node).collect::<Vec<_>>();
+			for child in removed.iter() {
+				self.tree.remove_from_parent(*child);
+			}
+
+			let mut added = Vec::new();
+			if !text.is_empty() {
+				let text = self.tree.create_text(text);
+				self.tree.append(node, text);
+				added.push(text);
+			}
+
+			if !added.is_empty() || !removed.is_empty() {
+				self.notify(HTMLMutation::ChildList {
+					parent: node,
+					added,
+					removed,
+				});
+			}
+			return Ok(());
+		}
+
+		if let HTMLNodeData::Text(data)
+		| HTMLNodeData::Comment(data)
+		| HTMLNodeData::ProcessingInstruction { data, .. } =
+			&mut self.tree.node_mut(node).data
+		{
+			let old_value = std::mem::replace(data, text.to_owned());
+			self.notify(HTMLMutation::CharacterData { node, old_value });
+		}
+
+		Ok(())
+	}
+
+	/// Ajoute la classe à l'élément, si elle est absente.
+	pub fn add_class(
+		&mut self,
+		element: HTMLNodeId,
+		class: &str,
+	) -> Result<(), HTMLMutationError>
+	{
+		let mut classes = self.class_list(element)?;
+		if classes.iter().any(|name| name == class) {
+			return Ok(());
+		}
+		classes.push(class.to_owned());
+		self.set_attribute(element, "class", &classes.join(" "))
+	}
+
+	/// Retire la classe de l'élément, si elle est présente.
+	pub fn remove_class(
+		&mut self,
+		element: HTMLNodeId,
+		class: &str,
+	) -> Result<(), HTMLMutationError>
+	{
+		let mut classes = self.class_list(element)?;
+		if !classes.iter().any(|name| name == class) {
+			return Ok(());
+		}
+		classes.retain(|name| name != class);
+		self.set_attribute(element, "class", &classes.join(" "))
+	}
+
+	/// Ajoute la classe à l'élément si elle est absente, la retire sinon.
+	/// Retourne la présence de la classe après modification.
+	pub fn toggle_class(
+		&mut self,
+		element: HTMLNodeId,
+		class: &str,
+	) -> Result<bool, HTMLMutationError>
+	{
+		if self.class_list(element)?.iter().any(|name| name == class) {
+			self.remove_class(element, class)?;
+			Ok(false)
+		} else {
+			self.add_class(element, class)?;
+			Ok(true)
+		}
+	}
+}
+
+impl HTMLDocument
+{
+	fn notify(&mut self, mutation: HTMLMutation)
+	{
+		for observer in self.observers.iter_mut() {
+			observer.notify(&mutation);
+		}
+	}
+
+	/// Nœuds à insérer, détachés de leur parent: les enfants d'un fragment,
+	/// ou le nœud lui-même.
+	fn take_nodes_to_insert(&mut self, node: HTMLNodeId) -> Vec<HTMLNodeId>
+	{
+		let (parent, nodes) =
+			if matches!(self.tree[node].data(), HTMLNodeData::DocumentFragment)
+			{
+				(Some(node), self.tree[node].children().to_vec())
+			} else {
+				(self.tree.parent(node), vec![node])
+			};
+
+		for node in nodes.iter() {
+			self.tree.remove_from_parent(*node);
+		}
+		if let Some(parent) = parent.filter(|_| !nodes.is_empty()) {
+			self.notify(HTMLMutation::ChildList {
+				parent,
+				added: Vec::new(),
+				removed: nodes.clone(),
+			});
+		}
+		nodes
+	}
+
+	fn ensure_exists(&self, node: HTMLNodeId) -> Result<(), HTMLMutationError>
+	{
+		match self.tree.get(node) {
+			| Some(_) => Ok(()),
+			| None => Err(HTMLMutationError::UnknownNode(node)),
+		}
+	}
+
+	fn ensure_child(
+		&self,
+		parent: HTMLNodeId,
+		child: HTMLNodeId,
+	) -> Result<(), HTMLMutationError>
+	{
+		self.ensure_exists(parent)?;
+		self.ensure_exists(child)?;
+		if self.tree.parent(child) != Some(parent) {
+			return Err(HTMLMutationError::NotAChild { parent, child });
+		}
+		Ok(())
+	}
+
+	/// Validité de l'insertion du nœud parmi les enfants du parent (« ensure
+	/// pre-insertion validity » et « replace a child » du DOM).
+	///
+	/// Un nœud ne peut être inséré que dans un document, un fragment ou un
+	/// élément, et jamais dans l'un de ses propres descendants. Un document
+	/// ne contient pas de texte, et seul un document contient un DOCTYPE. Un
+	/// document contient au plus un élément et un DOCTYPE, le DOCTYPE
+	/// précédant l'élément.
+	fn ensure_insertion_validity(
+		&self,
+		parent: HTMLNodeId,
+		child: HTMLNodeId,
+		point: HTMLInsertionPoint,
+	) -> Result<(), HTMLMutationError>
+	{
+		self.ensure_exists(parent)?;
+		self.ensure_exists(child)?;
+
+		let parent_is_document =
+			matches!(self.tree[parent].data(), HTMLNodeData::Document);
+		let valid_parent = parent_is_document
+			|| matches!(
+				self.tree[parent].data(),
+				HTMLNodeData::DocumentFragment | HTMLNodeData::Element { .. }
+			);
+		let valid_child = match self.tree[child].data() {
+			| HTMLNodeData::Document => false,
+			| HTMLNodeData::Doctype { .. } => parent_is_document,
+			| HTMLNodeData::Text(_) => !parent_is_document,
+			| _ => true,
+		};
+		let is_inclusive_ancestor = child == parent
+			|| self.tree.ancestors(parent).any(|id| id == child);
+
+		if !valid_parent
+			|| !valid_child
+			|| is_inclusive_ancestor
+			|| (parent_is_document
+				&& !self.is_valid_document_child(parent, child, point))
+		{
+			return Err(HTMLMutationError::HierarchyRequest { parent, child });
+		}
+		Ok(())
+	}
+
+	/// Vérifie que le document garde au plus un élément et un DOCTYPE, le
+	/// DOCTYPE précédant l'élément, une fois le nœud inséré.
+	fn is_valid_document_child(
+		&self,
+		document: HTMLNodeId,
+		node: HTMLNodeId,
+		point: HTMLInsertionPoint,
+	) -> bool
+	{
+		let is_element = |id: &HTMLNodeId| self.tree[*id].is_element();
+		let is_doctype = |id: &HTMLNodeId| {
+			matches!(self.tree[*id].data(), HTMLNodeData::Doctype { .. })
+		};
+
+		let (reference, replaced) = match point {
+			| HTMLInsertionPoint::Before(reference) => (reference, None),
+			| HTMLInsertionPoint::Replace(child) => (Some(child), Some(child)),
+		};
+		let children = self.tree[document].children();
+		let (preceding, following) = match reference.and_then(|reference| {
+			children.iter().position(|id| *id == reference)
+		}) {
+			| Some(index) => (&children[..index], &children[index..]),
+			| None => (children, &[][..]),
+		};
+		// NOTE: l'enfant remplacé ne compte pas parmi les enfants du document.
+		let others = |id: &&HTMLNodeId| Some(**id) != replaced;
+
+		let has_element = children.iter().filter(others).any(is_element);
+		let has_doctype = children.iter().filter(others).any(is_doctype);
+		let element_before = preceding.iter().any(is_element);
+		let doctype_after = following.iter().filter(others).any(is_doctype);
+
+		match self.tree[node].data() {
+			| HTMLNodeData::DocumentFragment => {
+				let nodes = self.tree[node].children();
+				let elements = nodes.iter().filter(|id| is_element(id)).count();
+				let has_text = nodes.iter().any(|id| {
+					matches!(self.tree[*id].data(), HTMLNodeData::Text(_))
+				});
+				!has_text
+					&& (elements == 0
+						|| (elements == 1 && !has_element && !doctype_after))
+			}
+			| HTMLNodeData::Element { .. } => !has_element && !doctype_after,
+			| HTMLNodeData::Doctype { .. } => !has_doctype && !element_before,
+			| _ => true,
+		}
+	}
+
+	/// Nom normalisé de l'attribut, et attributs de l'élément.
+	fn attributes_mut(
+		&mut self,
+		element: HTMLNodeId,
+		name: &str,
+	) -> Result<(String, &mut Vec<HTMLAttribute>), HTMLMutationError>
+	{
+		self.ensure_exists(element)?;
+		match &mut self.tree.node_mut(element).data {
+			| HTMLNodeData::Element {
+				namespace,
+				attributes,
+				..
+			} => {
+				let name = if *namespace == HTMLNamespace::Html {
+					name.to_ascii_lowercase()
+				} else {
+					name.to_owned()
+				};
+				Ok((name, attributes))
+			}
+			| _ => Err(HTMLMutationError::NotAnElement(element)),
+		}
+	}
+
+	fn class_list(
+		&self,
+		element: HTMLNodeId,
+	) -> Result<Vec<String>, HTMLMutationError>
+	{
+		self.ensure_exists(element)?;
+		let node = &self.tree[element];
+		if !node.is_element() {
+			return Err(HTMLMutationError::NotAnElement(element));
+		}
+		Ok(node.class_list().map(str::to_owned).collect())
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<F> HTMLMutationObserver for F
+where
+	F: FnMut(&HTMLMutation),
+{
+	fn notify(&mut self, mutation: &HTMLMutation)
+	{
+		self(mutation);
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use std::cell::RefCell;
+	use std::rc::Rc;
+
+	use super::*;
+	use crate::{HTMLEngine, HTMLSelector};
+
+	fn document(source: &str)
+		-> (HTMLDocument, Rc<RefCell<Vec<HTMLMutation>>>)
+	{
+		let mut document = HTMLDocument::from_slice(
+			&mut source.as_bytes(),
+			HTMLEngine::Native,
+		)
+		.unwrap();
+		let mutations = Rc::new(RefCell::new(Vec::new()));
+		let records = Rc::clone(&mutations);
+		document.add_mutation_observer(move |mutation: &HTMLMutation| {
+			records.borrow_mut().push(mutation.clone());
+		});
+		(document, mutations)
+	}
+
+	fn find(document: &HTMLDocument, selector: &str) -> HTMLNodeId
+	{
+		document
+			.query_selector(&HTMLSelector::parse(selector).unwrap())
+			.unwrap()
+	}
+
+	fn body_html(document: &HTMLDocument) -> String
+	{
+		document.tree().inner_html(find(document, "body"))
+	}
+
+	#[test]
+	fn test_child_list_mutations()
+	{
+		let (mut document, mutations) =
+			document("<ul><li>a</li><li>c</li></ul>");
+		let ul = find(&document, "ul");
+		let c = find(&document, "li + li");
+
+		let b = document.create_element("LI");
+		let text = document.create_text_node("b");
+		document.append_child(b, text).unwrap();
+		document.insert_before(ul, b, Some(c)).unwrap();
+		assert_eq!(
+			body_html(&document),
+			"<ul><li>a</li><li>b</li><li>c</li></ul>"
+		);
+
+		let comment = document.create_comment("x");
+		document.replace_child(ul, comment, c).unwrap();
+		assert_eq!(
+			body_html(&document),
+			"<ul><li>a</li><li>b</li><!--x--></ul>"
+		);
+
+		document.remove_child(ul, b).unwrap();
+		assert_eq!(body_html(&document), "<ul><li>a</li><!--x--></ul>");
+
+		// NOTE: un nœud retiré garde son identifiant et peut être réinséré.
+		document.append_child(ul, b).unwrap();
+		assert_eq!(
+			body_html(&document),
+			"<ul><li>a</li><!--x--><li>b</li></ul>"
+		);
+
+		assert_eq!(
+			mutations.borrow()[1],
+			HTMLMutation::ChildList {
+				parent: ul,
+				added: vec![b],
+				removed: Vec::new(),
+			}
+		);
+		assert_eq!(
+			mutations.borrow()[2],
+			HTMLMutation::ChildList {
+				parent: ul,
+				added: vec![comment],
+				removed: vec![c],
+			}
+		);
+		assert_eq!(mutations.borrow().len(), 5);
+	}
+
+	#[test]
+	fn test_invalid_mutations()
+	{
+		let (mut document, mutations) = document("<div><p>a</p></div>");
+		let div = find(&document, "div");
+		let p = find(&document, "p");
+		let text = document.tree().children(p).next().unwrap();
+
+		assert_eq!(
+			document.append_child(p, div),
+			Err(HTMLMutationError::HierarchyRequest {
+				parent: p,
+				child: div
+			})
+		);
+		assert_eq!(
+			document.append_child(text, div),
+			Err(HTMLMutationError::HierarchyRequest {
+				parent: text,
+				child: div
+			})
+		);
+		assert_eq!(
+			document.remove_child(div, text),
+			Err(HTMLMutationError::NotAChild {
+				parent: div,
+				child: text
+			})
+		);
+		assert_eq!(
+			document.set_attribute(text, "id", "x"),
+			Err(HTMLMutationError::NotAnElement(text))
+		);
+		assert_eq!(
+			document.remove_child(div, HTMLNodeId(usize::MAX)),
+			Err(HTMLMutationError::UnknownNode(HTMLNodeId(usize::MAX)))
+		);
+		assert!(mutations.borrow().is_empty());
+	}
+
+	#[test]
+	fn test_document_children_validity()
+	{
+		let (mut document, mutations) = document("<!DOCTYPE html><p>a</p>");
+		let root = document.tree().document();
+		let doctype = document.tree().doctype().unwrap();
+		let html = document.tree().document_element().unwrap();
+		let div = document.create_element("div");
+		let other_doctype = document.tree.create_doctype("html", "", "");
+		let hierarchy_request = |child| {
+			Err(HTMLMutationError::HierarchyRequest {
+				parent: root,
+				child,
+			})
+		};
+
+		// NOTE: un seul élément.
+		assert_eq!(document.append_child(root, div), hierarchy_request(div));
+		// NOTE: un seul DOCTYPE.
+		assert_eq!(
+			document.insert_before(root, other_doctype, Some(doctype)),
+			hierarchy_request(other_doctype)
+		);
+		assert_eq!(
+			document.replace_child(root, other_doctype, html),
+			hierarchy_request(other_doctype)
+		);
+
+		// NOTE: le DOCTYPE précède l'élément.
+		document.remove_child(root, doctype).unwrap();
+		assert_eq!(
+			document.append_child(root, doctype),
+			hierarchy_request(doctype)
+		);
+		document.remove_child(root, html).unwrap();
+		document.append_child(root, doctype).unwrap();
+		assert_eq!(
+			document.insert_before(root, div, Some(doctype)),
+			hierarchy_request(div)
+		);
+		document.append_child(root, div).unwrap();
+		assert_eq!(
+			document.replace_child(root, other_doctype, div),
+			hierarchy_request(other_doctype)
+		);
+		document.replace_child(root, html, div).unwrap();
+		document
+			.replace_child(root, other_doctype, doctype)
+			.unwrap();
+		assert_eq!(document.tree()[root].children(), [other_doctype, html]);
+		assert_eq!(mutations.borrow().len(), 6);
+	}
+
+	#[test]
+	fn test_document_fragment_insertion()
+	{
+		let (mut document, mutations) = document("<ul><li>a</li></ul>");
+		let root = document.tree().document();
+		let ul = find(&document, "ul");
+
+		let fragment = document.create_document_fragment();
+		let b = document.create_element("li");
+		let c = document.create_element("li");
+		document.append_child(fragment, b).unwrap();
+		document.append_child(fragment, c).unwrap();
+		mutations.borrow_mut().clear();
+
+		document.append_child(ul, fragment).unwrap();
+		assert_eq!(
+			body_html(&document),
+			"<ul><li>a</li><li></li><li></li></ul>"
+		);
+		assert_eq!(document.tree()[fragment].children(), []);
+		assert_eq!(
+			mutations.borrow()[..],
+			[
+				HTMLMutation::ChildList {
+					parent: fragment,
+					added: Vec::new(),
+					removed: vec![b, c],
+				},
+				HTMLMutation::ChildList {
+					parent: ul,
+					added: vec![b, c],
+					removed: Vec::new(),
+				},
+			]
+		);
+
+		// NOTE: un fragment vide n'ajoute rien.
+		document.append_child(ul, fragment).unwrap();
+		assert_eq!(mutations.borrow().len(), 2);
+
+		// NOTE: un document contient au plus un élément, et pas de texte.
+		let html = document.tree().document_element().unwrap();
+		document.remove_child(root, html).unwrap();
+		let text = document.create_text_node("x");
+		document.append_child(fragment, b).unwrap();
+		document.append_child(fragment, text).unwrap();
+		assert_eq!(
+			document.append_child(root, fragment),
+			Err(HTMLMutationError::HierarchyRequest {
+				parent: root,
+				child: fragment
+			})
+		);
+		document.remove_child(fragment, text).unwrap();
+		document.append_child(fragment, c).unwrap();
+		assert_eq!(
+			document.append_child(root, fragment),
+			Err(HTMLMutationError::HierarchyRequest {
+				parent: root,
+				child: fragment
+			})
+		);
+		document.remove_child(fragment, c).unwrap();
+		document.append_child(root, fragment).unwrap();
+		assert_eq!(document.tree()[root].children(), [b]);
+
+		document.append_child(fragment, html).unwrap();
+		assert_eq!(document.replace_child(root, fragment, b), Ok(()));
+		assert_eq!(document.tree()[root].children(), [html]);
+	}
+
+	#[test]
+	fn test_attribute_mutations()
+	{
+		let (mut document, mutations) = document("<p class='a b'>x</p>");
+		let p = find(&document, "p");
+
+		document.set_attribute(p, "ID", "un").unwrap();
+		document.set_attribute(p, "id", "deux").unwrap();
+		assert_eq!(document.tree()[p].attribute("id"), Some("deux"));
+		assert_eq!(document.remove_attribute(p, "id"), Ok(Some("deux".into())));
+		assert_eq!(document.remove_attribute(p, "id"), Ok(None));
+
+		document.add_class(p, "c").unwrap();
+		document.remove_class(p, "a").unwrap();
+		assert_eq!(document.toggle_class(p, "b"), Ok(false));
+		assert_eq!(document.toggle_class(p, "d"), Ok(true));
+		assert_eq!(document.tree()[p].attribute("class"), Some("c d"));
+		assert!(document.tree()[p].has_class("d"));
+
+		assert_eq!(
+			mutations.borrow()[..3],
+			[
+				HTMLMutation::Attribute {
+					element: p,
+					name: "id".to_owned(),
+					old_value: None,
+				},
+				HTMLMutation::Attribute {
+					element: p,
+					name: "id".to_owned(),
+					old_value: Some("un".to_owned()),
+				},
+				HTMLMutation::Attribute {
+					element: p,
+					name: "id".to_owned(),
+					old_value: Some("deux".to_owned()),
+				},
+			]
+		);
+		assert_eq!(mutations.borrow().len(), 7);
+	}
+
+	#[test]
+	fn test_set_text_content()
+	{
+		let (mut document, mutations) = document("<p>a<b>b</b></p>");
+		let p = find(&document, "p");
+
+		document.set_text_content(p, "<c>").unwrap();
+		assert_eq!(body_html(&document), "<p>&lt;c&gt;</p>");
+
+		let text = document.tree().children(p).next().unwrap();
+		document.set_text_content(text, "d").unwrap();
+		assert_eq!(document.tree().text_content(p), "d");
+
+		document.set_text_content(p, "").unwrap();
+		assert_eq!(body_html(&document), "<p></p>");
+
+		assert_eq!(
+			mutations.borrow()[1],
+			HTMLMutation::CharacterData {
+				node: text,
+				old_value: "<c>".to_owned(),
+			}
+		);
+		assert_eq!(mutations.borrow().len(), 3);
+	}
+}
@@ -0,0 +1,327 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use sky_lang::Location;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Identifiant d'un nœud dans l'arbre.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq, Hash)]
+#[derive(PartialOrd, Ord)]
+pub struct HTMLNodeId(pub(crate) usize);
+
+/// Nœud de l'arbre.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct HTMLNode
+{
+	pub(crate) parent: Option<HTMLNodeId>,
+	pub(crate) previous_sibling: Option<HTMLNodeId>,
+	pub(crate) next_sibling: Option<HTMLNodeId>,
+	pub(crate) children: Vec<HTMLNodeId>,
+	pub(crate) data: HTMLNodeData,
+	pub(crate) location: Option<Location>,
+}
+
+/// Attribut d'un élément, ex: `href="..."`, `xlink:href="..."`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct HTMLAttribute
+{
+	/// Espace de noms de l'attribut, uniquement pour les attributs étrangers
+	/// ajustés, ex: `xlink:href`.
+	pub namespace: Option<HTMLNamespace>,
+	/// Nom local de l'attribut, ex: `href`.
+	pub name: String,
+	/// Valeur de l'attribut.
+	pub value: String,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Données d'un nœud.
+#[derive(Debug)]
+#[derive(Clone)]
+pub enum HTMLNodeData
+{
+	/// Racine de l'arbre.
+	Document,
+
+	/// Fragment de document, ex: le contenu d'un élément `<template>`.
+	DocumentFragment,
+
+	/// Déclaration du type de document, ex: `<!DOCTYPE html>`
+	Doctype
+	{
+		name: String,
+		public_id: String,
+		system_id: String,
+	},
+
+	/// Élément, ex: `<p class="...">`
+	Element
+	{
+		namespace: HTMLNamespace,
+		name: String,
+		attributes: Vec<HTMLAttribute>,
+		/// Contenu de l'élément `<template>`.
+		template_contents: Option<HTMLNodeId>,
+	},
+
+	/// Texte.
+	Text(String),
+
+	/// Commentaire, ex: `<!-- ... -->`
+	Comment(String),
+
+	/// Instruction de traitement, ex: `<?xml-stylesheet ...?>`. Le parseur
+	/// HTML n'en crée jamais, elles proviennent uniquement des analyseurs
+	/// XML.
+	ProcessingInstruction
+	{
+		target: String, data: String
+	},
+}
+
+/// Espace de noms.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum HTMLNamespace
+{
+	#[default]
+	Html,
+	MathML,
+	Svg,
+	XLink,
+	Xml,
+	Xmlns,
+}
+
+/// Mode de rendu du document, déterminé par son DOCTYPE.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum HTMLQuirksMode
+{
+	#[default]
+	NoQuirks,
+	LimitedQuirks,
+	Quirks,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl HTMLNodeId
+{
+	/// Identifiant du nœud racine, le document.
+	pub const DOCUMENT: Self = Self(0);
+}
+
+impl HTMLNode
+{
+	pub(crate) const fn new(data: HTMLNodeData) -> Self
+	{
+		Self {
+			parent: None,
+			previous_sibling: None,
+			next_sibling: None,
+			children: Vec::new(),
+			data,
+			location: None,
+		}
+	}
+
+	/// Parent du nœud.
+	pub const fn parent(&self) -> Option<HTMLNodeId>
+	{
+		self.parent
+	}
+
+	/// Frère précédent du nœud.
+	pub const fn previous_sibling(&self) -> Option<HTMLNodeId>
+	{
+		self.previous_sibling
+	}
+
+	/// Frère suivant du nœud.
+	pub const fn next_sibling(&self) -> Option<HTMLNodeId>
+	{
+		self.next_sibling
+	}
+
+	/// Enfants du nœud, dans l'ordre du document.
+	pub fn children(&self) -> &[HTMLNodeId]
+	{
+		&self.children
+	}
+
+	/// Premier enfant du nœud.
+	pub fn first_child(&self) -> Option<HTMLNodeId>
+	{
+		self.children.first().copied()
+	}
+
+	/// Dernier enfant du nœud.
+	pub fn last_child(&self) -> Option<HTMLNodeId>
+	{
+		self.children.last().copied()
+	}
+
+	/// Emplacement, dans la source, du jeton ayant créé le nœud. Seul
+	/// l'analyseur natif renseigne cet emplacement.
+	pub const fn location(&self) -> Option<Location>
+	{
+		self.location
+	}
+
+	/// Données du nœud.
+	pub const fn data(&self) -> &HTMLNodeData
+	{
+		&self.data
+	}
+
+	/// Vérifie que le nœud est un élément.
+	pub const fn is_element(&self) -> bool
+	{
+		matches!(self.data, HTMLNodeData::Element { .. })
+	}
+
+	/// Nom local de l'élément.
+	pub fn element_name(&self) -> Option<&str>
+	{
+		match &self.data {
+			| HTMLNodeData::Element { name, .. } => Some(name),
+			| _ => None,
+		}
+	}
+
+	/// Espace de noms de l'élément.
+	pub const fn namespace(&self) -> Option<HTMLNamespace>
+	{
+		match &self.data {
+			| HTMLNodeData::Element { namespace, .. } => Some(*namespace),
+			| _ => None,
+		}
+	}
+
+	/// Attributs de l'élément.
+	pub fn attributes(&self) -> &[HTMLAttribute]
+	{
+		match &self.data {
+			| HTMLNodeData::Element { attributes, .. } => attributes,
+			| _ => &[],
+		}
+	}
+
+	/// Valeur de l'attribut (sans espace de noms) de l'élément.
+	pub fn attribute(&self, name: &str) -> Option<&str>
+	{
+		self.attributes()
+			.iter()
+			.find(|attr| attr.namespace.is_none() && attr.name == name)
+			.map(|attr| attr.value.as_str())
+	}
+
+	/// Classes de l'élément, dans l'ordre de l'attribut `class`.
+	pub fn class_list(&self) -> impl Iterator<Item = &str>
+	{
+		self.attribute("class")
+			.unwrap_or_default()
+			.split_ascii_whitespace()
+	}
+
+	/// Vérifie que l'élément possède la classe.
+	pub fn has_class(&self, class: &str) -> bool
+	{
+		self.class_list().any(|name| name == class)
+	}
+
+	/// Vérifie que le nœud est un élément ayant l'espace de noms et le nom
+	/// donnés.
+	pub fn is(&self, namespace: HTMLNamespace, name: &str) -> bool
+	{
+		matches!(
+			&self.data,
+			HTMLNodeData::Element { namespace: ns, name: n, .. }
+			if *ns == namespace && n == name
+		)
+	}
+
+	/// Vérifie que le nœud est un élément HTML ayant le nom donné.
+	pub fn is_html(&self, name: &str) -> bool
+	{
+		self.is(HTMLNamespace::Html, name)
+	}
+
+	/// Contenu de l'élément `<template>`.
+	pub const fn template_contents(&self) -> Option<HTMLNodeId>
+	{
+		match &self.data {
+			| HTMLNodeData::Element {
+				template_contents, ..
+			} => *template_contents,
+			| _ => None,
+		}
+	}
+}
+
+impl HTMLAttribute
+{
+	/// Crée un attribut sans espace de noms.
+	pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self
+	{
+		Self {
+			namespace: None,
+			name: name.into(),
+			value: value.into(),
+		}
+	}
+}
+
+impl HTMLNamespace
+{
+	/// URL de l'espace de noms.
+	pub const fn url(self) -> &'static str
+	{
+		match self {
+			| Self::Html => "http://www.w3.org/1999/xhtml",
+			| Self::MathML => "http://www.w3.org/1998/Math/MathML",
+			| Self::Svg => "http://www.w3.org/2000/svg",
+			| Self::XLink => "http://www.w3.org/1999/xlink",
+			| Self::Xml => "http://www.w3.org/XML/1998/namespace",
+			| Self::Xmlns => "http://www.w3.org/2000/xmlns/",
+		}
+	}
+
+	/// Préfixe usuel de l'espace de noms, ex: `svg`, `xlink`.
+	pub const fn prefix(self) -> &'static str
+	{
+		match self {
+			| Self::Html => "html",
+			| Self::MathML => "math",
+			| Self::Svg => "svg",
+			| Self::XLink => "xlink",
+			| Self::Xml => "xml",
+			| Self::Xmlns => "xmlns",
+		}
+	}
+}
@@ -0,0 +1,427 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::{HTMLAttribute, HTMLNamespace, HTMLNodeData, HTMLNodeId, HTMLTree};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Éléments vides: ils n'ont ni contenu ni balise de fin.
+#[rustfmt::skip]
+const VOID_ELEMENTS: [&str; 18] = [
+	"area", "base", "basefont", "bgsound", "br", "col", "embed", "frame", "hr",
+	"img", "input", "keygen", "link", "meta", "param", "source", "track", "wbr",
+];
+
+/// Éléments dont le texte est sérialisé tel quel, sans échappement. Les
+/// scripts n'étant pas exécutés, `<noscript>` n'en fait pas partie.
+#[rustfmt::skip]
+const RAW_TEXT_ELEMENTS: [&str; 7] = [
+	"iframe", "noembed", "noframes", "plaintext", "script", "style", "xmp",
+];
+
+/// Éléments dont le contenu n'est pas réindenté, en plus des éléments de
+/// [RAW_TEXT_ELEMENTS], et dont le premier saut de ligne est ignoré par
+/// l'analyseur.
+const PREFORMATTED_ELEMENTS: [&str; 3] = ["listing", "pre", "textarea"];
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Sérialisation HTML d'un arbre, selon l'algorithme de sérialisation des
+/// fragments HTML de la spécification.
+///
+/// En mode indenté (voir [HTMLSerializer::with_indentation]), chaque nœud est
+/// écrit sur sa propre ligne: les textes blancs sont ignorés et les autres
+/// textes sont rognés, sauf dans les éléments préformatés. Le résultat est
+/// destiné à la lecture, il ne reproduit pas forcément le même arbre.
+pub struct HTMLSerializer<'tree>
+{
+	tree: &'tree HTMLTree,
+	/// Indentation d'un niveau, en mode indenté.
+	indentation: Option<String>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<'tree> HTMLSerializer<'tree>
+{
+	pub const fn new(tree: &'tree HTMLTree) -> Self
+	{
+		Self {
+			tree,
+			indentation: None,
+		}
+	}
+
+	/// Active le mode indenté, avec l'indentation d'un niveau donnée, ex:
+	/// `"\t"`.
+	pub fn with_indentation(mut self, indentation: impl Into<String>) -> Self
+	{
+		self.indentation.replace(indentation.into());
+		self
+	}
+}
+
+impl HTMLSerializer<'_>
+{
+	/// Sérialisation du nœud et de ses descendants. Pour un document ou un
+	/// fragment, seuls les descendants sont sérialisés.
+	pub fn outer_html(&self, id: HTMLNodeId) -> String
+	{
+		let mut output = String::new();
+		match self.tree[id].data() {
+			| HTMLNodeData::Document | HTMLNodeData::DocumentFragment => {
+				self.serialize_children(id, self.depth(), &mut output);
+			}
+			| _ => self.serialize_node(id, self.depth(), &mut output),
+		}
+		output
+	}
+
+	/// Sérialisation des descendants du nœud. Pour un élément `<template>`,
+	/// il s'agit des descendants de son contenu.
+	pub fn inner_html(&self, id: HTMLNodeId) -> String
+	{
+		let mut output = String::new();
+		self.serialize_children(id, self.depth(), &mut output);
+		output
+	}
+
+	/// Profondeur initiale: aucune, hors du mode indenté.
+	fn depth(&self) -> Option<usize>
+	{
+		self.indentation.as_ref().map(|_| 0)
+	}
+
+	fn serialize_children(
+		&self,
+		id: HTMLNodeId,
+		depth: Option<usize>,
+		output: &mut String,
+	)
+	{
+		let parent = self.tree[id].template_contents().unwrap_or(id);
+
+		for child in self.tree.children(parent) {
+			let (Some(depth), Some(indentation)) = (depth, &self.indentation)
+			else {
+				self.serialize_node(child, None, output);
+				continue;
+			};
+
+			if matches!(
+				self.tree[child].data(),
+				HTMLNodeData::Text(text) if text.trim().is_empty()
+			) {
+				continue;
+			}
+
+			if !output.is_empty() {
+				output.push('\n');
+			}
+			output.push_str(&indentation.repeat(depth));
+			self.serialize_node(child, Some(depth), output);
+		}
+	}
+
+	fn serialize_node(
+		&self,
+		id: HTMLNodeId,
+		depth: Option<usize>,
+		output: &mut String,
+	)
+	{
+		match self.tree[id].data() {
+			| HTMLNodeData::Document | HTMLNodeData::DocumentFragment => {
+				self.serialize_children(id, depth, output);
+			}
+			| HTMLNodeData::Doctype { name, .. } => {
+				output.push_str("<!DOCTYPE ");
+				output.push_str(name);
+				output.push('>');
+			}
+			| HTMLNodeData::Element {
+				namespace,
+				name,
+				attributes,
+				..
+			} => {
+				output.push('<');
+				output.push_str(name);
+				for attribute in attributes {
+					output.push(' ');
+					output.push_str(&Self::attribute_name(attribute));
+					output.push_str("=\"");
+					output.push_str(&Self::escape(&attribute.value, true));
+					output.push('"');
+				}
+				output.push('>');
+
+				let is_html = *namespace == HTMLNamespace::Html;
+				if is_html && VOID_ELEMENTS.contains(&name.as_str()) {
+					return;
+				}
+
+				// NOTE: l'analyseur ignore le premier saut de ligne de ces
+				// éléments, il est doublé afin d'être conservé lors d'une
+				// nouvelle analyse.
+				if is_html
+					&& PREFORMATTED_ELEMENTS.contains(&name.as_str())
+					&& self.tree[id].first_child().is_some_and(|child| {
+						matches!(
+							self.tree[child].data(),
+							HTMLNodeData::Text(text) if text.starts_with('\n')
+						)
+					}) {
+					output.push('\n');
+				}
+
+				match depth {
+					| Some(depth) if !self.is_compact(id) => {
+						self.serialize_children(id, Some(depth + 1), output);
+						output.push('\n');
+						if let Some(indentation) = &self.indentation {
+							output.push_str(&indentation.repeat(depth));
+						}
+					}
+					| _ => self.serialize_children(id, None, output),
+				}
+
+				output.push_str("</");
+				output.push_str(name);
+				output.push('>');
+			}
+			| HTMLNodeData::Text(text) => {
+				let text = match depth {
+					| Some(_) => text.trim(),
+					| None => text,
+				};
+
+				let is_raw_text = self.tree.parent(id).is_some_and(|parent| {
+					RAW_TEXT_ELEMENTS
+						.iter()
+						.any(|name| self.tree[parent].is_html(name))
+				});
+				if is_raw_text {
+					output.push_str(text);
+				} else {
+					output.push_str(&Self::escape(text, false));
+				}
+			}
+			| HTMLNodeData::Comment(data) => {
+				output.push_str("<!--");
+				output.push_str(data);
+				output.push_str("-->");
+			}
+			| HTMLNodeData::ProcessingInstruction { target, data } => {
+				output.push_str("<?");
+				output.push_str(target);
+				output.push(' ');
+				output.push_str(data);
+				output.push('>');
+			}
+		}
+	}
+
+	/// En mode indenté, le contenu d'un élément est écrit sur la même ligne
+	/// lorsqu'il est préformaté ou qu'il ne contient que du texte.
+	fn is_compact(&self, id: HTMLNodeId) -> bool
+	{
+		let element = &self.tree[id];
+		let parent = element.template_contents().unwrap_or(id);
+
+		RAW_TEXT_ELEMENTS
+			.iter()
+			.chain(PREFORMATTED_ELEMENTS.iter())
+			.any(|name| element.is_html(name))
+			|| self.tree.children(parent).all(|child| {
+				matches!(self.tree[child].data(), HTMLNodeData::Text(_))
+			})
+	}
+
+	/// Nom sérialisé d'un attribut, préfixé selon son espace de noms.
+	fn attribute_name(attribute: &HTMLAttribute) -> String
+	{
+		match attribute.namespace {
+			| None => attribute.name.clone(),
+			| Some(HTMLNamespace::Xmlns) if attribute.name == "xmlns" => {
+				attribute.name.clone()
+			}
+			| Some(namespace) => {
+				format!("{}:{}", namespace.prefix(), attribute.name)
+			}
+		}
+	}
+
+	/// Échappe le texte: `&`, U+00A0 NO-BREAK SPACE, `<` et `>`, ainsi que
+	/// `"` dans la valeur d'un attribut.
+	fn escape(text: &str, attribute_mode: bool) -> String
+	{
+		let mut escaped = String::with_capacity(text.len());
+		for ch in text.chars() {
+			match ch {
+				| '&' => escaped.push_str("&amp;"),
+				| '\u{A0}' => escaped.push_str("&nbsp;"),
+				| '<' => escaped.push_str("&lt;"),
+				| '>' => escaped.push_str("&gt;"),
+				| '"' if attribute_mode => escaped.push_str("&quot;"),
+				| _ => escaped.push(ch),
+			}
+		}
+		escaped
+	}
+}
+
+impl HTMLTree
+{
+	/// Sérialisation HTML du nœud et de ses descendants, voir
+	/// [HTMLSerializer::outer_html].
+	pub fn outer_html(&self, id: HTMLNodeId) -> String
+	{
+		HTMLSerializer::new(self).outer_html(id)
+	}
+
+	/// Sérialisation HTML des descendants du nœud, voir
+	/// [HTMLSerializer::inner_html].
+	pub fn inner_html(&self, id: HTMLNodeId) -> String
+	{
+		HTMLSerializer::new(self).inner_html(id)
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::HTMLParser;
+
+	fn parse(source: &str) -> HTMLTree
+	{
+		HTMLParser::new(source.chars())
+			.parse()
+			.expect("l'analyse ne devrait pas échouer")
+	}
+
+	fn find(tree: &HTMLTree, name: &str) -> HTMLNodeId
+	{
+		tree.descendants(tree.document())
+			.find(|id| tree[*id].element_name() == Some(name))
+			.expect("l'élément existe")
+	}
+
+	#[test]
+	fn test_document()
+	{
+		let tree = parse("<!DOCTYPE html><!--a--><title>T</title>x<!--b-->");
+		assert_eq!(
+			tree.outer_html(tree.document()),
+			"<!DOCTYPE html><!--a--><html><head><title>T</title></\
+			 head><body>x<!--b--></body></html>"
+		);
+	}
+
+	#[test]
+	fn test_void_elements_and_escaping()
+	{
+		let tree = parse(
+			"<p title='a\"b&amp;<'>x &lt; y&nbsp;&gt;<br><img src=a.png></p>",
+		);
+		let p = find(&tree, "p");
+		assert_eq!(
+			tree.outer_html(p),
+			"<p title=\"a&quot;b&amp;&lt;\">x &lt; y&nbsp;&gt;<br><img \
+			 src=\"a.png\"></p>"
+		);
+		assert_eq!(tree.inner_html(find(&tree, "br")), "");
+	}
+
+	#[test]
+	fn test_raw_text_elements()
+	{
+		let tree = parse(
+			"<script>if (a < b && c) {}</script><style>a > b \
+			 {}</style><textarea>&lt;b&gt;</textarea>",
+		);
+		assert_eq!(
+			tree.inner_html(find(&tree, "head")),
+			"<script>if (a < b && c) {}</script><style>a > b {}</style>"
+		);
+		assert_eq!(tree.inner_html(find(&tree, "textarea")), "&lt;b&gt;");
+	}
+
+	#[test]
+	fn test_template_contents()
+	{
+		let tree = parse("<template><b>x</b><!--y--></template>");
+		let template = find(&tree, "template");
+		assert_eq!(tree.inner_html(template), "<b>x</b><!--y-->");
+		assert_eq!(
+			tree.outer_html(template),
+			"<template><b>x</b><!--y--></template>"
+		);
+	}
+
+	#[test]
+	fn test_foreign_elements()
+	{
+		let tree = parse(
+			"<svg viewbox='0 0 1 1' xlink:href=#a xmlns:xlink=x><path/></svg>",
+		);
+		assert_eq!(
+			tree.outer_html(find(&tree, "svg")),
+			"<svg viewBox=\"0 0 1 1\" xlink:href=\"#a\" \
+			 xmlns:xlink=\"x\"><path></path></svg>"
+		);
+	}
+
+	#[test]
+	fn test_indentation()
+	{
+		let tree = parse(
+			"<title>T</title>\n<ul>\n  <li>a <b>b</b></li>\n</ul><pre>\n \
+			 x\n</pre><br>",
+		);
+		let html = HTMLSerializer::new(&tree)
+			.with_indentation("  ")
+			.outer_html(tree.document());
+		assert_eq!(
+			html,
+			"<html>\n  <head>\n    <title>T</title>\n  </head>\n  <body>\n    \
+			 <ul>\n      <li>\n        a\n        <b>b</b>\n      </li>\n    \
+			 </ul>\n    <pre> x\n</pre>\n    <br>\n  </body>\n</html>"
+		);
+	}
+
+	#[test]
+	fn test_round_trip()
+	{
+		for source in [
+			"<!DOCTYPE html><html lang=fr><head><meta \
+			 charset=utf-8></head><body><p>a<b>b<i>c</b>d</i></p></body></\
+			 html>",
+			"<table><tr><td>a<td>b</table><select><option>x</select>",
+			"<svg><foreignObject><p>x</p></foreignObject></svg><math><mi>y",
+			"<template><tr><td>x</template><pre>\n\ny</pre>",
+		] {
+			let tree = parse(source);
+			let html = tree.outer_html(tree.document());
+			assert_eq!(parse(&html).dump(), tree.dump(), "{html}");
+		}
+	}
+}
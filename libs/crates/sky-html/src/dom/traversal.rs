@@ -0,0 +1,131 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::{HTMLNodeId, HTMLTree};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Itérateur sur les enfants d'un nœud, en suivant les liens entre frères.
+pub struct HTMLChildren<'a>
+{
+	tree: &'a HTMLTree,
+	next: Option<HTMLNodeId>,
+}
+
+/// Itérateur sur les descendants d'un nœud, dans l'ordre du document.
+pub struct HTMLDescendants<'a>
+{
+	tree: &'a HTMLTree,
+	root: HTMLNodeId,
+	next: Option<HTMLNodeId>,
+}
+
+/// Itérateur sur les ancêtres d'un nœud, du parent jusqu'à la racine.
+pub struct HTMLAncestors<'a>
+{
+	tree: &'a HTMLTree,
+	next: Option<HTMLNodeId>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<'a> HTMLChildren<'a>
+{
+	pub(crate) fn new(tree: &'a HTMLTree, parent: HTMLNodeId) -> Self
+	{
+		Self {
+			tree,
+			next: tree[parent].first_child(),
+		}
+	}
+}
+
+impl<'a> HTMLDescendants<'a>
+{
+	pub(crate) fn new(tree: &'a HTMLTree, root: HTMLNodeId) -> Self
+	{
+		Self {
+			tree,
+			root,
+			next: tree[root].first_child(),
+		}
+	}
+}
+
+impl<'a> HTMLAncestors<'a>
+{
+	pub(crate) fn new(tree: &'a HTMLTree, node: HTMLNodeId) -> Self
+	{
+		Self {
+			tree,
+			next: tree[node].parent(),
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Iterator for HTMLChildren<'_>
+{
+	type Item = HTMLNodeId;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let current = self.next?;
+		self.next = self.tree[current].next_sibling();
+		Some(current)
+	}
+}
+
+impl Iterator for HTMLDescendants<'_>
+{
+	type Item = HTMLNodeId;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let current = self.next?;
+
+		// Le nœud suivant est le premier enfant, sinon le frère suivant du
+		// nœud ou de l'un de ses ancêtres, sans jamais remonter au-delà de la
+		// racine.
+		self.next = self.tree[current].first_child().or_else(|| {
+			let mut node = current;
+			loop {
+				if node == self.root {
+					return None;
+				}
+				if let Some(sibling) = self.tree[node].next_sibling() {
+					return Some(sibling);
+				}
+				node = self.tree[node].parent()?;
+			}
+		});
+
+		Some(current)
+	}
+}
+
+impl Iterator for HTMLAncestors<'_>
+{
+	type Item = HTMLNodeId;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let current = self.next?;
+		self.next = self.tree[current].parent();
+		Some(current)
+	}
+}
@@ -0,0 +1,556 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::ops;
+
+use sky_lang::Location;
+
+use super::{
+	HTMLAncestors,
+	HTMLAttribute,
+	HTMLChildren,
+	HTMLDescendants,
+	HTMLNamespace,
+	HTMLNode,
+	HTMLNodeData,
+	HTMLNodeId,
+	HTMLQuirksMode,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Arbre du document: les nœuds sont stockés dans une arène et référencés
+/// par leur [HTMLNodeId].
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct HTMLTree
+{
+	nodes: Vec<HTMLNode>,
+	quirks_mode: HTMLQuirksMode,
+	/// Formulaire propriétaire des éléments associés à un formulaire.
+	form_owners: HashMap<HTMLNodeId, HTMLNodeId>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl HTMLTree
+{
+	/// Crée un arbre ne contenant que le nœud document.
+	pub fn new() -> Self
+	{
+		Self {
+			nodes: vec![HTMLNode::new(HTMLNodeData::Document)],
+			quirks_mode: Default::default(),
+			form_owners: Default::default(),
+		}
+	}
+
+	/// Nœud racine de l'arbre.
+	pub const fn document(&self) -> HTMLNodeId
+	{
+		HTMLNodeId::DOCUMENT
+	}
+
+	/// Nœud associé à l'identifiant.
+	pub fn get(&self, id: HTMLNodeId) -> Option<&HTMLNode>
+	{
+		self.nodes.get(id.0)
+	}
+
+	/// Mode de rendu du document.
+	pub const fn quirks_mode(&self) -> HTMLQuirksMode
+	{
+		self.quirks_mode
+	}
+
+	/// Déclaration du type de document, `<!DOCTYPE ...>`.
+	pub fn doctype(&self) -> Option<HTMLNodeId>
+	{
+		self.children(self.document()).find(|child| {
+			matches!(self[*child].data, HTMLNodeData::Doctype { .. })
+		})
+	}
+
+	/// Formulaire propriétaire de l'élément, ex: l'élément `<form>` qui
+	/// contenait un `<input>` au moment de l'analyse.
+	pub fn form_owner(&self, element: HTMLNodeId) -> Option<HTMLNodeId>
+	{
+		self.form_owners.get(&element).copied()
+	}
+
+	/// Élément racine du document, `<html>`.
+	pub fn document_element(&self) -> Option<HTMLNodeId>
+	{
+		self[self.document()]
+			.children
+			.iter()
+			.copied()
+			.find(|child| self[*child].is_element())
+	}
+
+	/// Parent du nœud.
+	pub fn parent(&self, id: HTMLNodeId) -> Option<HTMLNodeId>
+	{
+		self[id].parent
+	}
+
+	/// Frère précédent du nœud.
+	pub fn previous_sibling(&self, id: HTMLNodeId) -> Option<HTMLNodeId>
+	{
+		self[id].previous_sibling
+	}
+
+	/// Frère suivant du nœud.
+	pub fn next_sibling(&self, id: HTMLNodeId) -> Option<HTMLNodeId>
+	{
+		self[id].next_sibling
+	}
+
+	/// Enfants du nœud, dans l'ordre du document.
+	pub fn children(&self, id: HTMLNodeId) -> HTMLChildren<'_>
+	{
+		HTMLChildren::new(self, id)
+	}
+
+	/// Descendants du nœud, dans l'ordre du document (parcours en
+	/// profondeur). Le contenu des éléments `<template>` n'est pas parcouru.
+	pub fn descendants(&self, id: HTMLNodeId) -> HTMLDescendants<'_>
+	{
+		HTMLDescendants::new(self, id)
+	}
+
+	/// Ancêtres du nœud, du parent jusqu'à la racine.
+	pub fn ancestors(&self, id: HTMLNodeId) -> HTMLAncestors<'_>
+	{
+		HTMLAncestors::new(self, id)
+	}
+
+	/// Racine de l'arbre contenant le nœud: le document, sinon le nœud
+	/// détaché le plus haut.
+	pub fn root(&self, id: HTMLNodeId) -> HTMLNodeId
+	{
+		self.ancestors(id).last().unwrap_or(id)
+	}
+
+	/// Contenu textuel du nœud et de ses descendants.
+	pub fn text_content(&self, id: HTMLNodeId) -> String
+	{
+		let mut output = String::new();
+		self.collect_text(id, &mut output);
+		output
+	}
+
+	fn collect_text(&self, id: HTMLNodeId, output: &mut String)
+	{
+		match &self[id].data {
+			| HTMLNodeData::Text(text) => output.push_str(text),
+			| _ => {
+				for child in &self[id].children {
+					self.collect_text(*child, output);
+				}
+			}
+		}
+	}
+}
+
+impl HTMLTree
+{
+	pub(crate) fn node_mut(&mut self, id: HTMLNodeId) -> &mut HTMLNode
+	{
+		&mut self.nodes[id.0]
+	}
+
+	pub(crate) fn set_quirks_mode(&mut self, mode: HTMLQuirksMode)
+	{
+		self.quirks_mode = mode;
+	}
+
+	pub(crate) fn set_form_owner(
+		&mut self,
+		element: HTMLNodeId,
+		form: HTMLNodeId,
+	)
+	{
+		self.form_owners.insert(element, form);
+	}
+
+	/// Nombre de nœuds de l'arène: les nœuds créés par la suite auront un
+	/// identifiant supérieur ou égal.
+	pub(crate) fn next_node_id(&self) -> HTMLNodeId
+	{
+		HTMLNodeId(self.nodes.len())
+	}
+
+	/// Renseigne l'emplacement des nœuds créés à partir de l'identifiant
+	/// donné.
+	pub(crate) fn locate_nodes_from(
+		&mut self,
+		first: HTMLNodeId,
+		location: Location,
+	)
+	{
+		for node in &mut self.nodes[first.0..] {
+			node.location.get_or_insert(location);
+		}
+	}
+
+	fn create_node(&mut self, data: HTMLNodeData) -> HTMLNodeId
+	{
+		let id = HTMLNodeId(self.nodes.len());
+		self.nodes.push(HTMLNode::new(data));
+		id
+	}
+
+	/// Crée un élément détaché de l'arbre. Un élément `<template>` HTML
+	/// reçoit un fragment de document pour son contenu.
+	pub(crate) fn create_element(
+		&mut self,
+		namespace: HTMLNamespace,
+		name: impl Into<String>,
+		attributes: Vec<HTMLAttribute>,
+	) -> HTMLNodeId
+	{
+		let name = name.into();
+		let template_contents = (namespace == HTMLNamespace::Html
+			&& name == "template")
+			.then(|| self.create_node(HTMLNodeData::DocumentFragment));
+
+		self.create_node(HTMLNodeData::Element {
+			namespace,
+			name,
+			attributes,
+			template_contents,
+		})
+	}
+
+	pub(crate) fn create_document_fragment(&mut self) -> HTMLNodeId
+	{
+		self.create_node(HTMLNodeData::DocumentFragment)
+	}
+
+	pub(crate) fn create_text(&mut self, data: impl Into<String>)
+		-> HTMLNodeId
+	{
+		self.create_node(HTMLNodeData::Text(data.into()))
+	}
+
+	pub(crate) fn create_comment(
+		&mut self,
+		data: impl Into<String>,
+	) -> HTMLNodeId
+	{
+		self.create_node(HTMLNodeData::Comment(data.into()))
+	}
+
+	pub(crate) fn create_processing_instruction(
+		&mut self,
+		target: impl Into<String>,
+		data: impl Into<String>,
+	) -> HTMLNodeId
+	{
+		self.create_node(HTMLNodeData::ProcessingInstruction {
+			target: target.into(),
+			data: data.into(),
+		})
+	}
+
+	pub(crate) fn create_doctype(
+		&mut self,
+		name: impl Into<String>,
+		public_id: impl Into<String>,
+		system_id: impl Into<String>,
+	) -> HTMLNodeId
+	{
+		self.create_node(HTMLNodeData::Doctype {
+			name: name.into(),
+			public_id: public_id.into(),
+			system_id: system_id.into(),
+		})
+	}
+
+	/// Ajoute les attributs absents de l'élément.
+	pub(crate) fn add_attributes_if_missing(
+		&mut self,
+		id: HTMLNodeId,
+		new_attributes: impl IntoIterator<Item = HTMLAttribute>,
+	)
+	{
+		let HTMLNodeData::Element { attributes, .. } =
+			&mut self.node_mut(id).data
+		else {
+			return;
+		};
+
+		for attribute in new_attributes {
+			let exists = attributes.iter().any(|attr| {
+				attr.namespace == attribute.namespace
+					&& attr.name == attribute.name
+			});
+			if !exists {
+				attributes.push(attribute);
+			}
+		}
+	}
+
+	/// Ajoute le nœud à la fin des enfants du parent, en le détachant de son
+	/// parent actuel.
+	pub(crate) fn append(&mut self, parent: HTMLNodeId, child: HTMLNodeId)
+	{
+		self.remove_from_parent(child);
+
+		let last = self[parent].last_child();
+		self.node_mut(child).parent = Some(parent);
+		self.node_mut(parent).children.push(child);
+		self.link_siblings(last, Some(child));
+		self.link_siblings(Some(child), None);
+	}
+
+	/// Insère le nœud juste avant le nœud de référence, en le détachant de son
+	/// parent actuel.
+	pub(crate) fn insert_before(
+		&mut self,
+		reference: HTMLNodeId,
+		child: HTMLNodeId,
+	)
+	{
+		self.remove_from_parent(child);
+
+		let parent = self[reference]
+			.parent
+			.expect("le nœud de référence doit avoir un parent");
+		let index = self.index_in_parent(reference);
+		let previous = self[reference].previous_sibling;
+		self.node_mut(child).parent = Some(parent);
+		self.node_mut(parent).children.insert(index, child);
+		self.link_siblings(previous, Some(child));
+		self.link_siblings(Some(child), Some(reference));
+	}
+
+	/// Ajoute du texte à la fin des enfants du parent. Le texte est fusionné
+	/// avec le dernier enfant lorsqu'il s'agit d'un nœud texte.
+	pub(crate) fn append_text(&mut self, parent: HTMLNodeId, text: &str)
+	{
+		if let Some(&last) = self[parent].children.last() {
+			if let HTMLNodeData::Text(data) = &mut self.node_mut(last).data {
+				data.push_str(text);
+				return;
+			}
+		}
+
+		let node = self.create_text(text);
+		self.append(parent, node);
+	}
+
+	/// Insère du texte juste avant le nœud de référence. Le texte est
+	/// fusionné avec le frère précédent lorsqu'il s'agit d'un nœud texte.
+	pub(crate) fn insert_text_before(
+		&mut self,
+		reference: HTMLNodeId,
+		text: &str,
+	)
+	{
+		let parent = self[reference]
+			.parent
+			.expect("le nœud de référence doit avoir un parent");
+		let index = self.index_in_parent(reference);
+
+		if index > 0 {
+			let previous = self[parent].children[index - 1];
+			if let HTMLNodeData::Text(data) = &mut self.node_mut(previous).data
+			{
+				data.push_str(text);
+				return;
+			}
+		}
+
+		let node = self.create_text(text);
+		self.insert_before(reference, node);
+	}
+
+	/// Détache le nœud de son parent.
+	pub(crate) fn remove_from_parent(&mut self, child: HTMLNodeId)
+	{
+		let Some(parent) = self.node_mut(child).parent.take() else {
+			return;
+		};
+		self.node_mut(parent).children.retain(|node| *node != child);
+
+		let node = self.node_mut(child);
+		let previous = node.previous_sibling.take();
+		let next = node.next_sibling.take();
+		self.link_siblings(previous, next);
+	}
+
+	/// Déplace tous les enfants du nœud à la fin des enfants du nouveau
+	/// parent.
+	pub(crate) fn reparent_children(&mut self, from: HTMLNodeId, to: HTMLNodeId)
+	{
+		let children = std::mem::take(&mut self.node_mut(from).children);
+		for child in children.iter() {
+			self.node_mut(*child).parent = Some(to);
+		}
+
+		let last = self[to].last_child();
+		self.link_siblings(last, children.first().copied());
+		self.node_mut(to).children.extend(children);
+	}
+
+	/// Relie deux nœuds frères consécutifs.
+	fn link_siblings(
+		&mut self,
+		previous: Option<HTMLNodeId>,
+		next: Option<HTMLNodeId>,
+	)
+	{
+		if let Some(previous) = previous {
+			self.node_mut(previous).next_sibling = next;
+		}
+		if let Some(next) = next {
+			self.node_mut(next).previous_sibling = previous;
+		}
+	}
+
+	fn index_in_parent(&self, child: HTMLNodeId) -> usize
+	{
+		let parent = self[child].parent.expect("le nœud doit avoir un parent");
+		self[parent]
+			.children
+			.iter()
+			.position(|node| *node == child)
+			.expect("le nœud doit être un enfant de son parent")
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for HTMLTree
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl ops::Index<HTMLNodeId> for HTMLTree
+{
+	type Output = HTMLNode;
+
+	fn index(&self, id: HTMLNodeId) -> &Self::Output
+	{
+		&self.nodes[id.0]
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	fn element(tree: &mut HTMLTree, name: &str) -> HTMLNodeId
+	{
+		tree.create_element(HTMLNamespace::Html, name, Vec::new())
+	}
+
+	fn names(tree: &HTMLTree, ids: impl Iterator<Item = HTMLNodeId>) -> String
+	{
+		ids.map(|id| {
+			match tree[id].data() {
+				| HTMLNodeData::Text(text) => format!("#{text}"),
+				| _ => tree[id].element_name().unwrap_or("?").to_owned(),
+			}
+		})
+		.collect::<Vec<_>>()
+		.join(",")
+	}
+
+	#[test]
+	fn test_sibling_links()
+	{
+		let mut tree = HTMLTree::new();
+		let body = element(&mut tree, "body");
+		let a = element(&mut tree, "a");
+		let b = element(&mut tree, "b");
+		let c = element(&mut tree, "c");
+		tree.append(tree.document(), body);
+		tree.append(body, a);
+		tree.append(body, c);
+		tree.insert_before(c, b);
+
+		assert_eq!(names(&tree, tree.children(body)), "a,b,c");
+		assert_eq!(tree.next_sibling(a), Some(b));
+		assert_eq!(tree.previous_sibling(c), Some(b));
+		assert_eq!(tree.previous_sibling(a), None);
+
+		tree.remove_from_parent(b);
+		assert_eq!(tree.next_sibling(a), Some(c));
+		assert_eq!(tree.previous_sibling(c), Some(a));
+		assert_eq!(tree.parent(b), None);
+		assert_eq!(tree.next_sibling(b), None);
+
+		let div = element(&mut tree, "div");
+		tree.append(div, b);
+		tree.reparent_children(body, div);
+		assert_eq!(names(&tree, tree.children(div)), "b,a,c");
+		assert_eq!(tree.next_sibling(b), Some(a));
+		assert_eq!(tree.children(body).count(), 0);
+	}
+
+	#[test]
+	fn test_text_is_kept_in_order()
+	{
+		let mut tree = HTMLTree::new();
+		let p = element(&mut tree, "p");
+		let b = element(&mut tree, "b");
+		tree.append(tree.document(), p);
+		tree.append_text(p, "a ");
+		tree.append(p, b);
+		tree.append_text(b, "b");
+		tree.append_text(p, " c");
+		tree.insert_text_before(b, "!");
+
+		assert_eq!(names(&tree, tree.children(p)), "#a !,b,# c");
+		assert_eq!(tree.text_content(p), "a !b c");
+	}
+
+	#[test]
+	fn test_traversal()
+	{
+		let mut tree = HTMLTree::new();
+		let html = element(&mut tree, "html");
+		let body = element(&mut tree, "body");
+		let ul = element(&mut tree, "ul");
+		let li = element(&mut tree, "li");
+		let p = element(&mut tree, "p");
+		tree.append(tree.document(), html);
+		tree.append(html, body);
+		tree.append(body, ul);
+		tree.append(ul, li);
+		tree.append(body, p);
+
+		assert_eq!(
+			names(&tree, tree.descendants(tree.document())),
+			"html,body,ul,li,p"
+		);
+		assert_eq!(names(&tree, tree.descendants(ul)), "li");
+		assert_eq!(names(&tree, tree.ancestors(li)), "ul,body,html,?");
+		assert_eq!(tree.document_element(), Some(html));
+	}
+}
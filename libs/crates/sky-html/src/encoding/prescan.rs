@@ -0,0 +1,350 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use encoding_rs::{
+	Encoding,
+	UTF_16BE,
+	UTF_16LE,
+	UTF_8,
+	WINDOWS_1252,
+	X_USER_DEFINED,
+};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Nombre d'octets examinés par la pré-analyse.
+pub(crate) const PRESCAN_LENGTH: usize = 1024;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Pré-analyse des premiers octets d'un document à la recherche d'un
+/// encodage déclaré par un élément `<meta>`.
+///
+/// Voir <https://html.spec.whatwg.org/multipage/parsing.html#prescan-a-byte-stream-to-determine-its-encoding>
+struct HTMLPrescanner<'a>
+{
+	bytes: &'a [u8],
+	position: usize,
+}
+
+/// Attribut lu par la pré-analyse: nom et valeur, en minuscules ASCII.
+type Attribute = (Vec<u8>, Vec<u8>);
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Encodage déclaré par un élément `<meta charset>` ou `<meta
+/// http-equiv=content-type content="...; charset=...">`.
+pub(crate) fn prescan(bytes: &[u8]) -> Option<&'static Encoding>
+{
+	HTMLPrescanner {
+		bytes: &bytes[..bytes.len().min(PRESCAN_LENGTH)],
+		position: 0,
+	}
+	.run()
+}
+
+/// Encodage de l'attribut `content` d'un élément `<meta>`, ex:
+/// `text/html; charset=utf-8`.
+///
+/// Voir <https://html.spec.whatwg.org/multipage/urls-and-fetching.html#algorithm-for-extracting-a-character-encoding-from-a-meta-element>
+fn extract_charset(content: &[u8]) -> Option<&'static Encoding>
+{
+	let mut position = 0;
+	loop {
+		position += find(&content[position..], b"charset")? + b"charset".len();
+		position += skip_whitespace(&content[position..]);
+		if content.get(position) == Some(&b'=') {
+			position += 1;
+			break;
+		}
+	}
+	position += skip_whitespace(&content[position..]);
+
+	let label = match content.get(position)? {
+		| quote @ (b'"' | b'\'') => {
+			let rest = &content[position + 1..];
+			&rest[..rest.iter().position(|byte| byte == quote)?]
+		}
+		| _ => {
+			let rest = &content[position..];
+			let end = rest
+				.iter()
+				.position(|byte| is_whitespace(*byte) || *byte == b';')
+				.unwrap_or(rest.len());
+			&rest[..end]
+		}
+	};
+	Encoding::for_label(label)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize>
+{
+	haystack
+		.windows(needle.len())
+		.position(|window| window == needle)
+}
+
+fn skip_whitespace(bytes: &[u8]) -> usize
+{
+	bytes
+		.iter()
+		.take_while(|byte| is_whitespace(**byte))
+		.count()
+}
+
+const fn is_whitespace(byte: u8) -> bool
+{
+	matches!(byte, b'\t' | b'\n' | 0x0C | b'\r' | b' ')
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+/// Les méthodes retournent `None` lorsque la fin des octets examinés est
+/// atteinte: la pré-analyse est alors abandonnée.
+impl HTMLPrescanner<'_>
+{
+	fn run(mut self) -> Option<&'static Encoding>
+	{
+		while self.position < self.bytes.len() {
+			let rest = &self.bytes[self.position..];
+			let starts_tag = |offset: usize| {
+				rest.get(offset).is_some_and(u8::is_ascii_alphabetic)
+			};
+
+			if rest.starts_with(b"<!--") {
+				// NOTE: les tirets de `<!--` peuvent terminer le commentaire,
+				// ex: `<!-->`.
+				self.position += 2 + find(&rest[2..], b"-->")? + 2;
+			} else if rest.len() > 5
+				&& rest[..5].eq_ignore_ascii_case(b"<meta")
+				&& (is_whitespace(rest[5]) || rest[5] == b'/')
+			{
+				self.position += 5;
+				if let Some(encoding) = self.meta()? {
+					return Some(encoding);
+				}
+			} else if rest[0] == b'<'
+				&& (starts_tag(1)
+					|| rest.get(1) == Some(&b'/') && starts_tag(2))
+			{
+				while !matches!(self.byte()?, byte if byte == b'>' || is_whitespace(byte))
+				{
+					self.position += 1;
+				}
+				while self.attribute()?.is_some() {}
+			} else if rest.starts_with(b"<!")
+				|| rest.starts_with(b"</")
+				|| rest.starts_with(b"<?")
+			{
+				self.position += find(rest, b">")?;
+			}
+
+			self.position += 1;
+		}
+		None
+	}
+
+	fn byte(&self) -> Option<u8>
+	{
+		self.bytes.get(self.position).copied()
+	}
+
+	/// Attributs d'un élément `<meta>`, et encodage qu'ils déclarent.
+	fn meta(&mut self) -> Option<Option<&'static Encoding>>
+	{
+		let mut names = Vec::new();
+		let mut got_pragma = false;
+		let mut need_pragma = None;
+		// NOTE: `Some(None)` lorsque l'encodage déclaré est inconnu.
+		let mut charset = None;
+
+		while let Some((name, value)) = self.attribute()? {
+			if names.contains(&name) {
+				continue;
+			}
+
+			match name.as_slice() {
+				| b"http-equiv" => got_pragma |= value == b"content-type",
+				| b"content" if charset.is_none() => {
+					if let Some(encoding) = extract_charset(&value) {
+						charset = Some(Some(encoding));
+						need_pragma = Some(true);
+					}
+				}
+				| b"charset" if charset.is_none() => {
+					charset = Some(Encoding::for_label(&value));
+					need_pragma = Some(false);
+				}
+				| _ => {}
+			}
+
+			names.push(name);
+		}
+
+		let encoding = match (need_pragma, charset) {
+			| (Some(true), _) if !got_pragma => return Some(None),
+			| (Some(_), Some(Some(encoding))) => encoding,
+			| _ => return Some(None),
+		};
+
+		Some(Some(if encoding == UTF_16BE || encoding == UTF_16LE {
+			UTF_8
+		} else if encoding == X_USER_DEFINED {
+			WINDOWS_1252
+		} else {
+			encoding
+		}))
+	}
+
+	/// Attribut suivant de la balise, ou `Some(None)` à la fin de la balise.
+	fn attribute(&mut self) -> Option<Option<Attribute>>
+	{
+		while is_whitespace(self.byte()?) || self.byte()? == b'/' {
+			self.position += 1;
+		}
+		if self.byte()? == b'>' {
+			return Some(None);
+		}
+
+		let mut name = Vec::new();
+		loop {
+			match self.byte()? {
+				| b'=' if !name.is_empty() => {
+					self.position += 1;
+					return self.attribute_value(name);
+				}
+				| byte if is_whitespace(byte) => break,
+				| b'/' | b'>' => return Some(Some((name, Vec::new()))),
+				| byte => name.push(byte.to_ascii_lowercase()),
+			}
+			self.position += 1;
+		}
+
+		while is_whitespace(self.byte()?) {
+			self.position += 1;
+		}
+		if self.byte()? != b'=' {
+			return Some(Some((name, Vec::new())));
+		}
+		self.position += 1;
+		self.attribute_value(name)
+	}
+
+	fn attribute_value(&mut self, name: Vec<u8>) -> Option<Option<Attribute>>
+	{
+		while is_whitespace(self.byte()?) {
+			self.position += 1;
+		}
+
+		let mut value = Vec::new();
+		match self.byte()? {
+			| quote @ (b'"' | b'\'') => {
+				loop {
+					self.position += 1;
+					match self.byte()? {
+						| byte if byte == quote => {
+							self.position += 1;
+							return Some(Some((name, value)));
+						}
+						| byte => value.push(byte.to_ascii_lowercase()),
+					}
+				}
+			}
+			| b'>' => return Some(Some((name, value))),
+			| _ => {}
+		}
+
+		loop {
+			match self.byte()? {
+				| byte if is_whitespace(byte) || byte == b'>' => {
+					return Some(Some((name, value)));
+				}
+				| byte => value.push(byte.to_ascii_lowercase()),
+			}
+			self.position += 1;
+		}
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use encoding_rs::{SHIFT_JIS, WINDOWS_1252};
+
+	use super::*;
+
+	#[test]
+	fn test_prescan_meta_charset()
+	{
+		assert_eq!(prescan(b"<meta charset=shift_jis>"), Some(SHIFT_JIS));
+		assert_eq!(prescan(b"<META CHARSET='Latin1'/>"), Some(WINDOWS_1252));
+		assert_eq!(prescan(b"<meta charset=\"utf-16le\">"), Some(UTF_8));
+		assert_eq!(
+			prescan(b"<meta charset=x-user-defined>"),
+			Some(WINDOWS_1252)
+		);
+		assert_eq!(
+			prescan(b"<meta charset=inconnu><meta charset=sjis>"),
+			Some(SHIFT_JIS)
+		);
+		assert_eq!(prescan(b"<meta name=charset>"), None);
+	}
+
+	#[test]
+	fn test_prescan_meta_http_equiv()
+	{
+		assert_eq!(
+			prescan(
+				b"<meta http-equiv=Content-Type content='text/html; \
+				  charset=\"shift_jis\"'>"
+			),
+			Some(SHIFT_JIS)
+		);
+		assert_eq!(
+			prescan(b"<meta content='text/html; charset=shift_jis'>"),
+			None
+		);
+		assert_eq!(
+			prescan(
+				b"<meta content='charset; charset = latin1' \
+				  http-equiv=content-type>"
+			),
+			Some(WINDOWS_1252)
+		);
+	}
+
+	#[test]
+	fn test_prescan_skips_comments_and_tags()
+	{
+		assert_eq!(prescan(b"<!-- <meta charset=sjis> --><p>"), None);
+		assert_eq!(prescan(b"<!--><meta charset=sjis>"), Some(SHIFT_JIS));
+		assert_eq!(
+			prescan(b"<p title='<meta charset=sjis>'><meta charset=latin1>"),
+			Some(WINDOWS_1252)
+		);
+		assert_eq!(prescan(b"<!DOCTYPE html><?x?><meta charset=sjis"), None);
+
+		let mut late = vec![b' '; PRESCAN_LENGTH];
+		late.extend_from_slice(b"<meta charset=sjis>");
+		assert_eq!(prescan(&late), None);
+	}
+}
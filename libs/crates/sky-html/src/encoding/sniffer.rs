@@ -0,0 +1,207 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::borrow::Cow;
+
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
+
+use super::prescan::prescan;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Encodage retenu pour décoder un document.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub struct HTMLEncoding
+{
+	pub encoding: &'static Encoding,
+	pub confidence: HTMLEncodingConfidence,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Certitude de l'encodage d'un document.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum HTMLEncodingConfidence
+{
+	/// Encodage déduit du contenu: déclaré par un élément `<meta>`, ou
+	/// détecté.
+	Tentative,
+	/// Encodage indiqué par une marque d'ordre des octets (BOM) ou par le
+	/// protocole de transport, ex: l'en-tête HTTP `Content-Type`.
+	Certain,
+	/// Document analysé à partir de texte déjà décodé.
+	#[default]
+	Irrelevant,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl HTMLEncoding
+{
+	const fn new(
+		encoding: &'static Encoding,
+		confidence: HTMLEncodingConfidence,
+	) -> Self
+	{
+		Self {
+			encoding,
+			confidence,
+		}
+	}
+
+	/// Détermine l'encodage d'un document à partir de ses octets et, s'il
+	/// est connu, de son type de contenu, ex: `text/html; charset=utf-8`.
+	///
+	/// Dans l'ordre: la marque d'ordre des octets (BOM), le paramètre
+	/// `charset` du type de contenu, puis la pré-analyse des éléments
+	/// `<meta>` des 1024 premiers octets. À défaut, le document est en UTF-8
+	/// si ses octets sont valides, en windows-1252 sinon.
+	///
+	/// Voir <https://html.spec.whatwg.org/multipage/parsing.html#encoding-sniffing-algorithm>
+	pub fn sniff(bytes: &[u8], content_type: Option<&str>) -> Self
+	{
+		if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+			return Self::new(encoding, HTMLEncodingConfidence::Certain);
+		}
+
+		if let Some(encoding) = content_type
+			.and_then(Self::content_type_charset)
+			.and_then(|label| Encoding::for_label(label.as_bytes()))
+		{
+			return Self::new(encoding, HTMLEncodingConfidence::Certain);
+		}
+
+		if let Some(encoding) = prescan(bytes) {
+			return Self::new(encoding, HTMLEncodingConfidence::Tentative);
+		}
+
+		let encoding = if std::str::from_utf8(bytes).is_ok() {
+			UTF_8
+		} else {
+			WINDOWS_1252
+		};
+		Self::new(encoding, HTMLEncodingConfidence::Tentative)
+	}
+
+	/// Paramètre `charset` d'un type de contenu, ex: `utf-8` pour
+	/// `text/html; charset="utf-8"`.
+	pub fn content_type_charset(content_type: &str) -> Option<&str>
+	{
+		content_type.split(';').skip(1).find_map(|parameter| {
+			let (name, value) = parameter.split_once('=')?;
+			name.trim()
+				.eq_ignore_ascii_case("charset")
+				.then(|| value.trim().trim_matches('"'))
+		})
+	}
+
+	/// Nom de l'encodage, ex: `windows-1252`.
+	pub fn name(&self) -> &'static str
+	{
+		self.encoding.name()
+	}
+
+	/// Décode les octets du document. La marque d'ordre des octets est
+	/// retirée, les séquences invalides sont remplacées par U+FFFD.
+	pub fn decode<'a>(&self, bytes: &'a [u8]) -> Cow<'a, str>
+	{
+		self.encoding.decode_with_bom_removal(bytes).0
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for HTMLEncoding
+{
+	fn default() -> Self
+	{
+		Self::new(UTF_8, HTMLEncodingConfidence::default())
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use encoding_rs::{SHIFT_JIS, UTF_16BE};
+
+	use super::*;
+
+	#[test]
+	fn test_sniff_order()
+	{
+		let bom = b"\xFE\xFF\0<\0p\0>";
+		assert_eq!(
+			HTMLEncoding::sniff(bom, Some("text/html; charset=utf-8")),
+			HTMLEncoding::new(UTF_16BE, HTMLEncodingConfidence::Certain)
+		);
+
+		let meta = b"<meta charset=sjis>";
+		assert_eq!(
+			HTMLEncoding::sniff(meta, Some("text/html; charset=latin1")),
+			HTMLEncoding::new(WINDOWS_1252, HTMLEncodingConfidence::Certain)
+		);
+		assert_eq!(
+			HTMLEncoding::sniff(meta, Some("text/html; charset=inconnu")),
+			HTMLEncoding::new(SHIFT_JIS, HTMLEncodingConfidence::Tentative)
+		);
+
+		assert_eq!(
+			HTMLEncoding::sniff("<p>é".as_bytes(), None).name(),
+			"UTF-8"
+		);
+		assert_eq!(
+			HTMLEncoding::sniff(b"<p>\xE9", None).name(),
+			"windows-1252"
+		);
+	}
+
+	#[test]
+	fn test_content_type_charset()
+	{
+		assert_eq!(
+			HTMLEncoding::content_type_charset("text/html;Charset=\"UTF-8\""),
+			Some("UTF-8")
+		);
+		assert_eq!(
+			HTMLEncoding::content_type_charset(
+				"text/html; q=1; charset=latin1"
+			),
+			Some("latin1")
+		);
+		assert_eq!(HTMLEncoding::content_type_charset("text/html"), None);
+	}
+
+	#[test]
+	fn test_decode()
+	{
+		let encoding = HTMLEncoding::sniff(b"\xEF\xBB\xBF<p>\xC3\xA9", None);
+		assert_eq!(encoding.decode(b"\xEF\xBB\xBF<p>\xC3\xA9"), "<p>é");
+
+		let encoding = HTMLEncoding::sniff(b"<p>\xE9\x80", None);
+		assert_eq!(encoding.decode(b"<p>\xE9\x80"), "<p>é€");
+	}
+}
@@ -8,7 +8,7 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use sky_lang::Location;
+use sky_lang::{Diagnostic, Location};
 
 // --------- //
 // Structure //
@@ -26,6 +26,17 @@ pub struct HTMLLexicalError
 	location: Location,
 }
 
+// --------- //
+// Interface //
+// --------- //
+
+/// Collecteur des erreurs d'analyse récupérables du fournisseur de jeton.
+pub trait HTMLLexicalErrorSink
+{
+	/// Reçoit une erreur d'analyse, accompagnée de sa position.
+	fn report(&mut self, error: HTMLLexicalError);
+}
+
 // ----------- //
 // Énumération //
 // ----------- //
@@ -34,6 +45,68 @@ pub struct HTMLLexicalError
 #[derive(thiserror::Error)]
 pub enum HTMLLexicalErrorVariant
 {
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un
+	/// commentaire vide qui est brusquement fermé par un point de code U+003E
+	/// (>) (c'est-à-dire `<!-->` ou `<!--->`). L'analyseur syntaxique se
+	/// comporte comme si le commentaire était correctement fermé.
+	#[error("Fermeture abrupte d'un commentaire vide")]
+	AbruptClosingOfEmptyComment,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un point de
+	/// code U+003E (>) dans l'identifiant public du DOCTYPE (par exemple,
+	/// `<!DOCTYPE html PUBLIC "foo>`). Dans ce cas, si le DOCTYPE est
+	/// correctement placé en tant que préambule du document, l'analyseur
+	/// syntaxique définit le document en mode quirks.
+	#[error("Fermeture abrupte de l'identifiant public du DOCTYPE")]
+	AbruptDoctypePublicIdentifier,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un point de
+	/// code U+003E (>) dans l'identifiant système du DOCTYPE (par exemple,
+	/// `<!DOCTYPE html PUBLIC "-//W3C//DTD HTML 4.01//EN" "foo>`). Dans ce
+	/// cas, si le DOCTYPE est correctement placé en tant que préambule du
+	/// document, l'analyseur syntaxique définit le document en mode quirks.
+	#[error("Fermeture abrupte de l'identifiant système du DOCTYPE")]
+	AbruptDoctypeSystemIdentifier,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une
+	/// référence de caractère numérique qui ne contient aucun chiffre (par
+	/// exemple, `&#qux;`). Dans ce cas, l'analyseur syntaxique ne résout
+	/// aucune référence de caractère.
+	#[error("Aucun chiffre dans la référence de caractère numérique")]
+	AbsenceOfDigitsInNumericCharacterReference,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une section
+	/// CDATA en dehors d'un contenu étranger (SVG ou MathML). L'analyseur
+	/// syntaxique traite de telles sections CDATA (y compris les chaînes
+	/// `[CDATA[` et `]]`) comme des commentaires.
+	#[error("Section CDATA dans du contenu HTML")]
+	CdataInHtmlContent,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une
+	/// référence de caractère numérique qui fait référence à un point de code
+	/// supérieur à la plage valide d'Unicode. L'analyseur syntaxique résout
+	/// une telle référence de caractère en un CARACTÈRE DE REMPLACEMENT
+	/// U+FFFD.
+	#[error("Référence de caractère en dehors de la plage Unicode")]
+	CharacterReferenceOutsideUnicodeRange,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une
+	/// référence de caractère numérique qui fait référence à un point de code
+	/// de contrôle qui n'est pas un espace blanc ASCII ou qui est un U+000D
+	/// CARRIAGE RETURN. L'analyseur syntaxique résout ces références de
+	/// caractères telles quelles, à l'exception des références C1 qui sont
+	/// remplacées selon la table de remplacement des références de
+	/// caractères numériques.
+	#[error("Référence vers un caractère de contrôle")]
+	ControlCharacterReference,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un attribut
+	/// dans une balise qui possède déjà un attribut du même nom. L'analyseur
+	/// syntaxique ignore toutes les occurrences de cet attribut à l'exception
+	/// de la première.
+	#[error("Attribut en double")]
+	DuplicateAttribute,
+
 	/// Cette erreur se produit si l'analyseur syntaxique rencontre la fin du
 	/// flux d'entrée où un nom de balise est attendu. Dans ce cas, l'analyseur
 	/// traite le début d'une balise de début (<) ou d'une balise de fin (</)
@@ -41,12 +114,87 @@ pub enum HTMLLexicalErrorVariant
 	#[error("Fin du flux avant le nom de balise")]
 	EndOfStreamBeforeTagName,
 
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre la fin du
+	/// flux d'entrée dans une section CDATA. L'analyseur syntaxique traite de
+	/// telles sections CDATA comme si elles étaient fermées immédiatement
+	/// avant la fin du flux d'entrée.
+	#[error("Fin du flux dans une section CDATA")]
+	EndOfStreamInCdata,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre la fin du
+	/// flux d'entrée dans un commentaire. L'analyseur syntaxique traite de
+	/// tels commentaires comme s'ils étaient fermés immédiatement avant la
+	/// fin du flux d'entrée.
+	#[error("Fin du flux dans un commentaire")]
+	EndOfStreamInComment,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre la fin du
+	/// flux d'entrée dans un DOCTYPE. Dans ce cas, si le DOCTYPE est
+	/// correctement placé en tant que préambule du document, l'analyseur
+	/// syntaxique définit le document en mode quirks.
+	#[error("Fin du flux dans un DOCTYPE")]
+	EndOfStreamInDoctype,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre la fin du
+	/// flux d'entrée dans un texte ressemblant à un commentaire HTML à
+	/// l'intérieur d'un élément `script` (par exemple, `<script><!-- foo`).
+	#[error(
+		"Fin du flux dans un texte ressemblant à un commentaire HTML dans un \
+		 script"
+	)]
+	EndOfStreamInScriptHtmlCommentLikeText,
+
 	/// Cette erreur se produit si l'analyseur syntaxique rencontre la fin
 	/// du flux d'entrée dans une balise de début ou une balise de fin
 	/// (par exemple, `<div id=`). Une telle balise est ignorée.
 	#[error("Fin du flux dans la balise")]
 	EndOfStreamInTag,
 
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une balise
+	/// de fin avec des attributs. Les attributs des balises de fin sont
+	/// complètement ignorés et ne sont pas intégrés au DOM.
+	#[error("Balise de fin avec des attributs")]
+	EndTagWithAttributes,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une balise
+	/// de fin dont le drapeau d'auto-fermeture est défini (par exemple,
+	/// `</div/>`). Une telle balise de fin est traitée comme une balise de fin
+	/// normale.
+	#[error("Balise de fin avec une barre oblique finale")]
+	EndTagWithTrailingSolidus,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un
+	/// commentaire fermé par la séquence de points de code `--!>`.
+	/// L'analyseur syntaxique traite un tel commentaire comme s'il était
+	/// correctement fermé par la séquence `-->`.
+	#[error("Commentaire incorrectement fermé")]
+	IncorrectlyClosedComment,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre la séquence
+	/// de points de code `<!` qui n'est pas immédiatement suivie de deux
+	/// points de code U+002D (-) et qui n'est pas le début d'un DOCTYPE ou
+	/// d'une section CDATA. Tout le contenu qui suit est traité comme un
+	/// commentaire, jusqu'à un point de code U+003E (>) (s'il est présent) ou
+	/// jusqu'à la fin du flux d'entrée.
+	///
+	/// NOTE(html): une cause courante de cette erreur est l'utilisation d'une
+	/// déclaration de balisage XML (par exemple, `<!ELEMENT br EMPTY>`) dans
+	/// l'HTML.
+	#[error("Commentaire incorrectement ouvert")]
+	IncorrectlyOpenedComment,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une
+	/// séquence de points de code autre que les mots-clés "PUBLIC" et
+	/// "SYSTEM" après le nom d'un DOCTYPE. Dans ce cas, l'analyseur syntaxique
+	/// ignore tout identifiant public ou système qui suit, et si le DOCTYPE
+	/// est correctement placé en tant que préambule du document, l'analyseur
+	/// syntaxique définit le document en mode quirks.
+	#[error(
+		"Séquence de caractères invalide après le nom du DOCTYPE, `PUBLIC` ou \
+		 `SYSTEM` attendu"
+	)]
+	InvalidCharacterSequenceAfterDoctypeName,
+
 	/// Cette erreur se produit si l'analyseur rencontre un point de code
 	/// qui n'est pas un alpha ASCII où le premier point de code d'une
 	/// balise de début ou d'une balise de fin est attendu. Si une balise
@@ -79,12 +227,36 @@ pub enum HTMLLexicalErrorVariant
 		found: char
 	},
 
-	/// Cette erreur se produit si l'analyseur rencontre un point de code U+003E
-	/// (>) là où une valeur d'attribut est attendue (par exemple, `<div id=>`).
-	/// L'analyseur syntaxique traite l'attribut comme ayant une valeur vide.
+	/// Cette erreur se produit si l'analyseur rencontre un point de code
+	/// U+003E (>) là où une valeur d'attribut est attendue (par exemple,
+	/// `<div id=>`). L'analyseur syntaxique traite l'attribut comme ayant une
+	/// valeur vide.
 	#[error("Caractère '>' trouvé, une valeur d'attribut est attendue")]
 	MissingAttributeValue,
 
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un DOCTYPE
+	/// dont le nom est manquant (par exemple, `<!DOCTYPE>`). Dans ce cas, si
+	/// le DOCTYPE est correctement placé en tant que préambule du document,
+	/// l'analyseur syntaxique définit le document en mode quirks.
+	#[error("Nom du DOCTYPE manquant")]
+	MissingDoctypeName,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un point de
+	/// code U+003E (>) là où l'identifiant public du DOCTYPE est attendu (par
+	/// exemple, `<!DOCTYPE html PUBLIC >`). Dans ce cas, si le DOCTYPE est
+	/// correctement placé en tant que préambule du document, l'analyseur
+	/// syntaxique définit le document en mode quirks.
+	#[error("Identifiant public du DOCTYPE manquant")]
+	MissingDoctypePublicIdentifier,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un point de
+	/// code U+003E (>) là où l'identifiant système du DOCTYPE est attendu (par
+	/// exemple, `<!DOCTYPE html SYSTEM >`). Dans ce cas, si le DOCTYPE est
+	/// correctement placé en tant que préambule du document, l'analyseur
+	/// syntaxique définit le document en mode quirks.
+	#[error("Identifiant système du DOCTYPE manquant")]
+	MissingDoctypeSystemIdentifier,
+
 	/// Cette erreur se produit si l'analyseur rencontre un point de code
 	/// U+003E (>) là où un nom de balise de fin est attendu, c'est-à-dire </>.
 	/// L'analyseur syntaxique ignore l'ensemble de la séquence de points de
@@ -92,13 +264,115 @@ pub enum HTMLLexicalErrorVariant
 	#[error("Caractère '>' manquant")]
 	MissingEndTagName,
 
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un
+	/// identifiant public de DOCTYPE qui n'est pas précédé d'un guillemet
+	/// (par exemple, `<!DOCTYPE html PUBLIC -//W3C//DTD HTML 4.01//EN">`).
+	/// Dans ce cas, l'analyseur syntaxique ignore l'identifiant public, et si
+	/// le DOCTYPE est correctement placé en tant que préambule du document,
+	/// l'analyseur syntaxique définit le document en mode quirks.
+	#[error("Guillemet manquant avant l'identifiant public du DOCTYPE")]
+	MissingQuoteBeforeDoctypePublicIdentifier,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un
+	/// identifiant système de DOCTYPE qui n'est pas précédé d'un guillemet
+	/// (par exemple, `<!DOCTYPE html SYSTEM
+	/// http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd">`). Dans ce cas,
+	/// l'analyseur syntaxique ignore l'identifiant système, et si le DOCTYPE
+	/// est correctement placé en tant que préambule du document, l'analyseur
+	/// syntaxique définit le document en mode quirks.
+	#[error("Guillemet manquant avant l'identifiant système du DOCTYPE")]
+	MissingQuoteBeforeDoctypeSystemIdentifier,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une
+	/// référence de caractère qui n'est pas terminée par un point de code
+	/// U+003B (;). Habituellement, l'analyseur syntaxique se comporte comme si
+	/// la référence de caractère était terminée par un point de code U+003B
+	/// (;) ; cependant, il existe certaines situations ambiguës dans
+	/// lesquelles l'analyseur syntaxique inclut les points de code suivants
+	/// dans la référence de caractère.
+	///
+	/// Example: `<p>&not-a-character</p>` est interprété comme
+	/// `¬-a-character`, mais `<a href="?q&not=1">` conserve la valeur
+	/// `?q&not=1`.
+	#[error("Point-virgule manquant après la référence de caractère")]
+	MissingSemicolonAfterCharacterReference,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un DOCTYPE
+	/// dont le mot-clé "PUBLIC" et l'identifiant public ne sont pas séparés
+	/// par un espace blanc ASCII (par exemple, `<!DOCTYPE html
+	/// PUBLIC"-//W3C//DTD HTML 4.01//EN">`). Dans ce cas, l'analyseur se
+	/// comporte comme si un espace blanc ASCII était présent.
+	#[error("Espace blanc manquant après le mot-clé `PUBLIC` du DOCTYPE")]
+	MissingWhitespaceAfterDoctypePublicKeyword,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un DOCTYPE
+	/// dont le mot-clé "SYSTEM" et l'identifiant système ne sont pas séparés
+	/// par un espace blanc ASCII (par exemple, `<!DOCTYPE html
+	/// SYSTEM"http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd">`). Dans ce
+	/// cas, l'analyseur se comporte comme si un espace blanc ASCII était
+	/// présent.
+	#[error("Espace blanc manquant après le mot-clé `SYSTEM` du DOCTYPE")]
+	MissingWhitespaceAfterDoctypeSystemKeyword,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un DOCTYPE
+	/// dont le mot-clé "DOCTYPE" et le nom ne sont pas séparés par un espace
+	/// blanc ASCII (par exemple, `<!DOCTYPEhtml>`). Dans ce cas, l'analyseur
+	/// se comporte comme si un espace blanc ASCII était présent.
+	#[error("Espace blanc manquant avant le nom du DOCTYPE")]
+	MissingWhitespaceBeforeDoctypeName,
+
 	/// Cette erreur se produit si l'analyseur rencontre des attributs qui ne
 	/// sont pas séparés par des espaces blancs ASCII (par exemple, `<div
-	/// id="foo"class="bar">`). Dans ce cas, l'analyseur se comporte comme si un
-	/// espace blanc ASCII était présent.
+	/// id="foo"class="bar">`). Dans ce cas, l'analyseur se comporte comme si
+	/// un espace blanc ASCII était présent.
 	#[error("Espaces blancs manquant")]
 	MissingWhitespaceBetweenAttributes,
 
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un DOCTYPE
+	/// dont les identifiants public et système ne sont pas séparés par un
+	/// espace blanc ASCII. Dans ce cas, l'analyseur se comporte comme si un
+	/// espace blanc ASCII était présent.
+	#[error(
+		"Espace blanc manquant entre les identifiants public et système du \
+		 DOCTYPE"
+	)]
+	MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un
+	/// commentaire imbriqué (par exemple, `<!-- <!-- imbriqué --> -->`). Un
+	/// tel commentaire sera fermé par la première séquence de points de code
+	/// `-->` rencontrée et tout ce qui suit sera traité comme du balisage.
+	#[error("Commentaire imbriqué")]
+	NestedComment,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une
+	/// référence de caractère numérique qui fait référence à un non-caractère
+	/// Unicode. L'analyseur syntaxique résout ces références de caractères
+	/// telles quelles.
+	#[error("Référence vers un non-caractère")]
+	NoncharacterCharacterReference,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une
+	/// référence de caractère numérique qui fait référence au point de code
+	/// U+0000 NULL. L'analyseur syntaxique résout une telle référence de
+	/// caractère en un CARACTÈRE DE REMPLACEMENT U+FFFD.
+	#[error("Référence vers le caractère NULL")]
+	NullCharacterReference,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une
+	/// référence de caractère numérique qui fait référence à un substitut
+	/// (surrogate). L'analyseur syntaxique résout une telle référence de
+	/// caractère en un CARACTÈRE DE REMPLACEMENT U+FFFD.
+	#[error("Référence vers un substitut (surrogate)")]
+	SurrogateCharacterReference,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre des points
+	/// de code autres que des espaces blancs ASCII ou le point de code U+003E
+	/// (>) après l'identifiant système du DOCTYPE. L'analyseur syntaxique
+	/// ignore ces points de code.
+	#[error("Caractère inattendu après l'identifiant système du DOCTYPE")]
+	UnexpectedCharacterAfterDoctypeSystemIdentifier,
+
 	/// Cette erreur se produit si l'analyseur syntaxique rencontre un point de
 	/// code U+0022 ("), U+0027 (') ou U+003C (<) dans un nom d'attribut.
 	/// L'analyseur syntaxique inclut ces points de code dans le nom de
@@ -189,6 +463,23 @@ pub enum HTMLLexicalErrorVariant
 	#[error("Caractère NULL inattendu")]
 	UnexpectedNullCharacter,
 
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre un point de
+	/// code U+002F (/) qui ne fait pas partie d'une valeur d'attribut entre
+	/// guillemets et qui n'est pas immédiatement suivi d'un point de code
+	/// U+003E (>) dans une balise (par exemple, `<div / id="foo">`). Dans ce
+	/// cas, l'analyseur syntaxique se comporte comme s'il rencontrait un
+	/// espace blanc ASCII.
+	#[error("Caractère '/' inattendu dans la balise")]
+	UnexpectedSolidusInTag,
+
+	/// Cette erreur se produit si l'analyseur syntaxique rencontre une
+	/// séquence de points de code alphanumériques ASCII suivie d'un point de
+	/// code U+003B (;) qui ne correspond à aucune référence de caractère
+	/// nommée (par exemple, `&qux;`). L'analyseur syntaxique traite cette
+	/// séquence comme du contenu textuel.
+	#[error("Référence de caractère nommée inconnue")]
+	UnknownNamedCharacterReference,
+
 	#[error("Unknown")]
 	Unknown,
 }
@@ -199,6 +490,71 @@ pub enum HTMLLexicalErrorVariant
 
 impl HTMLLexicalError
 {
+	pub const fn abrupt_closing_of_empty_comment() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::AbruptClosingOfEmptyComment,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn abrupt_doctype_public_identifier() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::AbruptDoctypePublicIdentifier,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn abrupt_doctype_system_identifier() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::AbruptDoctypeSystemIdentifier,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn absence_of_digits_in_numeric_character_reference() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::AbsenceOfDigitsInNumericCharacterReference,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn cdata_in_html_content() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::CdataInHtmlContent,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn character_reference_outside_unicode_range() -> Self
+	{
+		Self {
+			variant:
+				HTMLLexicalErrorVariant::CharacterReferenceOutsideUnicodeRange,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn control_character_reference() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::ControlCharacterReference,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn duplicate_attribute() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::DuplicateAttribute,
+			location: Location::new(),
+		}
+	}
+
 	pub const fn end_of_stream_before_tag_name() -> Self
 	{
 		Self {
@@ -207,6 +563,39 @@ impl HTMLLexicalError
 		}
 	}
 
+	pub const fn end_of_stream_in_cdata() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::EndOfStreamInCdata,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn end_of_stream_in_comment() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::EndOfStreamInComment,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn end_of_stream_in_doctype() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::EndOfStreamInDoctype,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn end_of_stream_in_script_html_comment_like_text() -> Self
+	{
+		Self {
+			variant:
+				HTMLLexicalErrorVariant::EndOfStreamInScriptHtmlCommentLikeText,
+			location: Location::new(),
+		}
+	}
+
 	pub const fn end_of_stream_in_tag() -> Self
 	{
 		Self {
@@ -215,6 +604,22 @@ impl HTMLLexicalError
 		}
 	}
 
+	pub const fn end_tag_with_attributes() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::EndTagWithAttributes,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn end_tag_with_trailing_solidus() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::EndTagWithTrailingSolidus,
+			location: Location::new(),
+		}
+	}
+
 	pub const fn idk() -> Self
 	{
 		Self {
@@ -223,6 +628,30 @@ impl HTMLLexicalError
 		}
 	}
 
+	pub const fn incorrectly_closed_comment() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::IncorrectlyClosedComment,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn incorrectly_opened_comment() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::IncorrectlyOpenedComment,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn invalid_character_sequence_after_doctype_name() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::InvalidCharacterSequenceAfterDoctypeName,
+			location: Location::new(),
+		}
+	}
+
 	pub const fn invalid_first_character_of_tag_name(found: char) -> Self
 	{
 		Self {
@@ -241,6 +670,30 @@ impl HTMLLexicalError
 		}
 	}
 
+	pub const fn missing_doctype_name() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::MissingDoctypeName,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn missing_doctype_public_identifier() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::MissingDoctypePublicIdentifier,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn missing_doctype_system_identifier() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::MissingDoctypeSystemIdentifier,
+			location: Location::new(),
+		}
+	}
+
 	pub const fn missing_end_tag_name() -> Self
 	{
 		Self {
@@ -249,10 +702,110 @@ impl HTMLLexicalError
 		}
 	}
 
+	pub const fn missing_quote_before_doctype_public_identifier() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::MissingQuoteBeforeDoctypePublicIdentifier,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn missing_quote_before_doctype_system_identifier() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::MissingQuoteBeforeDoctypeSystemIdentifier,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn missing_semicolon_after_character_reference() -> Self
+	{
+		Self {
+			variant:
+				HTMLLexicalErrorVariant::MissingSemicolonAfterCharacterReference,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn missing_whitespace_after_doctype_public_keyword() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::MissingWhitespaceAfterDoctypePublicKeyword,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn missing_whitespace_after_doctype_system_keyword() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::MissingWhitespaceAfterDoctypeSystemKeyword,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn missing_whitespace_before_doctype_name() -> Self
+	{
+		Self {
+			variant:
+				HTMLLexicalErrorVariant::MissingWhitespaceBeforeDoctypeName,
+			location: Location::new(),
+		}
+	}
+
 	pub const fn missing_whitespace_between_attributes() -> Self
 	{
 		Self {
-			variant: HTMLLexicalErrorVariant::MissingWhitespaceBetweenAttributes,
+			variant:
+				HTMLLexicalErrorVariant::MissingWhitespaceBetweenAttributes,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn missing_whitespace_between_doctype_public_and_system_identifiers(
+	) -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn nested_comment() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::NestedComment,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn noncharacter_character_reference() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::NoncharacterCharacterReference,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn null_character_reference() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::NullCharacterReference,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn surrogate_character_reference() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::SurrogateCharacterReference,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn unexpected_character_after_doctype_system_identifier() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::UnexpectedCharacterAfterDoctypeSystemIdentifier,
 			location: Location::new(),
 		}
 	}
@@ -306,13 +859,129 @@ impl HTMLLexicalError
 			location: Location::new(),
 		}
 	}
+
+	pub const fn unexpected_solidus_in_tag() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::UnexpectedSolidusInTag,
+			location: Location::new(),
+		}
+	}
+
+	pub const fn unknown_named_character_reference() -> Self
+	{
+		Self {
+			variant: HTMLLexicalErrorVariant::UnknownNamedCharacterReference,
+			location: Location::new(),
+		}
+	}
+}
+
+impl HTMLLexicalErrorVariant
+{
+	/// Code de l'erreur tel que défini par la spécification, ex:
+	/// `eof-in-tag`.
+	pub const fn code(&self) -> &'static str
+	{
+		match self {
+			| Self::AbruptClosingOfEmptyComment => "abrupt-closing-of-empty-comment",
+			| Self::AbruptDoctypePublicIdentifier => "abrupt-doctype-public-identifier",
+			| Self::AbruptDoctypeSystemIdentifier => "abrupt-doctype-system-identifier",
+			| Self::AbsenceOfDigitsInNumericCharacterReference => "absence-of-digits-in-numeric-character-reference",
+			| Self::CdataInHtmlContent => "cdata-in-html-content",
+			| Self::CharacterReferenceOutsideUnicodeRange => "character-reference-outside-unicode-range",
+			| Self::ControlCharacterReference => "control-character-reference",
+			| Self::DuplicateAttribute => "duplicate-attribute",
+			| Self::EndOfStreamBeforeTagName => "eof-before-tag-name",
+			| Self::EndOfStreamInCdata => "eof-in-cdata",
+			| Self::EndOfStreamInComment => "eof-in-comment",
+			| Self::EndOfStreamInDoctype => "eof-in-doctype",
+			| Self::EndOfStreamInScriptHtmlCommentLikeText => "eof-in-script-html-comment-like-text",
+			| Self::EndOfStreamInTag => "eof-in-tag",
+			| Self::EndTagWithAttributes => "end-tag-with-attributes",
+			| Self::EndTagWithTrailingSolidus => "end-tag-with-trailing-solidus",
+			| Self::IncorrectlyClosedComment => "incorrectly-closed-comment",
+			| Self::IncorrectlyOpenedComment => "incorrectly-opened-comment",
+			| Self::InvalidCharacterSequenceAfterDoctypeName => "invalid-character-sequence-after-doctype-name",
+			| Self::InvalidFirstCharacterOfTagName { .. } => "invalid-first-character-of-tag-name",
+			| Self::MissingAttributeValue => "missing-attribute-value",
+			| Self::MissingDoctypeName => "missing-doctype-name",
+			| Self::MissingDoctypePublicIdentifier => "missing-doctype-public-identifier",
+			| Self::MissingDoctypeSystemIdentifier => "missing-doctype-system-identifier",
+			| Self::MissingEndTagName => "missing-end-tag-name",
+			| Self::MissingQuoteBeforeDoctypePublicIdentifier => "missing-quote-before-doctype-public-identifier",
+			| Self::MissingQuoteBeforeDoctypeSystemIdentifier => "missing-quote-before-doctype-system-identifier",
+			| Self::MissingSemicolonAfterCharacterReference => "missing-semicolon-after-character-reference",
+			| Self::MissingWhitespaceAfterDoctypePublicKeyword => "missing-whitespace-after-doctype-public-keyword",
+			| Self::MissingWhitespaceAfterDoctypeSystemKeyword => "missing-whitespace-after-doctype-system-keyword",
+			| Self::MissingWhitespaceBeforeDoctypeName => "missing-whitespace-before-doctype-name",
+			| Self::MissingWhitespaceBetweenAttributes => "missing-whitespace-between-attributes",
+			| Self::MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers => "missing-whitespace-between-doctype-public-and-system-identifiers",
+			| Self::NestedComment => "nested-comment",
+			| Self::NoncharacterCharacterReference => "noncharacter-character-reference",
+			| Self::NullCharacterReference => "null-character-reference",
+			| Self::SurrogateCharacterReference => "surrogate-character-reference",
+			| Self::UnexpectedCharacterAfterDoctypeSystemIdentifier => "unexpected-character-after-doctype-system-identifier",
+			| Self::UnexpectedCharacterInAttributeName { .. } => "unexpected-character-in-attribute-name",
+			| Self::UnexpectedCharacterInUnquotedAttributeValue { .. } => "unexpected-character-in-unquoted-attribute-value",
+			| Self::UnexpectedEqualsSignBeforeAttributeName => "unexpected-equals-sign-before-attribute-name",
+			| Self::UnexpectedQuestionMarkInsteadOfTagName => "unexpected-question-mark-instead-of-tag-name",
+			| Self::UnexpectedNullCharacter => "unexpected-null-character",
+			| Self::UnexpectedSolidusInTag => "unexpected-solidus-in-tag",
+			| Self::UnknownNamedCharacterReference => "unknown-named-character-reference",
+			| Self::Unknown => "unknown",
+		}
+	}
 }
 
 impl HTMLLexicalError
 {
+	/// Type de l'erreur.
+	pub const fn variant(&self) -> &HTMLLexicalErrorVariant
+	{
+		&self.variant
+	}
+
+	/// Position de l'erreur dans le flux, la source.
+	pub const fn location(&self) -> Location
+	{
+		self.location
+	}
+
 	pub fn with_location(mut self, location: Location) -> Self
 	{
 		self.location = location;
 		self
 	}
+
+	/// Diagnostic de l'erreur, à afficher avec un extrait de la source, voir
+	/// [sky_lang::DiagnosticRenderer].
+	pub fn to_diagnostic(&self) -> Diagnostic
+	{
+		Diagnostic::error(self.variant.to_string())
+			.with_code(self.variant.code())
+			.with_primary_label(self.location, "")
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl HTMLLexicalErrorSink for Vec<HTMLLexicalError>
+{
+	fn report(&mut self, error: HTMLLexicalError)
+	{
+		self.push(error);
+	}
+}
+
+impl<F> HTMLLexicalErrorSink for F
+where
+	F: FnMut(HTMLLexicalError),
+{
+	fn report(&mut self, error: HTMLLexicalError)
+	{
+		self(error);
+	}
 }
@@ -8,6 +8,7 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::collections::HashSet;
 use std::fmt;
 
 // ----------- //
@@ -25,6 +26,19 @@ pub enum HTMLLexeme
 		String,
 	),
 
+	/// Déclaration du type de document, ex: `<!DOCTYPE html>`
+	Doctype
+	{
+		/// Nom du DOCTYPE, ex: "html"
+		name: Option<String>,
+		/// Identifiant public, ex: "-//W3C//DTD HTML 4.01//EN"
+		public_id: Option<String>,
+		/// Identifiant système, ex: "http://www.w3.org/TR/html4/strict.dtd"
+		system_id: Option<String>,
+		/// Force le mode quirks du document.
+		force_quirks: bool,
+	},
+
 	/// Balise HTML, ex: `<button onclick="...">`, `</div>`, `<input
 	/// type="text" />`
 	Tag
@@ -63,6 +77,76 @@ pub enum TagState
 
 impl HTMLLexeme
 {
+	/// Ajoute un caractère au contenu du commentaire courant.
+	pub fn add_character_to_comment(&mut self, ch: char)
+	{
+		assert!(matches!(self, Self::Comment(_)));
+
+		if let Self::Comment(data) = self {
+			data.push(ch);
+		}
+	}
+
+	/// Ajoute un caractère au nom du DOCTYPE courant.
+	pub fn add_character_to_doctype_name(&mut self, ch: char)
+	{
+		assert!(matches!(self, Self::Doctype { .. }));
+
+		if let Self::Doctype { name, .. } = self {
+			name.get_or_insert_with(String::default).push(ch);
+		}
+	}
+
+	/// Ajoute un caractère à l'identifiant public du DOCTYPE courant.
+	pub fn add_character_to_doctype_public_identifier(&mut self, ch: char)
+	{
+		assert!(matches!(self, Self::Doctype { .. }));
+
+		if let Self::Doctype { public_id, .. } = self {
+			public_id.get_or_insert_with(String::default).push(ch);
+		}
+	}
+
+	/// Ajoute un caractère à l'identifiant système du DOCTYPE courant.
+	pub fn add_character_to_doctype_system_identifier(&mut self, ch: char)
+	{
+		assert!(matches!(self, Self::Doctype { .. }));
+
+		if let Self::Doctype { system_id, .. } = self {
+			system_id.get_or_insert_with(String::default).push(ch);
+		}
+	}
+
+	/// Définit le drapeau force-quirks du DOCTYPE courant.
+	pub fn set_force_quirks_flag(&mut self)
+	{
+		assert!(matches!(self, Self::Doctype { .. }));
+
+		if let Self::Doctype { force_quirks, .. } = self {
+			*force_quirks = true;
+		}
+	}
+
+	/// Définit un identifiant public vide pour le DOCTYPE courant.
+	pub fn start_empty_doctype_public_identifier(&mut self)
+	{
+		assert!(matches!(self, Self::Doctype { .. }));
+
+		if let Self::Doctype { public_id, .. } = self {
+			public_id.replace(String::default());
+		}
+	}
+
+	/// Définit un identifiant système vide pour le DOCTYPE courant.
+	pub fn start_empty_doctype_system_identifier(&mut self)
+	{
+		assert!(matches!(self, Self::Doctype { .. }));
+
+		if let Self::Doctype { system_id, .. } = self {
+			system_id.replace(String::default());
+		}
+	}
+
 	/// Ajoute un caractère au nom de la balise courante.
 	pub fn add_character_to_tag_name(&mut self, ch: char)
 	{
@@ -99,6 +183,20 @@ impl HTMLLexeme
 		}
 	}
 
+	/// Définit le drapeau d'auto-fermeture de la balise courante.
+	///
+	/// NOTE: une balise de fin n'a pas d'état auto-fermé, elle reste fermée.
+	pub fn set_self_closing_tag(&mut self)
+	{
+		assert!(matches!(self, Self::Tag { .. }));
+
+		if let Self::Tag { state, .. } = self {
+			if *state == TagState::Opened {
+				*state = TagState::SelfClosed;
+			}
+		}
+	}
+
 	/// Ajoute un attribut vide pour la balise courante.
 	pub fn start_empty_attribute_for_tag(&mut self)
 	{
@@ -119,6 +217,29 @@ impl HTMLLexeme
 			attributes.push((ch.to_string(), String::default()));
 		}
 	}
+
+	/// Vérifie que le dernier attribut de la balise courante porte le nom
+	/// d'un attribut précédent.
+	pub fn has_duplicate_last_attribute(&self) -> bool
+	{
+		let Self::Tag { attributes, .. } = self else {
+			return false;
+		};
+		let Some(((last_name, _), previous)) = attributes.split_last() else {
+			return false;
+		};
+		previous.iter().any(|(name, _)| name == last_name)
+	}
+
+	/// Retire les attributs de la balise dont le nom est déjà porté par un
+	/// attribut précédent: seule la première occurrence est conservée.
+	pub fn remove_duplicate_attributes(&mut self)
+	{
+		if let Self::Tag { attributes, .. } = self {
+			let mut names = HashSet::new();
+			attributes.retain(|(name, _)| names.insert(name.clone()));
+		}
+	}
 }
 
 // -------------- //
@@ -131,6 +252,7 @@ impl fmt::Display for HTMLLexeme
 	{
 		let name = match self {
 			| Self::Comment(_) => String::from("comment"),
+			| Self::Doctype { .. } => String::from("doctype"),
 			| Self::Tag { name, .. } => format!("tag-{}", name.to_owned()),
 			| Self::Character(ch) => ch.to_string(),
 			| Self::EndOfStream => String::from("eos"),
@@ -8,19 +8,41 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+mod entities;
 mod error;
 mod lexeme;
 mod stream
 {
+	mod cdata;
+	mod character_reference;
+	mod comment;
 	mod data;
+	mod doctype;
+	mod script;
 	mod tag;
+	mod text;
 
+	pub use self::cdata::*;
+	pub use self::character_reference::*;
+	pub use self::comment::*;
 	pub use self::data::*;
+	pub use self::doctype::*;
+	pub use self::script::*;
 	pub use self::tag::*;
+	pub use self::text::*;
 }
 mod token;
 mod tokenizer;
 
-pub use self::error::HTMLLexicalError;
+pub use self::error::{
+	HTMLLexicalError,
+	HTMLLexicalErrorSink,
+	HTMLLexicalErrorVariant,
+};
+pub use self::lexeme::{HTMLLexeme, TagState};
 pub use self::token::HTMLToken;
-pub use self::tokenizer::HTMLTokenizer;
+pub use self::tokenizer::{
+	HTMLTokenizer,
+	HTMLTokenizerMode,
+	HTMLTokenizerState,
+};
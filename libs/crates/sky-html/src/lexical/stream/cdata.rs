@@ -0,0 +1,124 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ops::ControlFlow;
+
+use sky_lang::UnitCodePointExt;
+
+use crate::lexical::error::HTMLLexicalError;
+use crate::lexical::token::HTMLToken;
+use crate::lexical::tokenizer::{
+	HTMLTokenizerErr,
+	HTMLTokenizerOk,
+	HTMLTokenizerState,
+};
+use crate::lexical::HTMLTokenizer;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLTokenizerCdataSectionStream
+{
+	fn handle_cdata_section_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_cdata_section_bracket_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_cdata_section_end_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLTokenizerCdataSectionStream for HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_cdata_section_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is(']') => {
+				self.current_state
+					.switch(HTMLTokenizerState::CdataSectionBracket);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			// NOTE: les caractères NULL sont émis tels quels, ils sont gérés
+			// par la construction de l'arbre.
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character(cp.unit()),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(HTMLTokenizerOk::EmitWithError(
+					HTMLToken::end_of_stream(),
+					HTMLLexicalError::end_of_stream_in_cdata(),
+				))
+			}
+		}
+	}
+
+	fn handle_cdata_section_bracket_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is(']') => {
+				self.current_state
+					.switch(HTMLTokenizerState::CdataSectionEnd);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::CdataSection);
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character(']'),
+				))
+			}
+		}
+	}
+
+	fn handle_cdata_section_end_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is(']') => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character(']'),
+				))
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::CdataSection);
+				ControlFlow::Continue(HTMLTokenizerOk::ManyEmit(vec![
+					HTMLToken::character(']'),
+					HTMLToken::character(']'),
+				]))
+			}
+		}
+	}
+}
@@ -0,0 +1,516 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ops::ControlFlow;
+
+use sky_lang::UnitCodePointExt;
+
+use crate::lexical::entities::{
+	NAMED_CHARACTER_REFERENCES,
+	NAMED_CHARACTER_REFERENCE_MAX_LENGTH,
+};
+use crate::lexical::error::HTMLLexicalError;
+use crate::lexical::tokenizer::{
+	HTMLTokenizerErr,
+	HTMLTokenizerOk,
+	HTMLTokenizerState,
+};
+use crate::lexical::HTMLTokenizer;
+use crate::HTMLToken;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLTokenizerCharacterReferenceStream
+{
+	fn handle_character_reference_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_named_character_reference_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_ambiguous_ampersand_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_numeric_character_reference_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_hexadecimal_character_reference_start_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_decimal_character_reference_start_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_hexadecimal_character_reference_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_decimal_character_reference_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_numeric_character_reference_end_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Recherche la plus longue référence de caractère nommée qui préfixe la
+/// séquence donnée.
+fn find_named_character_reference(
+	sequence: &str,
+) -> Option<(&'static str, &'static str)>
+{
+	(1..=sequence.len()).rev().find_map(|len| {
+		let name = &sequence[..len];
+		NAMED_CHARACTER_REFERENCES
+			.binary_search_by(|(entity, _)| entity.cmp(&name))
+			.ok()
+			.map(|idx| NAMED_CHARACTER_REFERENCES[idx])
+	})
+}
+
+/// Table de remplacement des références de caractères numériques de la plage
+/// C1 (U+0080 à U+009F), basée sur l'encodage windows-1252.
+const fn c1_replacement(code: u32) -> Option<u32>
+{
+	let replacement = match code {
+		| 0x80 => 0x20AC, // EURO SIGN (€)
+		| 0x82 => 0x201A, // SINGLE LOW-9 QUOTATION MARK (‚)
+		| 0x83 => 0x0192, // LATIN SMALL LETTER F WITH HOOK (ƒ)
+		| 0x84 => 0x201E, // DOUBLE LOW-9 QUOTATION MARK („)
+		| 0x85 => 0x2026, // HORIZONTAL ELLIPSIS (…)
+		| 0x86 => 0x2020, // DAGGER (†)
+		| 0x87 => 0x2021, // DOUBLE DAGGER (‡)
+		| 0x88 => 0x02C6, // MODIFIER LETTER CIRCUMFLEX ACCENT (ˆ)
+		| 0x89 => 0x2030, // PER MILLE SIGN (‰)
+		| 0x8A => 0x0160, // LATIN CAPITAL LETTER S WITH CARON (Š)
+		| 0x8B => 0x2039, // SINGLE LEFT-POINTING ANGLE QUOTATION MARK (‹)
+		| 0x8C => 0x0152, // LATIN CAPITAL LIGATURE OE (Œ)
+		| 0x8E => 0x017D, // LATIN CAPITAL LETTER Z WITH CARON (Ž)
+		| 0x91 => 0x2018, // LEFT SINGLE QUOTATION MARK (‘)
+		| 0x92 => 0x2019, // RIGHT SINGLE QUOTATION MARK (’)
+		| 0x93 => 0x201C, // LEFT DOUBLE QUOTATION MARK (“)
+		| 0x94 => 0x201D, // RIGHT DOUBLE QUOTATION MARK (”)
+		| 0x95 => 0x2022, // BULLET (•)
+		| 0x96 => 0x2013, // EN DASH (–)
+		| 0x97 => 0x2014, // EM DASH (—)
+		| 0x98 => 0x02DC, // SMALL TILDE (˜)
+		| 0x99 => 0x2122, // TRADE MARK SIGN (™)
+		| 0x9A => 0x0161, // LATIN SMALL LETTER S WITH CARON (š)
+		| 0x9B => 0x203A, // SINGLE RIGHT-POINTING ANGLE QUOTATION MARK (›)
+		| 0x9C => 0x0153, // LATIN SMALL LIGATURE OE (œ)
+		| 0x9E => 0x017E, // LATIN SMALL LETTER Z WITH CARON (ž)
+		| 0x9F => 0x0178, // LATIN CAPITAL LETTER Y WITH DIAERESIS (Ÿ)
+		| _ => return None,
+	};
+	Some(replacement)
+}
+
+/// Vérifie que le point de code est un non-caractère Unicode.
+const fn is_noncharacter(code: u32) -> bool
+{
+	matches!(code, 0xFDD0..=0xFDEF)
+		|| (code <= 0x10FFFF && (code & 0xFFFE) == 0xFFFE)
+}
+
+/// Vérifie que le point de code est un caractère de contrôle.
+const fn is_control(code: u32) -> bool
+{
+	matches!(code, 0x00..=0x1F | 0x7F..=0x9F)
+}
+
+/// Vérifie que le point de code est un espace blanc ASCII.
+const fn is_ascii_whitespace(code: u32) -> bool
+{
+	matches!(code, 0x09 | 0x0A | 0x0C | 0x0D | 0x20)
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<Input: Iterator> HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	/// Vérifie que la référence de caractère est consommée dans le cadre de la
+	/// valeur d'un attribut.
+	pub(crate) fn is_consumed_as_part_of_attribute(&self) -> bool
+	{
+		matches!(
+			self.return_state,
+			Some(HTMLTokenizerState::AttributeValue { .. })
+		)
+	}
+
+	/// Vide le tampon temporaire: les points de code sont ajoutés à la valeur
+	/// de l'attribut courant ou émis en tant que jetons de caractères, selon
+	/// l'état de retour.
+	fn flush_code_points_consumed_as_character_reference(
+		&mut self,
+		maybe_error: Option<HTMLLexicalError>,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		let buffer = std::mem::take(&mut self.temporary_buffer);
+
+		if self.is_consumed_as_part_of_attribute() {
+			let update = Box::new(move |token: &mut HTMLToken| {
+				for ch in buffer.chars() {
+					token.add_character_to_last_attribute_value_of_tag(ch);
+				}
+			});
+
+			return ControlFlow::Continue(match maybe_error {
+				| Some(err) => HTMLTokenizerOk::UpdateFnWithError(update, err),
+				| None => HTMLTokenizerOk::UpdateFn(update),
+			});
+		}
+
+		let tokens = buffer.chars().map(HTMLToken::character).collect();
+
+		ControlFlow::Continue(match maybe_error {
+			| Some(err) => HTMLTokenizerOk::ManyEmitWithError(tokens, err),
+			| None => HTMLTokenizerOk::ManyEmit(tokens),
+		})
+	}
+
+	/// État de retour, l'état des données par défaut.
+	fn return_state(&self) -> HTMLTokenizerState
+	{
+		self.return_state.unwrap_or(HTMLTokenizerState::Data)
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLTokenizerCharacterReferenceStream
+	for HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_character_reference_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.temporary_buffer = String::from('&');
+
+		match self.input.consume_next() {
+			| Some(cp) if cp.is__alphanumeric() => {
+				self.reconsume(HTMLTokenizerState::NamedCharacterReference);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('#') => {
+				self.temporary_buffer.push(cp.unit());
+				self.current_state
+					.switch(HTMLTokenizerState::NumericCharacterReference);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				let flow = self
+					.flush_code_points_consumed_as_character_reference(None);
+				self.reconsume(self.return_state());
+				flow
+			}
+		}
+	}
+
+	fn handle_named_character_reference_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		let next_sequence = self
+			.input
+			.peek_n(NAMED_CHARACTER_REFERENCE_MAX_LENGTH)
+			.iter()
+			.map(|item| item.unit())
+			.collect::<String>();
+
+		let mut name_len = next_sequence
+			.find(|ch: char| !ch.is_ascii_alphanumeric())
+			.unwrap_or(next_sequence.len());
+		if next_sequence[name_len..].starts_with(';') {
+			name_len += 1;
+		}
+
+		let Some((name, value)) =
+			find_named_character_reference(&next_sequence[..name_len])
+		else {
+			let flow =
+				self.flush_code_points_consumed_as_character_reference(None);
+			self.current_state
+				.switch(HTMLTokenizerState::AmbiguousAmpersand);
+			return flow;
+		};
+
+		self.consume_sequence(name);
+		self.temporary_buffer.push_str(name);
+
+		let is_terminated = name.ends_with(';');
+
+		// NOTE: pour des raisons historiques, une référence de caractère non
+		// terminée dans la valeur d'un attribut, suivie d'un `=` ou d'un
+		// caractère alphanumérique, n'est pas résolue.
+		if self.is_consumed_as_part_of_attribute()
+			&& !is_terminated
+			&& self.input.is_peek(|cp| cp.is('=') || cp.is__alphanumeric())
+		{
+			let flow =
+				self.flush_code_points_consumed_as_character_reference(None);
+			self.current_state.switch(self.return_state());
+			return flow;
+		}
+
+		let maybe_error = (!is_terminated).then(
+			HTMLLexicalError::missing_semicolon_after_character_reference,
+		);
+
+		self.temporary_buffer = value.to_owned();
+		let flow =
+			self.flush_code_points_consumed_as_character_reference(maybe_error);
+		self.current_state.switch(self.return_state());
+		flow
+	}
+
+	fn handle_ambiguous_ampersand_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is__alphanumeric() => {
+				let ch = cp.unit();
+
+				if self.is_consumed_as_part_of_attribute() {
+					ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(Box::new(
+						move |token| {
+							token.add_character_to_last_attribute_value_of_tag(
+								ch,
+							);
+						},
+					)))
+				} else {
+					ControlFlow::Continue(HTMLTokenizerOk::Emit(
+						HTMLToken::character(ch),
+					))
+				}
+			}
+
+			| Some(cp) if cp.is(';') => {
+				self.reconsume(self.return_state());
+				ControlFlow::Break(HTMLTokenizerErr::Emit(
+					HTMLLexicalError::unknown_named_character_reference(),
+				))
+			}
+
+			| _ => {
+				self.reconsume(self.return_state());
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		}
+	}
+
+	fn handle_numeric_character_reference_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.character_reference_code = 0;
+
+		match self.input.consume_next() {
+			| Some(cp) if cp.one_of(['x', 'X']) => {
+				self.temporary_buffer.push(cp.unit());
+				self.current_state.switch(
+					HTMLTokenizerState::HexadecimalCharacterReferenceStart,
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				self.reconsume(
+					HTMLTokenizerState::DecimalCharacterReferenceStart,
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		}
+	}
+
+	fn handle_hexadecimal_character_reference_start_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_hexdigit() => {
+				self.reconsume(
+					HTMLTokenizerState::HexadecimalCharacterReference,
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				let flow = self
+					.flush_code_points_consumed_as_character_reference(Some(
+					HTMLLexicalError::absence_of_digits_in_numeric_character_reference(),
+				));
+				self.reconsume(self.return_state());
+				flow
+			}
+		}
+	}
+
+	fn handle_decimal_character_reference_start_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is__digit() => {
+				self.reconsume(HTMLTokenizerState::DecimalCharacterReference);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				let flow = self
+					.flush_code_points_consumed_as_character_reference(Some(
+					HTMLLexicalError::absence_of_digits_in_numeric_character_reference(),
+				));
+				self.reconsume(self.return_state());
+				flow
+			}
+		}
+	}
+
+	fn handle_hexadecimal_character_reference_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_hexdigit() => {
+				let digit = cp.unit().to_digit(16).unwrap_or_default();
+				self.character_reference_code = self
+					.character_reference_code
+					.saturating_mul(16)
+					.saturating_add(digit);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is(';') => {
+				self.current_state
+					.switch(HTMLTokenizerState::NumericCharacterReferenceEnd);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				self.reconsume(
+					HTMLTokenizerState::NumericCharacterReferenceEnd,
+				);
+				ControlFlow::Break(HTMLTokenizerErr::Emit(
+					HTMLLexicalError::missing_semicolon_after_character_reference(),
+				))
+			}
+		}
+	}
+
+	fn handle_decimal_character_reference_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is__digit() => {
+				let digit = cp.unit().to_digit(10).unwrap_or_default();
+				self.character_reference_code = self
+					.character_reference_code
+					.saturating_mul(10)
+					.saturating_add(digit);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is(';') => {
+				self.current_state
+					.switch(HTMLTokenizerState::NumericCharacterReferenceEnd);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				self.reconsume(
+					HTMLTokenizerState::NumericCharacterReferenceEnd,
+				);
+				ControlFlow::Break(HTMLTokenizerErr::Emit(
+					HTMLLexicalError::missing_semicolon_after_character_reference(),
+				))
+			}
+		}
+	}
+
+	fn handle_numeric_character_reference_end_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		let (code, maybe_error) = match self.character_reference_code {
+			| 0x00 => {
+				(0xFFFD, Some(HTMLLexicalError::null_character_reference()))
+			}
+
+			| code if code > 0x10FFFF => (
+				0xFFFD,
+				Some(
+					HTMLLexicalError::character_reference_outside_unicode_range(
+					),
+				),
+			),
+
+			| code @ 0xD800..=0xDFFF => {
+				(
+					code,
+					Some(HTMLLexicalError::surrogate_character_reference()),
+				)
+			}
+
+			| code if is_noncharacter(code) => {
+				(
+					code,
+					Some(HTMLLexicalError::noncharacter_character_reference()),
+				)
+			}
+
+			| code if code == 0x0D
+				|| (is_control(code) && !is_ascii_whitespace(code)) =>
+			{
+				(
+					c1_replacement(code).unwrap_or(code),
+					Some(HTMLLexicalError::control_character_reference()),
+				)
+			}
+
+			| code => (code, None),
+		};
+
+		// NOTE: les substituts (surrogates) ne sont pas des `char` valides, ils
+		// sont remplacés par U+FFFD.
+		let ch = char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER);
+
+		self.temporary_buffer = String::from(ch);
+		let flow =
+			self.flush_code_points_consumed_as_character_reference(maybe_error);
+		self.current_state.switch(self.return_state());
+		flow
+	}
+}
@@ -0,0 +1,465 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ops::ControlFlow;
+
+use sky_lang::UnitCodePointExt;
+
+use crate::lexical::error::HTMLLexicalError;
+use crate::lexical::tokenizer::{
+	HTMLTokenizerErr,
+	HTMLTokenizerOk,
+	HTMLTokenizerState,
+};
+use crate::lexical::HTMLTokenizer;
+use crate::HTMLToken;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLTokenizerCommentStream
+{
+	fn handle_bogus_comment_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_markup_declaration_open_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_comment_start_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_comment_start_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_comment_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_comment_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_comment_less_than_sign_bang_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_comment_less_than_sign_bang_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_comment_less_than_sign_bang_dash_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_comment_end_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_comment_end_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_comment_end_bang_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Ajoute les caractères donnés au contenu du commentaire courant.
+fn update_comment(
+	chars: impl IntoIterator<Item = char>,
+) -> Box<dyn Fn(&mut HTMLToken)>
+{
+	let chars: Vec<char> = chars.into_iter().collect();
+	Box::new(move |token: &mut HTMLToken| {
+		for ch in chars.iter().copied() {
+			token.add_character_to_comment(ch);
+		}
+	})
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLTokenizerCommentStream for HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_bogus_comment_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrent)
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
+					update_comment([char::REPLACEMENT_CHARACTER]),
+					HTMLLexicalError::unexpected_null_character(),
+				))
+			}
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment([cp.unit()]),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(
+					HTMLTokenizerOk::EmitCurrentAndEndOfStream,
+				)
+			}
+		}
+	}
+
+	fn handle_markup_declaration_open_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		if self.is_next_sequence("--", false) {
+			self.consume_sequence("--");
+			self.current_state.switch(HTMLTokenizerState::CommentStart);
+			return ControlFlow::Continue(HTMLTokenizerOk::Update(
+				HTMLToken::empty_comment(),
+			));
+		}
+
+		if self.is_next_sequence("DOCTYPE", true) {
+			self.consume_sequence("DOCTYPE");
+			self.current_state.switch(HTMLTokenizerState::Doctype);
+			return ControlFlow::Continue(HTMLTokenizerOk::None);
+		}
+
+		if self.is_next_sequence("[CDATA[", false) {
+			self.consume_sequence("[CDATA[");
+
+			if self.in_foreign_content {
+				self.current_state.switch(HTMLTokenizerState::CdataSection);
+				return ControlFlow::Continue(HTMLTokenizerOk::None);
+			}
+
+			self.current_state.switch(HTMLTokenizerState::BogusComment);
+			return ControlFlow::Break(HTMLTokenizerErr::Update(
+				HTMLToken::comment("[CDATA["),
+				HTMLLexicalError::cdata_in_html_content(),
+			));
+		}
+
+		self.current_state.switch(HTMLTokenizerState::BogusComment);
+		ControlFlow::Break(HTMLTokenizerErr::Update(
+			HTMLToken::empty_comment(),
+			HTMLLexicalError::incorrectly_opened_comment(),
+		))
+	}
+
+	fn handle_comment_start_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.current_state
+					.switch(HTMLTokenizerState::CommentStartDash);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrentWithError(
+					HTMLLexicalError::abrupt_closing_of_empty_comment(),
+				))
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::Comment);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		}
+	}
+
+	fn handle_comment_start_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.current_state.switch(HTMLTokenizerState::CommentEnd);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrentWithError(
+					HTMLLexicalError::abrupt_closing_of_empty_comment(),
+				))
+			}
+
+			| Some(_) => {
+				self.reconsume(HTMLTokenizerState::Comment);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment(['-']),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(
+					HTMLTokenizerOk::EmitCurrentAndEndOfStreamWithError(
+						HTMLLexicalError::end_of_stream_in_comment(),
+					),
+				)
+			}
+		}
+	}
+
+	fn handle_comment_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('<') => {
+				self.current_state
+					.switch(HTMLTokenizerState::CommentLessThanSign);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment([cp.unit()]),
+				))
+			}
+
+			| Some(cp) if cp.is('-') => {
+				self.current_state
+					.switch(HTMLTokenizerState::CommentEndDash);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
+					update_comment([char::REPLACEMENT_CHARACTER]),
+					HTMLLexicalError::unexpected_null_character(),
+				))
+			}
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment([cp.unit()]),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(
+					HTMLTokenizerOk::EmitCurrentAndEndOfStreamWithError(
+						HTMLLexicalError::end_of_stream_in_comment(),
+					),
+				)
+			}
+		}
+	}
+
+	fn handle_comment_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('!') => {
+				self.current_state
+					.switch(HTMLTokenizerState::CommentLessThanSignBang);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment([cp.unit()]),
+				))
+			}
+
+			| Some(cp) if cp.is('<') => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment([cp.unit()]),
+				))
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::Comment);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		}
+	}
+
+	fn handle_comment_less_than_sign_bang_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.current_state
+					.switch(HTMLTokenizerState::CommentLessThanSignBangDash);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::Comment);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		}
+	}
+
+	fn handle_comment_less_than_sign_bang_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.current_state.switch(
+					HTMLTokenizerState::CommentLessThanSignBangDashDash,
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::CommentEndDash);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		}
+	}
+
+	fn handle_comment_less_than_sign_bang_dash_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('>') => {
+				self.reconsume(HTMLTokenizerState::CommentEnd);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| None => {
+				self.reconsume(HTMLTokenizerState::CommentEnd);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(_) => {
+				self.reconsume(HTMLTokenizerState::CommentEnd);
+				ControlFlow::Break(HTMLTokenizerErr::Emit(
+					HTMLLexicalError::nested_comment(),
+				))
+			}
+		}
+	}
+
+	fn handle_comment_end_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.current_state.switch(HTMLTokenizerState::CommentEnd);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(_) => {
+				self.reconsume(HTMLTokenizerState::Comment);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment(['-']),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(
+					HTMLTokenizerOk::EmitCurrentAndEndOfStreamWithError(
+						HTMLLexicalError::end_of_stream_in_comment(),
+					),
+				)
+			}
+		}
+	}
+
+	fn handle_comment_end_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrent)
+			}
+
+			| Some(cp) if cp.is('!') => {
+				self.current_state
+					.switch(HTMLTokenizerState::CommentEndBang);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('-') => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment(['-']),
+				))
+			}
+
+			| Some(_) => {
+				self.reconsume(HTMLTokenizerState::Comment);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment(['-', '-']),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(
+					HTMLTokenizerOk::EmitCurrentAndEndOfStreamWithError(
+						HTMLLexicalError::end_of_stream_in_comment(),
+					),
+				)
+			}
+		}
+	}
+
+	fn handle_comment_end_bang_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.current_state
+					.switch(HTMLTokenizerState::CommentEndDash);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment(['-', '-', '!']),
+				))
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrentWithError(
+					HTMLLexicalError::incorrectly_closed_comment(),
+				))
+			}
+
+			| Some(_) => {
+				self.reconsume(HTMLTokenizerState::Comment);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(
+					update_comment(['-', '-', '!']),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(
+					HTMLTokenizerOk::EmitCurrentAndEndOfStreamWithError(
+						HTMLLexicalError::end_of_stream_in_comment(),
+					),
+				)
+			}
+		}
+	}
+}
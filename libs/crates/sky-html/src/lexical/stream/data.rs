@@ -45,8 +45,10 @@ where
 	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
 	{
 		match self.input.consume_next() {
-			// TODO: character reference
 			| Some(cp) if cp.is('&') => {
+				self.return_state.replace(HTMLTokenizerState::Data);
+				self.current_state
+					.switch(HTMLTokenizerState::CharacterReference);
 				ControlFlow::Continue(HTMLTokenizerOk::None)
 			}
 
@@ -56,7 +58,8 @@ where
 			}
 
 			| Some(cp) if !cp.is__valid() => {
-				ControlFlow::Break(HTMLTokenizerErr::Emit(
+				ControlFlow::Continue(HTMLTokenizerOk::EmitWithError(
+					HTMLToken::character(cp.unit()),
 					HTMLLexicalError::unexpected_null_character(),
 				))
 			}
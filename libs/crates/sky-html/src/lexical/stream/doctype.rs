@@ -0,0 +1,683 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ops::ControlFlow;
+
+use sky_lang::UnitCodePointExt;
+
+use crate::lexical::error::HTMLLexicalError;
+use crate::lexical::tokenizer::{
+	HTMLTokenizerErr,
+	HTMLTokenizerOk,
+	HTMLTokenizerState,
+};
+use crate::lexical::HTMLTokenizer;
+use crate::HTMLToken;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLTokenizerDoctypeStream
+{
+	fn handle_doctype_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_before_doctype_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_doctype_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_after_doctype_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_after_doctype_public_keyword_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_before_doctype_public_identifier_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_doctype_public_identifier_state(
+		&mut self,
+		quote: char,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_after_doctype_public_identifier_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_between_doctype_public_and_system_identifiers_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_after_doctype_system_keyword_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_before_doctype_system_identifier_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_doctype_system_identifier_state(
+		&mut self,
+		quote: char,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_after_doctype_system_identifier_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_bogus_doctype_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<Input: Iterator> HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	/// Définit le drapeau force-quirks du DOCTYPE courant, puis émet ce
+	/// dernier suivi d'un jeton de fin de flux.
+	fn emit_doctype_with_force_quirks_and_end_of_stream(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.update_current_token(HTMLToken::set_force_quirks_flag);
+		ControlFlow::Continue(
+			HTMLTokenizerOk::EmitCurrentAndEndOfStreamWithError(
+				HTMLLexicalError::end_of_stream_in_doctype(),
+			),
+		)
+	}
+
+	/// Définit le drapeau force-quirks du DOCTYPE courant, puis émet ce
+	/// dernier.
+	fn emit_doctype_with_force_quirks(
+		&mut self,
+		err: HTMLLexicalError,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.current_state.switch(HTMLTokenizerState::Data);
+		self.update_current_token(HTMLToken::set_force_quirks_flag);
+		ControlFlow::Continue(HTMLTokenizerOk::EmitCurrentWithError(err))
+	}
+
+	/// Définit le drapeau force-quirks du DOCTYPE courant, puis reconsomme le
+	/// point de code courant dans l'état DOCTYPE bogus.
+	fn reconsume_in_bogus_doctype(
+		&mut self,
+		err: HTMLLexicalError,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.reconsume(HTMLTokenizerState::BogusDoctype);
+		ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
+			Box::new(HTMLToken::set_force_quirks_flag),
+			err,
+		))
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLTokenizerDoctypeStream for HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_doctype_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				self.current_state
+					.switch(HTMLTokenizerState::BeforeDoctypeName);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.reconsume(HTMLTokenizerState::BeforeDoctypeName);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(_) => {
+				self.reconsume(HTMLTokenizerState::BeforeDoctypeName);
+				ControlFlow::Break(HTMLTokenizerErr::Emit(
+					HTMLLexicalError::missing_whitespace_before_doctype_name(),
+				))
+			}
+
+			| None => {
+				self.replace_current_token(HTMLToken::doctype());
+				self.emit_doctype_with_force_quirks_and_end_of_stream()
+			}
+		}
+	}
+
+	fn handle_before_doctype_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		let doctype_with_name = |ch: char| {
+			let mut token = HTMLToken::doctype();
+			token.add_character_to_doctype_name(ch);
+			token
+		};
+
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is__upper_alphabetic() => {
+				self.current_state.switch(HTMLTokenizerState::DoctypeName);
+				ControlFlow::Continue(HTMLTokenizerOk::Update(
+					doctype_with_name(cp.unit().to_ascii_lowercase()),
+				))
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				self.current_state.switch(HTMLTokenizerState::DoctypeName);
+				ControlFlow::Break(HTMLTokenizerErr::Update(
+					doctype_with_name(char::REPLACEMENT_CHARACTER),
+					HTMLLexicalError::unexpected_null_character(),
+				))
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitWithError(
+					HTMLToken::doctype_with_force_quirks(),
+					HTMLLexicalError::missing_doctype_name(),
+				))
+			}
+
+			| Some(cp) => {
+				self.current_state.switch(HTMLTokenizerState::DoctypeName);
+				ControlFlow::Continue(HTMLTokenizerOk::Update(
+					doctype_with_name(cp.unit()),
+				))
+			}
+
+			| None => {
+				self.replace_current_token(HTMLToken::doctype());
+				self.emit_doctype_with_force_quirks_and_end_of_stream()
+			}
+		}
+	}
+
+	fn handle_doctype_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		let update_doctype_name = |ch: char| {
+			move |token: &mut HTMLToken| {
+				token.add_character_to_doctype_name(ch);
+			}
+		};
+
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				self.current_state
+					.switch(HTMLTokenizerState::AfterDoctypeName);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrent)
+			}
+
+			| Some(cp) if cp.is__upper_alphabetic() => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(Box::new(
+					update_doctype_name(cp.unit().to_ascii_lowercase()),
+				)))
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
+					Box::new(update_doctype_name(char::REPLACEMENT_CHARACTER)),
+					HTMLLexicalError::unexpected_null_character(),
+				))
+			}
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(Box::new(
+					update_doctype_name(cp.unit()),
+				)))
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_after_doctype_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrent)
+			}
+
+			| Some(_) => {
+				// NOTE: les mots-clés "PUBLIC" et "SYSTEM" commencent par le
+				// point de code courant.
+				let restored = self.input.rollback_once();
+				debug_assert!(
+					restored,
+					"le point de code courant est conservé"
+				);
+
+				if self.is_next_sequence("PUBLIC", true) {
+					self.consume_sequence("PUBLIC");
+					self.current_state
+						.switch(HTMLTokenizerState::AfterDoctypePublicKeyword);
+					return ControlFlow::Continue(HTMLTokenizerOk::None);
+				}
+
+				if self.is_next_sequence("SYSTEM", true) {
+					self.consume_sequence("SYSTEM");
+					self.current_state
+						.switch(HTMLTokenizerState::AfterDoctypeSystemKeyword);
+					return ControlFlow::Continue(HTMLTokenizerOk::None);
+				}
+
+				self.current_state.switch(HTMLTokenizerState::BogusDoctype);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
+					Box::new(HTMLToken::set_force_quirks_flag),
+					HTMLLexicalError::invalid_character_sequence_after_doctype_name(),
+				))
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_after_doctype_public_keyword_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				self.current_state
+					.switch(HTMLTokenizerState::BeforeDoctypePublicIdentifier);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.one_of(['"', '\'']) => {
+				self.current_state.switch(
+					HTMLTokenizerState::DoctypePublicIdentifier {
+						quote: cp.unit(),
+					},
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
+					Box::new(HTMLToken::start_empty_doctype_public_identifier),
+					HTMLLexicalError::missing_whitespace_after_doctype_public_keyword(),
+				))
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.emit_doctype_with_force_quirks(
+					HTMLLexicalError::missing_doctype_public_identifier(),
+				)
+			}
+
+			| Some(_) => {
+				self.reconsume_in_bogus_doctype(
+					HTMLLexicalError::missing_quote_before_doctype_public_identifier(),
+				)
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_before_doctype_public_identifier_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.one_of(['"', '\'']) => {
+				self.current_state.switch(
+					HTMLTokenizerState::DoctypePublicIdentifier {
+						quote: cp.unit(),
+					},
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(Box::new(
+					HTMLToken::start_empty_doctype_public_identifier,
+				)))
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.emit_doctype_with_force_quirks(
+					HTMLLexicalError::missing_doctype_public_identifier(),
+				)
+			}
+
+			| Some(_) => {
+				self.reconsume_in_bogus_doctype(
+					HTMLLexicalError::missing_quote_before_doctype_public_identifier(),
+				)
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_doctype_public_identifier_state(
+		&mut self,
+		quote: char,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		let update_public_identifier = |ch: char| {
+			move |token: &mut HTMLToken| {
+				token.add_character_to_doctype_public_identifier(ch);
+			}
+		};
+
+		match self.input.consume_next() {
+			| Some(cp) if cp.is(quote) => {
+				self.current_state
+					.switch(HTMLTokenizerState::AfterDoctypePublicIdentifier);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
+					Box::new(update_public_identifier(
+						char::REPLACEMENT_CHARACTER,
+					)),
+					HTMLLexicalError::unexpected_null_character(),
+				))
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.emit_doctype_with_force_quirks(
+					HTMLLexicalError::abrupt_doctype_public_identifier(),
+				)
+			}
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(Box::new(
+					update_public_identifier(cp.unit()),
+				)))
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_after_doctype_public_identifier_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				self.current_state.switch(
+					HTMLTokenizerState::BetweenDoctypePublicAndSystemIdentifiers,
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrent)
+			}
+
+			| Some(cp) if cp.one_of(['"', '\'']) => {
+				self.current_state.switch(
+					HTMLTokenizerState::DoctypeSystemIdentifier {
+						quote: cp.unit(),
+					},
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
+					Box::new(HTMLToken::start_empty_doctype_system_identifier),
+					HTMLLexicalError::missing_whitespace_between_doctype_public_and_system_identifiers(),
+				))
+			}
+
+			| Some(_) => {
+				self.reconsume_in_bogus_doctype(
+					HTMLLexicalError::missing_quote_before_doctype_system_identifier(),
+				)
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_between_doctype_public_and_system_identifiers_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrent)
+			}
+
+			| Some(cp) if cp.one_of(['"', '\'']) => {
+				self.current_state.switch(
+					HTMLTokenizerState::DoctypeSystemIdentifier {
+						quote: cp.unit(),
+					},
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(Box::new(
+					HTMLToken::start_empty_doctype_system_identifier,
+				)))
+			}
+
+			| Some(_) => {
+				self.reconsume_in_bogus_doctype(
+					HTMLLexicalError::missing_quote_before_doctype_system_identifier(),
+				)
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_after_doctype_system_keyword_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				self.current_state
+					.switch(HTMLTokenizerState::BeforeDoctypeSystemIdentifier);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.one_of(['"', '\'']) => {
+				self.current_state.switch(
+					HTMLTokenizerState::DoctypeSystemIdentifier {
+						quote: cp.unit(),
+					},
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
+					Box::new(HTMLToken::start_empty_doctype_system_identifier),
+					HTMLLexicalError::missing_whitespace_after_doctype_system_keyword(),
+				))
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.emit_doctype_with_force_quirks(
+					HTMLLexicalError::missing_doctype_system_identifier(),
+				)
+			}
+
+			| Some(_) => {
+				self.reconsume_in_bogus_doctype(
+					HTMLLexicalError::missing_quote_before_doctype_system_identifier(),
+				)
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_before_doctype_system_identifier_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.one_of(['"', '\'']) => {
+				self.current_state.switch(
+					HTMLTokenizerState::DoctypeSystemIdentifier {
+						quote: cp.unit(),
+					},
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(Box::new(
+					HTMLToken::start_empty_doctype_system_identifier,
+				)))
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.emit_doctype_with_force_quirks(
+					HTMLLexicalError::missing_doctype_system_identifier(),
+				)
+			}
+
+			| Some(_) => {
+				self.reconsume_in_bogus_doctype(
+					HTMLLexicalError::missing_quote_before_doctype_system_identifier(),
+				)
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_doctype_system_identifier_state(
+		&mut self,
+		quote: char,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		let update_system_identifier = |ch: char| {
+			move |token: &mut HTMLToken| {
+				token.add_character_to_doctype_system_identifier(ch);
+			}
+		};
+
+		match self.input.consume_next() {
+			| Some(cp) if cp.is(quote) => {
+				self.current_state
+					.switch(HTMLTokenizerState::AfterDoctypeSystemIdentifier);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
+					Box::new(update_system_identifier(
+						char::REPLACEMENT_CHARACTER,
+					)),
+					HTMLLexicalError::unexpected_null_character(),
+				))
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.emit_doctype_with_force_quirks(
+					HTMLLexicalError::abrupt_doctype_system_identifier(),
+				)
+			}
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(Box::new(
+					update_system_identifier(cp.unit()),
+				)))
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_after_doctype_system_identifier_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.unit().is_ascii_whitespace() => {
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrent)
+			}
+
+			// NOTE: contrairement aux autres états, le drapeau force-quirks
+			// n'est pas défini ici.
+			| Some(_) => {
+				self.reconsume(HTMLTokenizerState::BogusDoctype);
+				ControlFlow::Break(HTMLTokenizerErr::Emit(
+					HTMLLexicalError::unexpected_character_after_doctype_system_identifier(),
+				))
+			}
+
+			| None => self.emit_doctype_with_force_quirks_and_end_of_stream(),
+		}
+	}
+
+	fn handle_bogus_doctype_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrent)
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				ControlFlow::Break(HTMLTokenizerErr::Emit(
+					HTMLLexicalError::unexpected_null_character(),
+				))
+			}
+
+			| Some(_) => ControlFlow::Continue(HTMLTokenizerOk::None),
+
+			| None => {
+				ControlFlow::Continue(
+					HTMLTokenizerOk::EmitCurrentAndEndOfStream,
+				)
+			}
+		}
+	}
+}
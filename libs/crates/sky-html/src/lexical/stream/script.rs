@@ -0,0 +1,604 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ops::ControlFlow;
+
+use sky_lang::UnitCodePointExt;
+
+use crate::lexical::error::HTMLLexicalError;
+use crate::lexical::token::HTMLToken;
+use crate::lexical::tokenizer::{
+	HTMLTokenizerErr,
+	HTMLTokenizerOk,
+	HTMLTokenizerState,
+};
+use crate::lexical::HTMLTokenizer;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLTokenizerScriptDataStream
+{
+	fn handle_script_data_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_end_tag_open_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_end_tag_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_escape_start_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_escape_start_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_escaped_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_escaped_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_escaped_dash_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_escaped_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_escaped_end_tag_open_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_escaped_end_tag_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_double_escape_start_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_double_escaped_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_double_escaped_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_double_escaped_dash_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_double_escaped_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_script_data_double_escape_end_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<Input: Iterator> HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	/// Bascule dans l'état donné et émet le caractère.
+	fn switch_and_emit_character(
+		&mut self,
+		state: HTMLTokenizerState,
+		ch: char,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.current_state.switch(state);
+		ControlFlow::Continue(HTMLTokenizerOk::Emit(HTMLToken::character(ch)))
+	}
+
+	/// Émet un jeton de fin de flux, la fin du flux étant atteinte dans un
+	/// texte ressemblant à un commentaire HTML.
+	fn emit_end_of_stream_in_script_html_comment_like_text(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		ControlFlow::Continue(HTMLTokenizerOk::EmitWithError(
+			HTMLToken::end_of_stream(),
+			HTMLLexicalError::end_of_stream_in_script_html_comment_like_text(),
+		))
+	}
+
+	/// Traitement commun aux états "double escape start" et "double escape
+	/// end": le nom de balise `script` fait basculer entre les deux états
+	/// donnés.
+	fn handle_script_data_double_escape_boundary_state(
+		&mut self,
+		script_state: HTMLTokenizerState,
+		otherwise_state: HTMLTokenizerState,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp)
+				if cp.unit().is_ascii_whitespace() || cp.one_of(['/', '>']) =>
+			{
+				let state = if self.temporary_buffer == "script" {
+					script_state
+				} else {
+					otherwise_state
+				};
+				self.switch_and_emit_character(state, cp.unit())
+			}
+
+			| Some(cp) if cp.is__alphabetic() => {
+				self.temporary_buffer.push(cp.unit().to_ascii_lowercase());
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character(cp.unit()),
+				))
+			}
+
+			| _ => {
+				self.reconsume(otherwise_state);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLTokenizerScriptDataStream for HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_script_data_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('<') => {
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataLessThanSign);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if !cp.is__valid() => self.emit_replacement_character(),
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character(cp.unit()),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::end_of_stream(),
+				))
+			}
+		}
+	}
+
+	fn handle_script_data_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('/') => {
+				self.temporary_buffer.clear();
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataEndTagOpen);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('!') => {
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataEscapeStart);
+				ControlFlow::Continue(HTMLTokenizerOk::ManyEmit(vec![
+					HTMLToken::character('<'),
+					HTMLToken::character('!'),
+				]))
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::ScriptData);
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character('<'),
+				))
+			}
+		}
+	}
+
+	fn handle_script_data_end_tag_open_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_text_end_tag_open_state(
+			HTMLTokenizerState::ScriptDataEndTagName,
+			HTMLTokenizerState::ScriptData,
+		)
+	}
+
+	fn handle_script_data_end_tag_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_text_end_tag_name_state(HTMLTokenizerState::ScriptData)
+	}
+
+	fn handle_script_data_escape_start_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataEscapeStartDash,
+					'-',
+				)
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::ScriptData);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		}
+	}
+
+	fn handle_script_data_escape_start_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataEscapedDashDash,
+					'-',
+				)
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::ScriptData);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		}
+	}
+
+	fn handle_script_data_escaped_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataEscapedDash,
+					'-',
+				)
+			}
+
+			| Some(cp) if cp.is('<') => {
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataEscapedLessThanSign);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if !cp.is__valid() => self.emit_replacement_character(),
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character(cp.unit()),
+				))
+			}
+
+			| None => {
+				self.emit_end_of_stream_in_script_html_comment_like_text()
+			}
+		}
+	}
+
+	fn handle_script_data_escaped_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataEscapedDashDash,
+					'-',
+				)
+			}
+
+			| Some(cp) if cp.is('<') => {
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataEscapedLessThanSign);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataEscaped);
+				self.emit_replacement_character()
+			}
+
+			| Some(cp) => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataEscaped,
+					cp.unit(),
+				)
+			}
+
+			| None => {
+				self.emit_end_of_stream_in_script_html_comment_like_text()
+			}
+		}
+	}
+
+	fn handle_script_data_escaped_dash_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character('-'),
+				))
+			}
+
+			| Some(cp) if cp.is('<') => {
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataEscapedLessThanSign);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptData,
+					'>',
+				)
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataEscaped);
+				self.emit_replacement_character()
+			}
+
+			| Some(cp) => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataEscaped,
+					cp.unit(),
+				)
+			}
+
+			| None => {
+				self.emit_end_of_stream_in_script_html_comment_like_text()
+			}
+		}
+	}
+
+	fn handle_script_data_escaped_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('/') => {
+				self.temporary_buffer.clear();
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataEscapedEndTagOpen);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is__alphabetic() => {
+				self.temporary_buffer.clear();
+				self.reconsume(HTMLTokenizerState::ScriptDataDoubleEscapeStart);
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character('<'),
+				))
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::ScriptDataEscaped);
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character('<'),
+				))
+			}
+		}
+	}
+
+	fn handle_script_data_escaped_end_tag_open_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_text_end_tag_open_state(
+			HTMLTokenizerState::ScriptDataEscapedEndTagName,
+			HTMLTokenizerState::ScriptDataEscaped,
+		)
+	}
+
+	fn handle_script_data_escaped_end_tag_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_text_end_tag_name_state(
+			HTMLTokenizerState::ScriptDataEscaped,
+		)
+	}
+
+	fn handle_script_data_double_escape_start_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_script_data_double_escape_boundary_state(
+			HTMLTokenizerState::ScriptDataDoubleEscaped,
+			HTMLTokenizerState::ScriptDataEscaped,
+		)
+	}
+
+	fn handle_script_data_double_escaped_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataDoubleEscapedDash,
+					'-',
+				)
+			}
+
+			| Some(cp) if cp.is('<') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataDoubleEscapedLessThanSign,
+					'<',
+				)
+			}
+
+			| Some(cp) if !cp.is__valid() => self.emit_replacement_character(),
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character(cp.unit()),
+				))
+			}
+
+			| None => {
+				self.emit_end_of_stream_in_script_html_comment_like_text()
+			}
+		}
+	}
+
+	fn handle_script_data_double_escaped_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataDoubleEscapedDashDash,
+					'-',
+				)
+			}
+
+			| Some(cp) if cp.is('<') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataDoubleEscapedLessThanSign,
+					'<',
+				)
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataDoubleEscaped);
+				self.emit_replacement_character()
+			}
+
+			| Some(cp) => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataDoubleEscaped,
+					cp.unit(),
+				)
+			}
+
+			| None => {
+				self.emit_end_of_stream_in_script_html_comment_like_text()
+			}
+		}
+	}
+
+	fn handle_script_data_double_escaped_dash_dash_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('-') => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character('-'),
+				))
+			}
+
+			| Some(cp) if cp.is('<') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataDoubleEscapedLessThanSign,
+					'<',
+				)
+			}
+
+			| Some(cp) if cp.is('>') => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptData,
+					'>',
+				)
+			}
+
+			| Some(cp) if !cp.is__valid() => {
+				self.current_state
+					.switch(HTMLTokenizerState::ScriptDataDoubleEscaped);
+				self.emit_replacement_character()
+			}
+
+			| Some(cp) => {
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataDoubleEscaped,
+					cp.unit(),
+				)
+			}
+
+			| None => {
+				self.emit_end_of_stream_in_script_html_comment_like_text()
+			}
+		}
+	}
+
+	fn handle_script_data_double_escaped_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('/') => {
+				self.temporary_buffer.clear();
+				self.switch_and_emit_character(
+					HTMLTokenizerState::ScriptDataDoubleEscapeEnd,
+					'/',
+				)
+			}
+
+			| _ => {
+				self.reconsume(HTMLTokenizerState::ScriptDataDoubleEscaped);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		}
+	}
+
+	fn handle_script_data_double_escape_end_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_script_data_double_escape_boundary_state(
+			HTMLTokenizerState::ScriptDataEscaped,
+			HTMLTokenizerState::ScriptDataDoubleEscaped,
+		)
+	}
+}
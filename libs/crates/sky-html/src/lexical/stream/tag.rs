@@ -64,6 +64,10 @@ pub trait HTMLTokenizerTagStream
 		&mut self,
 		quote: Option<char>,
 	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_self_closing_start_tag_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
 }
 
 // -------------- //
@@ -118,11 +122,10 @@ where
 			}
 
 			| None => {
-				ControlFlow::Continue(HTMLTokenizerOk::Full {
-					emit: HTMLToken::character('<'),
-					update: HTMLToken::end_of_stream(),
-					error: HTMLLexicalError::end_of_stream_before_tag_name(),
-				})
+				ControlFlow::Continue(HTMLTokenizerOk::ManyEmitWithError(
+					vec![HTMLToken::character('<'), HTMLToken::end_of_stream()],
+					HTMLLexicalError::end_of_stream_before_tag_name(),
+				))
 			}
 		}
 	}
@@ -245,7 +248,6 @@ where
 				let ch = cp.unit();
 				ControlFlow::Continue(HTMLTokenizerOk::UpdateFnWithError(
 					Box::new(move |token| {
-						assert!(token.is_opened_tag());
 						token.start_attribute_tag_with(ch);
 					}),
 					HTMLLexicalError::unexpected_equals_sign_before_attribute_name(),
@@ -256,7 +258,6 @@ where
 				self.reconsume(HTMLTokenizerState::AttributeName);
 				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(Box::new(
 					|token| {
-						assert!(token.is_opened_tag());
 						token.start_empty_attribute_for_tag();
 					},
 				)))
@@ -275,21 +276,37 @@ where
 	{
 		let update_attribute_name = |ch: char| {
 			move |token: &mut HTMLToken| {
-				assert!(token.is_opened_tag());
 				token.add_character_to_last_attribute_name_of_tag(ch);
 			}
 		};
 
+		// NOTE: en quittant cet état, le nom de l'attribut est comparé à ceux
+		// des autres attributs de la balise. Un attribut en double est retiré
+		// de la balise à son émission.
+		let leave_attribute_name = |tokenizer: &Self| {
+			let is_duplicate = tokenizer
+				.current_token
+				.as_ref()
+				.is_some_and(HTMLToken::has_duplicate_last_attribute);
+			if is_duplicate {
+				ControlFlow::Break(HTMLTokenizerErr::Emit(
+					HTMLLexicalError::duplicate_attribute(),
+				))
+			} else {
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+		};
+
 		match self.input.consume_next() {
 			| Some(cp) if cp.is__whitespace() || cp.one_of(['/', '>']) => {
 				self.reconsume(HTMLTokenizerState::AfterAttributeName);
-				ControlFlow::Continue(HTMLTokenizerOk::None)
+				leave_attribute_name(self)
 			}
 
 			| Some(cp) if cp.is('=') => {
 				self.current_state
 					.switch(HTMLTokenizerState::BeforeAttributeValue);
-				ControlFlow::Continue(HTMLTokenizerOk::None)
+				leave_attribute_name(self)
 			}
 
 			| Some(cp) if cp.is__upper_alphabetic() => {
@@ -324,7 +341,7 @@ where
 
 			| None => {
 				self.reconsume(HTMLTokenizerState::AfterAttributeName);
-				ControlFlow::Continue(HTMLTokenizerOk::None)
+				leave_attribute_name(self)
 			}
 		}
 	}
@@ -426,8 +443,11 @@ where
 					ControlFlow::Continue(HTMLTokenizerOk::None)
 				}
 
-				// TODO: character reference
 				| Some(cp) if cp.is('&') => {
+					self.return_state
+						.replace(HTMLTokenizerState::AttributeValue { quote });
+					self.current_state
+						.switch(HTMLTokenizerState::CharacterReference);
 					ControlFlow::Continue(HTMLTokenizerOk::None)
 				}
 
@@ -461,8 +481,11 @@ where
 					ControlFlow::Continue(HTMLTokenizerOk::None)
 				}
 
-				// TODO: character reference
 				| Some(cp) if cp.is('&') => {
+					self.return_state
+						.replace(HTMLTokenizerState::AttributeValue { quote });
+					self.current_state
+						.switch(HTMLTokenizerState::CharacterReference);
 					ControlFlow::Continue(HTMLTokenizerOk::None)
 				}
 
@@ -541,4 +564,47 @@ where
 			}
 		}
 	}
+
+	fn handle_self_closing_start_tag_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('>') => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+
+				let is_end_tag = self
+					.current_token
+					.as_ref()
+					.filter(|token| token.is_closed_tag())
+					.is_some();
+
+				self.update_current_token(HTMLToken::set_self_closing_tag);
+
+				if is_end_tag {
+					ControlFlow::Continue(
+						HTMLTokenizerOk::EmitCurrentWithError(
+							HTMLLexicalError::end_tag_with_trailing_solidus(),
+						),
+					)
+				} else {
+					ControlFlow::Continue(HTMLTokenizerOk::EmitCurrent)
+				}
+			}
+
+			| Some(_) => {
+				self.reconsume(HTMLTokenizerState::BeforeAttributeName);
+				ControlFlow::Break(HTMLTokenizerErr::Emit(
+					HTMLLexicalError::unexpected_solidus_in_tag(),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(HTMLTokenizerOk::EmitWithError(
+					HTMLToken::end_of_stream(),
+					HTMLLexicalError::end_of_stream_in_tag(),
+				))
+			}
+		}
+	}
 }
@@ -0,0 +1,336 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ops::ControlFlow;
+
+use sky_lang::UnitCodePointExt;
+
+use crate::lexical::error::HTMLLexicalError;
+use crate::lexical::token::HTMLToken;
+use crate::lexical::tokenizer::{
+	HTMLTokenizerErr,
+	HTMLTokenizerOk,
+	HTMLTokenizerState,
+};
+use crate::lexical::HTMLTokenizer;
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLTokenizerTextStream
+{
+	fn handle_rcdata_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_rcdata_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_rcdata_end_tag_open_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_rcdata_end_tag_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_rawtext_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_rawtext_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_rawtext_end_tag_open_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_rawtext_end_tag_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+
+	fn handle_plaintext_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>;
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<Input: Iterator> HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	/// Traitement commun aux états "less-than sign" des états RCDATA, RAWTEXT
+	/// et script data: seul un `/` peut débuter une balise de fin.
+	pub(crate) fn handle_text_less_than_sign_state(
+		&mut self,
+		end_tag_open_state: HTMLTokenizerState,
+		text_state: HTMLTokenizerState,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('/') => {
+				self.temporary_buffer.clear();
+				self.current_state.switch(end_tag_open_state);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| _ => {
+				self.reconsume(text_state);
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character('<'),
+				))
+			}
+		}
+	}
+
+	/// Traitement commun aux états "end tag open" des états RCDATA, RAWTEXT
+	/// et script data.
+	pub(crate) fn handle_text_end_tag_open_state(
+		&mut self,
+		end_tag_name_state: HTMLTokenizerState,
+		text_state: HTMLTokenizerState,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is__alphabetic() => {
+				self.reconsume(end_tag_name_state);
+				ControlFlow::Continue(HTMLTokenizerOk::Update(
+					HTMLToken::end_tag(),
+				))
+			}
+
+			| _ => {
+				self.reconsume(text_state);
+				ControlFlow::Continue(HTMLTokenizerOk::ManyEmit(vec![
+					HTMLToken::character('<'),
+					HTMLToken::character('/'),
+				]))
+			}
+		}
+	}
+
+	/// Traitement commun aux états "end tag name" des états RCDATA, RAWTEXT
+	/// et script data: la balise de fin n'est reconnue que si elle est
+	/// appropriée, sinon les caractères consommés sont émis tels quels.
+	pub(crate) fn handle_text_end_tag_name_state(
+		&mut self,
+		text_state: HTMLTokenizerState,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp)
+				if cp.unit().is_ascii_whitespace()
+					&& self.is_appropriate_end_tag() =>
+			{
+				self.current_state
+					.switch(HTMLTokenizerState::BeforeAttributeName);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('/') && self.is_appropriate_end_tag() => {
+				self.current_state
+					.switch(HTMLTokenizerState::SelfClosingStartTag);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('>') && self.is_appropriate_end_tag() => {
+				self.current_state.switch(HTMLTokenizerState::Data);
+				ControlFlow::Continue(HTMLTokenizerOk::EmitCurrent)
+			}
+
+			| Some(cp) if cp.is__alphabetic() => {
+				let ch = cp.unit();
+				self.temporary_buffer.push(ch);
+				ControlFlow::Continue(HTMLTokenizerOk::UpdateFn(Box::new(
+					move |token| {
+						token
+							.add_character_to_tag_name(ch.to_ascii_lowercase());
+					},
+				)))
+			}
+
+			| _ => {
+				self.reconsume(text_state);
+
+				let mut tokens =
+					vec![HTMLToken::character('<'), HTMLToken::character('/')];
+				tokens.extend(
+					std::mem::take(&mut self.temporary_buffer)
+						.chars()
+						.map(HTMLToken::character),
+				);
+				ControlFlow::Continue(HTMLTokenizerOk::ManyEmit(tokens))
+			}
+		}
+	}
+
+	/// Émet le caractère de remplacement U+FFFD à la place d'un caractère
+	/// NULL.
+	pub(crate) fn emit_replacement_character(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		ControlFlow::Continue(HTMLTokenizerOk::EmitWithError(
+			HTMLToken::character(char::REPLACEMENT_CHARACTER),
+			HTMLLexicalError::unexpected_null_character(),
+		))
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLTokenizerTextStream for HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_rcdata_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('&') => {
+				self.return_state.replace(HTMLTokenizerState::Rcdata);
+				self.current_state
+					.switch(HTMLTokenizerState::CharacterReference);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if cp.is('<') => {
+				self.current_state
+					.switch(HTMLTokenizerState::RcdataLessThanSign);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if !cp.is__valid() => self.emit_replacement_character(),
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character(cp.unit()),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::end_of_stream(),
+				))
+			}
+		}
+	}
+
+	fn handle_rcdata_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_text_less_than_sign_state(
+			HTMLTokenizerState::RcdataEndTagOpen,
+			HTMLTokenizerState::Rcdata,
+		)
+	}
+
+	fn handle_rcdata_end_tag_open_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_text_end_tag_open_state(
+			HTMLTokenizerState::RcdataEndTagName,
+			HTMLTokenizerState::Rcdata,
+		)
+	}
+
+	fn handle_rcdata_end_tag_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_text_end_tag_name_state(HTMLTokenizerState::Rcdata)
+	}
+
+	fn handle_rawtext_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if cp.is('<') => {
+				self.current_state
+					.switch(HTMLTokenizerState::RawtextLessThanSign);
+				ControlFlow::Continue(HTMLTokenizerOk::None)
+			}
+
+			| Some(cp) if !cp.is__valid() => self.emit_replacement_character(),
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character(cp.unit()),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::end_of_stream(),
+				))
+			}
+		}
+	}
+
+	fn handle_rawtext_less_than_sign_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_text_less_than_sign_state(
+			HTMLTokenizerState::RawtextEndTagOpen,
+			HTMLTokenizerState::Rawtext,
+		)
+	}
+
+	fn handle_rawtext_end_tag_open_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_text_end_tag_open_state(
+			HTMLTokenizerState::RawtextEndTagName,
+			HTMLTokenizerState::Rawtext,
+		)
+	}
+
+	fn handle_rawtext_end_tag_name_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		self.handle_text_end_tag_name_state(HTMLTokenizerState::Rawtext)
+	}
+
+	fn handle_plaintext_state(
+		&mut self,
+	) -> ControlFlow<HTMLTokenizerErr, HTMLTokenizerOk>
+	{
+		match self.input.consume_next() {
+			| Some(cp) if !cp.is__valid() => self.emit_replacement_character(),
+
+			| Some(cp) => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::character(cp.unit()),
+				))
+			}
+
+			| None => {
+				ControlFlow::Continue(HTMLTokenizerOk::Emit(
+					HTMLToken::end_of_stream(),
+				))
+			}
+		}
+	}
+}
@@ -52,6 +52,88 @@ impl HTMLToken
 			location: Location::new(),
 		}
 	}
+
+	/// Crée un jeton de commentaire avec le contenu donné.
+	pub fn comment(content: impl Into<String>) -> Self
+	{
+		Self {
+			lexeme: HTMLLexeme::Comment(content.into()),
+			location: Location::new(),
+		}
+	}
+
+	/// Ajoute un caractère au contenu du commentaire courant.
+	pub fn add_character_to_comment(&mut self, ch: char)
+	{
+		self.lexeme.add_character_to_comment(ch);
+	}
+}
+
+impl HTMLToken
+{
+	/// Crée un jeton de DOCTYPE.
+	pub const fn doctype() -> Self
+	{
+		Self {
+			lexeme: HTMLLexeme::Doctype {
+				name: None,
+				public_id: None,
+				system_id: None,
+				force_quirks: false,
+			},
+			location: Location::new(),
+		}
+	}
+
+	/// Crée un jeton de DOCTYPE dont le drapeau force-quirks est défini.
+	pub const fn doctype_with_force_quirks() -> Self
+	{
+		Self {
+			lexeme: HTMLLexeme::Doctype {
+				name: None,
+				public_id: None,
+				system_id: None,
+				force_quirks: true,
+			},
+			location: Location::new(),
+		}
+	}
+
+	/// Ajoute un caractère au nom du DOCTYPE courant.
+	pub fn add_character_to_doctype_name(&mut self, ch: char)
+	{
+		self.lexeme.add_character_to_doctype_name(ch);
+	}
+
+	/// Ajoute un caractère à l'identifiant public du DOCTYPE courant.
+	pub fn add_character_to_doctype_public_identifier(&mut self, ch: char)
+	{
+		self.lexeme.add_character_to_doctype_public_identifier(ch);
+	}
+
+	/// Ajoute un caractère à l'identifiant système du DOCTYPE courant.
+	pub fn add_character_to_doctype_system_identifier(&mut self, ch: char)
+	{
+		self.lexeme.add_character_to_doctype_system_identifier(ch);
+	}
+
+	/// Définit le drapeau force-quirks du DOCTYPE courant.
+	pub fn set_force_quirks_flag(&mut self)
+	{
+		self.lexeme.set_force_quirks_flag();
+	}
+
+	/// Définit un identifiant public vide pour le DOCTYPE courant.
+	pub fn start_empty_doctype_public_identifier(&mut self)
+	{
+		self.lexeme.start_empty_doctype_public_identifier();
+	}
+
+	/// Définit un identifiant système vide pour le DOCTYPE courant.
+	pub fn start_empty_doctype_system_identifier(&mut self)
+	{
+		self.lexeme.start_empty_doctype_system_identifier();
+	}
 }
 
 impl HTMLToken
@@ -94,7 +176,32 @@ impl HTMLToken
 		)
 	}
 
-	/// Vérifie que le jeton est une balise ouverte.
+	/// Vérifie que le jeton est une balise fermée (balise de fin).
+	pub const fn is_closed_tag(&self) -> bool
+	{
+		matches!(
+			&self.lexeme,
+			HTMLLexeme::Tag {
+				state: TagState::Closed,
+				..
+			}
+		)
+	}
+
+	/// Vérifie que le jeton est une balise de début (ouverte ou
+	/// auto-fermante).
+	pub const fn is_start_tag(&self) -> bool
+	{
+		matches!(
+			&self.lexeme,
+			HTMLLexeme::Tag {
+				state: TagState::Opened | TagState::SelfClosed,
+				..
+			}
+		)
+	}
+
+	/// Ajoute un caractère au nom de la balise courante.
 	pub fn add_character_to_tag_name(&mut self, ch: char)
 	{
 		self.lexeme.add_character_to_tag_name(ch);
@@ -113,6 +220,12 @@ impl HTMLToken
 		self.lexeme.add_character_to_last_attribute_value_of_tag(ch);
 	}
 
+	/// Définit le drapeau d'auto-fermeture de la balise courante.
+	pub fn set_self_closing_tag(&mut self)
+	{
+		self.lexeme.set_self_closing_tag();
+	}
+
 	/// Ajoute un attribut vide pour la balise courante.
 	pub fn start_empty_attribute_for_tag(&mut self)
 	{
@@ -125,6 +238,20 @@ impl HTMLToken
 	{
 		self.lexeme.start_attribute_tag_with(ch);
 	}
+
+	/// Vérifie que le dernier attribut de la balise courante porte le nom
+	/// d'un attribut précédent.
+	pub fn has_duplicate_last_attribute(&self) -> bool
+	{
+		self.lexeme.has_duplicate_last_attribute()
+	}
+
+	/// Retire les attributs en double de la balise courante, seule la
+	/// première occurrence est conservée.
+	pub fn remove_duplicate_attributes(&mut self)
+	{
+		self.lexeme.remove_duplicate_attributes();
+	}
 }
 
 impl HTMLToken
@@ -147,6 +274,48 @@ impl HTMLToken
 
 impl HTMLToken
 {
+	/// Lexème du jeton.
+	pub const fn lexeme(&self) -> &HTMLLexeme
+	{
+		&self.lexeme
+	}
+
+	/// Position du jeton dans le flux, la source.
+	pub const fn location(&self) -> Location
+	{
+		self.location
+	}
+
+	/// Nom de la balise, si le jeton est une balise.
+	pub fn tag_name(&self) -> Option<&str>
+	{
+		match &self.lexeme {
+			| HTMLLexeme::Tag { name, .. } => Some(name),
+			| _ => None,
+		}
+	}
+
+	/// Attributs de la balise, si le jeton est une balise.
+	pub fn tag_attributes(&self) -> Option<&[(String, String)]>
+	{
+		match &self.lexeme {
+			| HTMLLexeme::Tag { attributes, .. } => Some(attributes),
+			| _ => None,
+		}
+	}
+
+	/// Vérifie que le jeton est une balise auto-fermante.
+	pub const fn is_self_closing_tag(&self) -> bool
+	{
+		matches!(
+			&self.lexeme,
+			HTMLLexeme::Tag {
+				state: TagState::SelfClosed,
+				..
+			}
+		)
+	}
+
 	/// Définit la position du jeton dans le flux, la source.
 	pub fn with_location(mut self, location: Location) -> Self
 	{
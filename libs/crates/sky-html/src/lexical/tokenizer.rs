@@ -8,12 +8,24 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
 use std::ops::ControlFlow;
 
-use sky_lang::{InputStream, Location, UnitCodePointExt};
+use sky_lang::{InputStream, Location, Position, UnitCodePointExt};
 
-use super::error::HTMLLexicalError;
-use super::stream::{HTMLTokenizerDataStream, HTMLTokenizerTagStream};
+use super::error::{HTMLLexicalError, HTMLLexicalErrorSink};
+use super::lexeme::HTMLLexeme;
+use super::stream::{
+	HTMLTokenizerCdataSectionStream,
+	HTMLTokenizerCharacterReferenceStream,
+	HTMLTokenizerCommentStream,
+	HTMLTokenizerDataStream,
+	HTMLTokenizerDoctypeStream,
+	HTMLTokenizerScriptDataStream,
+	HTMLTokenizerTagStream,
+	HTMLTokenizerTextStream,
+};
 use super::token::HTMLToken;
 
 // --------- //
@@ -25,23 +37,70 @@ pub struct HTMLTokenizer<Input: Iterator>
 	pub(crate) input: InputStream<Input>,
 	/// Jeton courant, ce jeton (peut / va) être modifié au fur & à mesure des
 	/// états.
-	current_token: Option<HTMLToken>,
-	/// Position du jeton, modifié à chaque itération.
-	pub(crate) current_location: Location,
+	pub(crate) current_token: Option<HTMLToken>,
+	/// Position du premier point de code du prochain jeton à émettre.
+	token_start: Position,
 	/// État courant du fournisseur de jeton.
 	pub(crate) current_state: HTMLTokenizerState,
+	/// État dans lequel revenir après l'analyse d'une référence de caractère.
+	pub(crate) return_state: Option<HTMLTokenizerState>,
+	/// Tampon temporaire, utilisé notamment par les états des références de
+	/// caractères.
+	pub(crate) temporary_buffer: String,
+	/// Code de la référence de caractère numérique en cours d'analyse.
+	pub(crate) character_reference_code: u32,
+	/// Nom de la dernière balise de début émise, utilisé pour déterminer si
+	/// une balise de fin est appropriée.
+	pub(crate) last_start_tag_name: Option<String>,
+	/// Le nœud courant ajusté n'est pas un élément de l'espace de noms HTML
+	/// (contenu étranger: SVG, MathML). Les sections CDATA ne sont reconnues
+	/// que dans ce cas.
+	pub(crate) in_foreign_content: bool,
+	/// Mode de gestion des erreurs d'analyse.
+	mode: HTMLTokenizerMode,
+	/// Erreurs d'analyse récupérables rencontrées jusqu'à présent,
+	/// lorsqu'aucun collecteur n'est défini.
+	diagnostics: Vec<HTMLLexicalError>,
+	/// Collecteur de diagnostics.
+	diagnostics_sink: Option<Box<dyn HTMLLexicalErrorSink>>,
+	/// Jetons émis en attente d'être retournés par l'itérateur.
+	emitted_tokens: VecDeque<HTMLToken>,
+	/// Le jeton de fin de flux a été retourné par l'itérateur (ou une erreur
+	/// fatale en mode strict).
+	is_terminated: bool,
 }
 
 // ----------- //
 // Énumération //
 // ----------- //
 
+/// Mode de gestion des erreurs d'analyse du fournisseur de jeton.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum HTMLTokenizerMode
+{
+	/// Les erreurs d'analyse sont collectées et l'analyse continue selon les
+	/// règles de récupération de la spécification.
+	#[default]
+	Lenient,
+	/// La première erreur d'analyse est fatale.
+	Strict,
+}
+
 #[derive(Debug)]
 #[derive(Copy, Clone)]
 #[derive(PartialEq, Eq)]
 pub enum HTMLTokenizerState
 {
 	AfterAttributeName,
+	AfterDoctypeName,
+	AfterDoctypePublicIdentifier,
+	AfterDoctypePublicKeyword,
+	AfterDoctypeSystemIdentifier,
+	AfterDoctypeSystemKeyword,
+	AmbiguousAmpersand,
 	AttributeName,
 	AttributeValue
 	{
@@ -59,10 +118,77 @@ pub enum HTMLTokenizerState
 	},
 	BeforeAttributeName,
 	BeforeAttributeValue,
+	BeforeDoctypeName,
+	BeforeDoctypePublicIdentifier,
+	BeforeDoctypeSystemIdentifier,
+	BetweenDoctypePublicAndSystemIdentifiers,
 	BogusComment,
+	BogusDoctype,
+	CdataSection,
+	CdataSectionBracket,
+	CdataSectionEnd,
+	CharacterReference,
+	Comment,
+	CommentEnd,
+	CommentEndBang,
+	CommentEndDash,
+	CommentLessThanSign,
+	CommentLessThanSignBang,
+	CommentLessThanSignBangDash,
+	CommentLessThanSignBangDashDash,
+	CommentStart,
+	CommentStartDash,
 	Data,
+	DecimalCharacterReference,
+	DecimalCharacterReferenceStart,
+	Doctype,
+	DoctypeName,
+	DoctypePublicIdentifier
+	{
+		// NOTE: quoted = '\''
+		// NOTE: quoted = '"'
+		quote: char,
+	},
+	DoctypeSystemIdentifier
+	{
+		// NOTE: quoted = '\''
+		// NOTE: quoted = '"'
+		quote: char,
+	},
 	EndTagOpen,
+	HexadecimalCharacterReference,
+	HexadecimalCharacterReferenceStart,
 	MarkupDeclarationOpen,
+	NamedCharacterReference,
+	NumericCharacterReference,
+	NumericCharacterReferenceEnd,
+	Plaintext,
+	Rawtext,
+	RawtextEndTagName,
+	RawtextEndTagOpen,
+	RawtextLessThanSign,
+	Rcdata,
+	RcdataEndTagName,
+	RcdataEndTagOpen,
+	RcdataLessThanSign,
+	ScriptData,
+	ScriptDataDoubleEscapeEnd,
+	ScriptDataDoubleEscapeStart,
+	ScriptDataDoubleEscaped,
+	ScriptDataDoubleEscapedDash,
+	ScriptDataDoubleEscapedDashDash,
+	ScriptDataDoubleEscapedLessThanSign,
+	ScriptDataEndTagName,
+	ScriptDataEndTagOpen,
+	ScriptDataEscapeStart,
+	ScriptDataEscapeStartDash,
+	ScriptDataEscaped,
+	ScriptDataEscapedDash,
+	ScriptDataEscapedDashDash,
+	ScriptDataEscapedEndTagName,
+	ScriptDataEscapedEndTagOpen,
+	ScriptDataEscapedLessThanSign,
+	ScriptDataLessThanSign,
 	SelfClosingStartTag,
 	TagName,
 	TagOpen,
@@ -77,16 +203,12 @@ pub enum HTMLTokenizerOk
 	Emit(HTMLToken),
 	EmitCurrent,
 	EmitCurrentWithError(HTMLLexicalError),
+	EmitCurrentAndEndOfStream,
+	EmitCurrentAndEndOfStreamWithError(HTMLLexicalError),
 	EmitWithError(HTMLToken, HTMLLexicalError),
+	ManyEmit(Vec<HTMLToken>),
 	ManyEmitWithError(Vec<HTMLToken>, HTMLLexicalError),
 
-	Full
-	{
-		emit: HTMLToken,
-		update: HTMLToken,
-		error: HTMLLexicalError,
-	},
-
 	None,
 }
 
@@ -107,10 +229,74 @@ impl<Input: Iterator> HTMLTokenizer<Input>
 		Self {
 			input: InputStream::new(input),
 			current_token: Default::default(),
-			current_location: Location::default(),
+			token_start: Position::new(),
 			current_state: HTMLTokenizerState::Data,
+			return_state: Default::default(),
+			temporary_buffer: Default::default(),
+			character_reference_code: Default::default(),
+			last_start_tag_name: Default::default(),
+			in_foreign_content: Default::default(),
+			mode: Default::default(),
+			diagnostics: Default::default(),
+			diagnostics_sink: Default::default(),
+			emitted_tokens: Default::default(),
+			is_terminated: Default::default(),
 		}
 	}
+
+	/// Définit le mode de gestion des erreurs d'analyse.
+	pub fn with_mode(mut self, mode: HTMLTokenizerMode) -> Self
+	{
+		self.mode = mode;
+		self
+	}
+
+	/// Transmet les erreurs d'analyse au collecteur donné plutôt que de les
+	/// accumuler dans le fournisseur de jeton.
+	pub fn with_diagnostics_sink(
+		mut self,
+		sink: impl HTMLLexicalErrorSink + 'static,
+	) -> Self
+	{
+		self.diagnostics_sink.replace(Box::new(sink));
+		self
+	}
+
+	/// Erreurs d'analyse récupérables rencontrées jusqu'à présent.
+	pub fn diagnostics(&self) -> &[HTMLLexicalError]
+	{
+		&self.diagnostics
+	}
+
+	/// Retire les erreurs d'analyse récupérables rencontrées jusqu'à présent.
+	pub fn take_diagnostics(&mut self) -> Vec<HTMLLexicalError>
+	{
+		std::mem::take(&mut self.diagnostics)
+	}
+
+	/// Bascule le fournisseur de jeton dans l'état donné.
+	///
+	/// Utilisé par la construction de l'arbre, par exemple pour analyser le
+	/// contenu des éléments `<title>` (RCDATA), `<style>` (RAWTEXT) ou
+	/// `<script>` (script data).
+	pub fn switch_state(&mut self, state: HTMLTokenizerState)
+	{
+		self.current_state.switch(state);
+	}
+
+	/// Définit le nom de la dernière balise de début, par exemple lors de
+	/// l'analyse d'un fragment dont l'élément de contexte est un `<textarea>`.
+	pub fn set_last_start_tag_name(&mut self, name: impl Into<String>)
+	{
+		self.last_start_tag_name.replace(name.into());
+	}
+
+	/// Indique au fournisseur de jeton si le nœud courant ajusté de la
+	/// construction de l'arbre se trouve en dehors de l'espace de noms HTML.
+	pub fn set_in_foreign_content(&mut self, in_foreign_content: bool)
+	{
+		self.in_foreign_content = in_foreign_content;
+	}
 }
 
 impl<Input: Iterator> HTMLTokenizer<Input>
@@ -120,26 +306,70 @@ where
 	pub fn consume_next(&mut self) -> Result<Vec<HTMLToken>, HTMLLexicalError>
 	{
 		loop {
-			if let Some(cp) = self.input.peek_next() {
-				if cp.is__tab() {
-					self.current_location.increment_column_by(4);
-				} else if cp.is__linefeed() {
-					self.current_location.increment_line().reset_column();
-				} else if !cp.is__carriage_return() {
-					self.current_location.increment_column();
-				}
-			}
-
 			#[rustfmt::skip]
 			let control_flow = match self.current_state {
 				// 13.2.5.1 Data state
 				| HTMLTokenizerState::Data => self.handle_data_state(),
+				// 13.2.5.2 RCDATA state
+				| HTMLTokenizerState::Rcdata => self.handle_rcdata_state(),
+				// 13.2.5.3 RAWTEXT state
+				| HTMLTokenizerState::Rawtext => self.handle_rawtext_state(),
+				// 13.2.5.4 Script data state
+				| HTMLTokenizerState::ScriptData => self.handle_script_data_state(),
+				// 13.2.5.5 PLAINTEXT state
+				| HTMLTokenizerState::Plaintext => self.handle_plaintext_state(),
 				// 13.2.5.6 Tag open state
 				| HTMLTokenizerState::TagOpen => self.handle_tag_open_state(),
 				// 13.2.5.7 End tag open state
 				| HTMLTokenizerState::EndTagOpen => self.handle_end_tag_open_state(),
 				// 13.2.5.8 Tag name state
 				| HTMLTokenizerState::TagName => self.handle_tag_name_state(),
+				// 13.2.5.9 RCDATA less-than sign state
+				| HTMLTokenizerState::RcdataLessThanSign => self.handle_rcdata_less_than_sign_state(),
+				// 13.2.5.10 RCDATA end tag open state
+				| HTMLTokenizerState::RcdataEndTagOpen => self.handle_rcdata_end_tag_open_state(),
+				// 13.2.5.11 RCDATA end tag name state
+				| HTMLTokenizerState::RcdataEndTagName => self.handle_rcdata_end_tag_name_state(),
+				// 13.2.5.12 RAWTEXT less-than sign state
+				| HTMLTokenizerState::RawtextLessThanSign => self.handle_rawtext_less_than_sign_state(),
+				// 13.2.5.13 RAWTEXT end tag open state
+				| HTMLTokenizerState::RawtextEndTagOpen => self.handle_rawtext_end_tag_open_state(),
+				// 13.2.5.14 RAWTEXT end tag name state
+				| HTMLTokenizerState::RawtextEndTagName => self.handle_rawtext_end_tag_name_state(),
+				// 13.2.5.15 Script data less-than sign state
+				| HTMLTokenizerState::ScriptDataLessThanSign => self.handle_script_data_less_than_sign_state(),
+				// 13.2.5.16 Script data end tag open state
+				| HTMLTokenizerState::ScriptDataEndTagOpen => self.handle_script_data_end_tag_open_state(),
+				// 13.2.5.17 Script data end tag name state
+				| HTMLTokenizerState::ScriptDataEndTagName => self.handle_script_data_end_tag_name_state(),
+				// 13.2.5.18 Script data escape start state
+				| HTMLTokenizerState::ScriptDataEscapeStart => self.handle_script_data_escape_start_state(),
+				// 13.2.5.19 Script data escape start dash state
+				| HTMLTokenizerState::ScriptDataEscapeStartDash => self.handle_script_data_escape_start_dash_state(),
+				// 13.2.5.20 Script data escaped state
+				| HTMLTokenizerState::ScriptDataEscaped => self.handle_script_data_escaped_state(),
+				// 13.2.5.21 Script data escaped dash state
+				| HTMLTokenizerState::ScriptDataEscapedDash => self.handle_script_data_escaped_dash_state(),
+				// 13.2.5.22 Script data escaped dash dash state
+				| HTMLTokenizerState::ScriptDataEscapedDashDash => self.handle_script_data_escaped_dash_dash_state(),
+				// 13.2.5.23 Script data escaped less-than sign state
+				| HTMLTokenizerState::ScriptDataEscapedLessThanSign => self.handle_script_data_escaped_less_than_sign_state(),
+				// 13.2.5.24 Script data escaped end tag open state
+				| HTMLTokenizerState::ScriptDataEscapedEndTagOpen => self.handle_script_data_escaped_end_tag_open_state(),
+				// 13.2.5.25 Script data escaped end tag name state
+				| HTMLTokenizerState::ScriptDataEscapedEndTagName => self.handle_script_data_escaped_end_tag_name_state(),
+				// 13.2.5.26 Script data double escape start state
+				| HTMLTokenizerState::ScriptDataDoubleEscapeStart => self.handle_script_data_double_escape_start_state(),
+				// 13.2.5.27 Script data double escaped state
+				| HTMLTokenizerState::ScriptDataDoubleEscaped => self.handle_script_data_double_escaped_state(),
+				// 13.2.5.28 Script data double escaped dash state
+				| HTMLTokenizerState::ScriptDataDoubleEscapedDash => self.handle_script_data_double_escaped_dash_state(),
+				// 13.2.5.29 Script data double escaped dash dash state
+				| HTMLTokenizerState::ScriptDataDoubleEscapedDashDash => self.handle_script_data_double_escaped_dash_dash_state(),
+				// 13.2.5.30 Script data double escaped less-than sign state
+				| HTMLTokenizerState::ScriptDataDoubleEscapedLessThanSign => self.handle_script_data_double_escaped_less_than_sign_state(),
+				// 13.2.5.31 Script data double escape end state
+				| HTMLTokenizerState::ScriptDataDoubleEscapeEnd => self.handle_script_data_double_escape_end_state(),
 				// 13.2.5.32 Before attribute name state
 				| HTMLTokenizerState::BeforeAttributeName => self.handle_before_attribute_name_state(),
 				// 13.2.5.33 Attribute name state
@@ -154,128 +384,378 @@ where
 				| HTMLTokenizerState::AttributeValue { quote } => self.handle_attribute_value_state(quote),
 				// 13.2.5.39 After attribute value (quoted) state
 				| HTMLTokenizerState::AfterAttributeValue { quote } => self.handle_after_attribute_value_state(quote),
-
-				| _ => return {
-					Ok(vec![HTMLToken::end_of_stream().with_location(self.current_location)])
-				},
+				// 13.2.5.40 Self-closing start tag state
+				| HTMLTokenizerState::SelfClosingStartTag => self.handle_self_closing_start_tag_state(),
+				// 13.2.5.41 Bogus comment state
+				| HTMLTokenizerState::BogusComment => self.handle_bogus_comment_state(),
+				// 13.2.5.42 Markup declaration open state
+				| HTMLTokenizerState::MarkupDeclarationOpen => self.handle_markup_declaration_open_state(),
+				// 13.2.5.43 Comment start state
+				| HTMLTokenizerState::CommentStart => self.handle_comment_start_state(),
+				// 13.2.5.44 Comment start dash state
+				| HTMLTokenizerState::CommentStartDash => self.handle_comment_start_dash_state(),
+				// 13.2.5.45 Comment state
+				| HTMLTokenizerState::Comment => self.handle_comment_state(),
+				// 13.2.5.46 Comment less-than sign state
+				| HTMLTokenizerState::CommentLessThanSign => self.handle_comment_less_than_sign_state(),
+				// 13.2.5.47 Comment less-than sign bang state
+				| HTMLTokenizerState::CommentLessThanSignBang => self.handle_comment_less_than_sign_bang_state(),
+				// 13.2.5.48 Comment less-than sign bang dash state
+				| HTMLTokenizerState::CommentLessThanSignBangDash => self.handle_comment_less_than_sign_bang_dash_state(),
+				// 13.2.5.49 Comment less-than sign bang dash dash state
+				| HTMLTokenizerState::CommentLessThanSignBangDashDash => self.handle_comment_less_than_sign_bang_dash_dash_state(),
+				// 13.2.5.50 Comment end dash state
+				| HTMLTokenizerState::CommentEndDash => self.handle_comment_end_dash_state(),
+				// 13.2.5.51 Comment end state
+				| HTMLTokenizerState::CommentEnd => self.handle_comment_end_state(),
+				// 13.2.5.52 Comment end bang state
+				| HTMLTokenizerState::CommentEndBang => self.handle_comment_end_bang_state(),
+				// 13.2.5.53 DOCTYPE state
+				| HTMLTokenizerState::Doctype => self.handle_doctype_state(),
+				// 13.2.5.54 Before DOCTYPE name state
+				| HTMLTokenizerState::BeforeDoctypeName => self.handle_before_doctype_name_state(),
+				// 13.2.5.55 DOCTYPE name state
+				| HTMLTokenizerState::DoctypeName => self.handle_doctype_name_state(),
+				// 13.2.5.56 After DOCTYPE name state
+				| HTMLTokenizerState::AfterDoctypeName => self.handle_after_doctype_name_state(),
+				// 13.2.5.57 After DOCTYPE public keyword state
+				| HTMLTokenizerState::AfterDoctypePublicKeyword => self.handle_after_doctype_public_keyword_state(),
+				// 13.2.5.58 Before DOCTYPE public identifier state
+				| HTMLTokenizerState::BeforeDoctypePublicIdentifier => self.handle_before_doctype_public_identifier_state(),
+				// 13.2.5.59 DOCTYPE public identifier (double-quoted) state
+				// 13.2.5.60 DOCTYPE public identifier (single-quoted) state
+				| HTMLTokenizerState::DoctypePublicIdentifier { quote } => self.handle_doctype_public_identifier_state(quote),
+				// 13.2.5.61 After DOCTYPE public identifier state
+				| HTMLTokenizerState::AfterDoctypePublicIdentifier => self.handle_after_doctype_public_identifier_state(),
+				// 13.2.5.62 Between DOCTYPE public and system identifiers state
+				| HTMLTokenizerState::BetweenDoctypePublicAndSystemIdentifiers => self.handle_between_doctype_public_and_system_identifiers_state(),
+				// 13.2.5.63 After DOCTYPE system keyword state
+				| HTMLTokenizerState::AfterDoctypeSystemKeyword => self.handle_after_doctype_system_keyword_state(),
+				// 13.2.5.64 Before DOCTYPE system identifier state
+				| HTMLTokenizerState::BeforeDoctypeSystemIdentifier => self.handle_before_doctype_system_identifier_state(),
+				// 13.2.5.65 DOCTYPE system identifier (double-quoted) state
+				// 13.2.5.66 DOCTYPE system identifier (single-quoted) state
+				| HTMLTokenizerState::DoctypeSystemIdentifier { quote } => self.handle_doctype_system_identifier_state(quote),
+				// 13.2.5.67 After DOCTYPE system identifier state
+				| HTMLTokenizerState::AfterDoctypeSystemIdentifier => self.handle_after_doctype_system_identifier_state(),
+				// 13.2.5.68 Bogus DOCTYPE state
+				| HTMLTokenizerState::BogusDoctype => self.handle_bogus_doctype_state(),
+				// 13.2.5.69 CDATA section state
+				| HTMLTokenizerState::CdataSection => self.handle_cdata_section_state(),
+				// 13.2.5.70 CDATA section bracket state
+				| HTMLTokenizerState::CdataSectionBracket => self.handle_cdata_section_bracket_state(),
+				// 13.2.5.71 CDATA section end state
+				| HTMLTokenizerState::CdataSectionEnd => self.handle_cdata_section_end_state(),
+				// 13.2.5.72 Character reference state
+				| HTMLTokenizerState::CharacterReference => self.handle_character_reference_state(),
+				// 13.2.5.73 Named character reference state
+				| HTMLTokenizerState::NamedCharacterReference => self.handle_named_character_reference_state(),
+				// 13.2.5.74 Ambiguous ampersand state
+				| HTMLTokenizerState::AmbiguousAmpersand => self.handle_ambiguous_ampersand_state(),
+				// 13.2.5.75 Numeric character reference state
+				| HTMLTokenizerState::NumericCharacterReference => self.handle_numeric_character_reference_state(),
+				// 13.2.5.76 Hexadecimal character reference start state
+				| HTMLTokenizerState::HexadecimalCharacterReferenceStart => self.handle_hexadecimal_character_reference_start_state(),
+				// 13.2.5.77 Decimal character reference start state
+				| HTMLTokenizerState::DecimalCharacterReferenceStart => self.handle_decimal_character_reference_start_state(),
+				// 13.2.5.78 Hexadecimal character reference state
+				| HTMLTokenizerState::HexadecimalCharacterReference => self.handle_hexadecimal_character_reference_state(),
+				// 13.2.5.79 Decimal character reference state
+				| HTMLTokenizerState::DecimalCharacterReference => self.handle_decimal_character_reference_state(),
+				// 13.2.5.80 Numeric character reference end state
+				| HTMLTokenizerState::NumericCharacterReferenceEnd => self.handle_numeric_character_reference_end_state(),
 			};
 
 			match control_flow {
-				| ControlFlow::Continue(ok_flow) => {
-					match ok_flow {
-						| HTMLTokenizerOk::Update(token) => {
-							self.current_token.replace(
-								token.with_location(self.current_location),
-							);
-						}
-						| HTMLTokenizerOk::UpdateFn(update) => {
-							if let Some(token) = self.current_token.as_mut() {
-								update(token);
-							}
-						}
-						// TODO: améliorer la gestion d'erreur.
-						| HTMLTokenizerOk::UpdateFnWithError(update, err) => {
-							eprintln!(
-								"HTMLTokenizer error: {}",
-								err.with_location(self.current_location)
-							);
-
-							if let Some(token) = self.current_token.as_mut() {
-								update(token);
-							}
+				| ControlFlow::Continue(ok_flow) => match ok_flow {
+					| HTMLTokenizerOk::Update(token) => {
+						self.replace_current_token(token);
+					}
+					| HTMLTokenizerOk::UpdateFn(update) => {
+						if let Some(token) = self.current_token.as_mut() {
+							update(token);
 						}
+					}
+					| HTMLTokenizerOk::UpdateFnWithError(update, err) => {
+						self.report_error(err)?;
 
-						| HTMLTokenizerOk::Emit(token) => {
-							return Ok(vec![
-								token.with_location(self.current_location)
-							]);
+						if let Some(token) = self.current_token.as_mut() {
+							update(token);
 						}
+					}
 
-						| HTMLTokenizerOk::EmitCurrent => {
-							break;
-						}
-						// TODO: améliorer la gestion d'erreur.
-						| HTMLTokenizerOk::EmitCurrentWithError(err) => {
-							eprintln!(
-								"HTMLTokenizer error: {}",
-								err.with_location(self.current_location)
-							);
-
-							break;
-						}
+					| HTMLTokenizerOk::Emit(token) => {
+						return Ok(self.locate_emitted_tokens(vec![token]));
+					}
 
-						// TODO: améliorer la gestion d'erreur.
-						| HTMLTokenizerOk::EmitWithError(token, err) => {
-							eprintln!(
-								"HTMLTokenizer error: {}",
-								err.with_location(self.current_location)
-							);
-							return Ok(vec![
-								token.with_location(self.current_location)
-							]);
-						}
-						// TODO: améliorer la gestion d'erreur.
-						| HTMLTokenizerOk::ManyEmitWithError(tokens, err) => {
-							eprintln!(
-								"HTMLTokenizer error: {}",
-								err.with_location(self.current_location)
-							);
-							return Ok(tokens);
-						}
+					| HTMLTokenizerOk::EmitCurrent => {
+						break;
+					}
+					| HTMLTokenizerOk::EmitCurrentWithError(err) => {
+						self.report_error(err)?;
+						break;
+					}
 
-						// TODO: améliorer la gestion d'erreur.
-						| HTMLTokenizerOk::Full {
-							emit,
-							update,
-							error,
-						} => {
-							self.current_token.replace(
-								update.with_location(self.current_location),
-							);
-
-							eprintln!(
-								"HTMLTokenizer error: {}",
-								error.with_location(self.current_location)
-							);
-
-							return Ok(vec![
-								emit.with_location(self.current_location)
-							]);
-						}
+					| HTMLTokenizerOk::EmitCurrentAndEndOfStream => {
+						return Ok(self.take_current_and_end_of_stream());
+					}
+					| HTMLTokenizerOk::EmitCurrentAndEndOfStreamWithError(
+						err,
+					) => {
+						self.report_error(err)?;
+						return Ok(self.take_current_and_end_of_stream());
+					}
 
-						| HTMLTokenizerOk::None => continue,
+					| HTMLTokenizerOk::EmitWithError(token, err) => {
+						self.report_error(err)?;
+						return Ok(self.locate_emitted_tokens(vec![token]));
+					}
+					| HTMLTokenizerOk::ManyEmit(tokens) => {
+						return Ok(self.locate_emitted_tokens(tokens));
+					}
+					| HTMLTokenizerOk::ManyEmitWithError(tokens, err) => {
+						self.report_error(err)?;
+						return Ok(self.locate_emitted_tokens(tokens));
 					}
-				}
 
+					| HTMLTokenizerOk::None => continue,
+				},
+
+				// NOTE: les erreurs d'analyse sont récupérables (cf. les règles
+				// de récupération de la spécification), l'analyse continue
+				// sauf en mode strict.
 				| ControlFlow::Break(err_flow) => {
 					match err_flow {
 						| HTMLTokenizerErr::Update(token, err) => {
-							self.current_token.replace(token);
-							return Err(
-								err.with_location(self.current_location)
-							);
+							self.replace_current_token(token);
+							self.report_error(err)?;
 						}
 
 						| HTMLTokenizerErr::Emit(err) => {
-							return Err(err.with_location(self.current_location))
+							self.report_error(err)?;
 						}
 					}
 				}
 			}
 		}
 
-		self.current_token
-			.as_ref()
-			.map(|t| vec![t.to_owned()])
-			.clone()
-			.ok_or(HTMLLexicalError::idk().with_location(self.current_location))
+		let Some(mut token) = self.current_token.take() else {
+			return Err(HTMLLexicalError::idk()
+				.with_location(Location::at(self.input.position())));
+		};
+
+		token.remove_duplicate_attributes();
+		if token.is_closed_tag()
+			&& token
+				.tag_attributes()
+				.is_some_and(|attrs| !attrs.is_empty())
+		{
+			self.report_error(HTMLLexicalError::end_tag_with_attributes())?;
+		}
+
+		if token.is_start_tag() {
+			self.last_start_tag_name = token.tag_name().map(ToOwned::to_owned);
+		}
+
+		Ok(self.locate_emitted_tokens(vec![token]))
+	}
+
+	/// Définit l'emplacement des jetons émis: du premier point de code
+	/// consommé depuis la dernière émission jusqu'au dernier.
+	///
+	/// Lorsque les jetons émis sont exactement les caractères consommés (par
+	/// exemple `</a` dans l'état RCDATA), chaque caractère reçoit son propre
+	/// emplacement.
+	fn locate_emitted_tokens(
+		&mut self,
+		tokens: Vec<HTMLToken>,
+	) -> Vec<HTMLToken>
+	{
+		let start =
+			std::mem::replace(&mut self.token_start, self.input.position());
+		let end = self.input.position();
+
+		let characters: Option<Vec<char>> = tokens
+			.iter()
+			.map(|token| {
+				match token.lexeme() {
+					| HTMLLexeme::Character(ch) => Some(*ch),
+					| _ => None,
+				}
+			})
+			.collect();
+
+		if let Some(characters) = characters.filter(|characters| {
+			characters.len() > 1
+				&& characters.iter().map(|ch| ch.len_utf8()).sum::<usize>()
+					== end.offset() - start.offset()
+		}) {
+			let mut position = start;
+			return tokens
+				.into_iter()
+				.zip(characters)
+				.map(|(token, ch)| {
+					let token_start = position;
+					position.advance(ch);
+					token
+						.with_location(Location::between(token_start, position))
+				})
+				.collect();
+		}
+
+		tokens
+			.into_iter()
+			.map(|token| {
+				if token.is_end_of_stream() {
+					token.with_location(Location::at(end))
+				} else {
+					token.with_location(Location::between(start, end))
+				}
+			})
+			.collect()
+	}
+
+	/// Signale une erreur d'analyse récupérable.
+	///
+	/// En mode strict, l'erreur est retournée à l'appelant et met fin à
+	/// l'analyse. En mode tolérant, l'erreur est transmise au collecteur de
+	/// diagnostics et l'analyse continue.
+	fn report_error(
+		&mut self,
+		err: HTMLLexicalError,
+	) -> Result<(), HTMLLexicalError>
+	{
+		let err = err.with_location(Location::at(self.input.position()));
+
+		match self.mode {
+			| HTMLTokenizerMode::Strict => Err(err),
+			| HTMLTokenizerMode::Lenient => {
+				match self.diagnostics_sink.as_mut() {
+					| Some(sink) => sink.report(err),
+					| None => self.diagnostics.push(err),
+				}
+				Ok(())
+			}
+		}
 	}
 
 	pub fn reconsume(&mut self, state: HTMLTokenizerState)
 	{
-		self.input.rollback_once();
+		// NOTE: la fin du flux n'est pas ajoutée à la liste des éléments
+		// consommés, il n'y a donc rien à restituer dans ce cas.
+		if self.input.current().is_some() {
+			let restored = self.input.rollback_once();
+			debug_assert!(restored, "le point de code courant est conservé");
+		}
 		self.current_state.switch(state);
 	}
+
+	/// Remplace le jeton courant par le jeton donné.
+	pub(crate) fn replace_current_token(&mut self, token: HTMLToken)
+	{
+		self.current_token.replace(token);
+	}
+
+	/// Met à jour le jeton courant, s'il y en a un.
+	pub(crate) fn update_current_token(
+		&mut self,
+		update: impl FnOnce(&mut HTMLToken),
+	)
+	{
+		if let Some(token) = self.current_token.as_mut() {
+			update(token);
+		}
+	}
+
+	/// Vérifie que les prochains points de code du flux correspondent à la
+	/// séquence donnée, sans les consommer.
+	pub(crate) fn is_next_sequence(
+		&mut self,
+		sequence: &str,
+		ascii_case_insensitive: bool,
+	) -> bool
+	{
+		if ascii_case_insensitive {
+			self.input.starts_with_ignore_ascii_case(sequence)
+		} else {
+			self.input.starts_with(sequence)
+		}
+	}
+
+	/// Consomme autant de points de code que la séquence donnée en contient.
+	pub(crate) fn consume_sequence(&mut self, sequence: &str)
+	{
+		for _ in sequence.chars() {
+			self.input.consume_next();
+		}
+	}
+
+	/// Vérifie que le jeton courant est une balise de fin appropriée, c'est à
+	/// dire une balise de fin dont le nom correspond à celui de la dernière
+	/// balise de début émise.
+	pub(crate) fn is_appropriate_end_tag(&self) -> bool
+	{
+		match (&self.current_token, &self.last_start_tag_name) {
+			| (Some(token), Some(last_start_tag_name)) => {
+				token.is_closed_tag()
+					&& token.tag_name() == Some(last_start_tag_name.as_str())
+			}
+			| _ => false,
+		}
+	}
+
+	/// Retire le jeton courant et le retourne accompagné d'un jeton de fin de
+	/// flux.
+	fn take_current_and_end_of_stream(&mut self) -> Vec<HTMLToken>
+	{
+		let mut tokens: Vec<_> =
+			self.current_token.take().into_iter().collect();
+		tokens.push(HTMLToken::end_of_stream());
+		self.locate_emitted_tokens(tokens)
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> Iterator for HTMLTokenizer<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	type Item = Result<HTMLToken, HTMLLexicalError>;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		loop {
+			if let Some(token) = self.emitted_tokens.pop_front() {
+				if token.is_end_of_stream() {
+					self.is_terminated = true;
+					self.emitted_tokens.clear();
+				}
+				return Some(Ok(token));
+			}
+
+			if self.is_terminated {
+				return None;
+			}
+
+			match self.consume_next() {
+				| Ok(tokens) => self.emitted_tokens.extend(tokens),
+				| Err(err) => {
+					if self.mode == HTMLTokenizerMode::Strict {
+						self.is_terminated = true;
+					}
+					return Some(Err(err));
+				}
+			}
+		}
+	}
+}
+
+impl<Input: Iterator> FusedIterator for HTMLTokenizer<Input> where
+	Input::Item: UnitCodePointExt
+{
 }
 
 impl HTMLTokenizerState
@@ -285,3 +765,571 @@ impl HTMLTokenizerState
 		*self = new_state;
 	}
 }
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use sky_lang::DiagnosticRenderer;
+
+	use super::*;
+	use crate::lexical::lexeme::{HTMLLexeme, TagState};
+
+	fn tokenize(source: &str) -> Vec<HTMLLexeme>
+	{
+		tokenize_with(HTMLTokenizer::new(source.chars()))
+	}
+
+	fn tokenize_in(
+		state: HTMLTokenizerState,
+		last_start_tag_name: &str,
+		source: &str,
+	) -> Vec<HTMLLexeme>
+	{
+		let mut tokenizer = HTMLTokenizer::new(source.chars());
+		tokenizer.switch_state(state);
+		tokenizer.set_last_start_tag_name(last_start_tag_name);
+		tokenize_with(tokenizer)
+	}
+
+	fn tokenize_with(
+		tokenizer: HTMLTokenizer<std::str::Chars<'_>>,
+	) -> Vec<HTMLLexeme>
+	{
+		tokenizer
+			.filter_map(Result::ok)
+			.take_while(|token| !token.is_end_of_stream())
+			.map(|token| token.lexeme().clone())
+			.collect()
+	}
+
+	#[test]
+	fn test_comment()
+	{
+		assert_eq!(
+			tokenize("<!-- Mon super commentaire -->a"),
+			vec![
+				HTMLLexeme::Comment(" Mon super commentaire ".into()),
+				HTMLLexeme::Character('a'),
+			]
+		);
+
+		assert_eq!(tokenize("<!---->"), vec![HTMLLexeme::Comment("".into())]);
+		assert_eq!(tokenize("<!-->"), vec![HTMLLexeme::Comment("".into())]);
+		assert_eq!(
+			tokenize("<!-- a -- b --!>"),
+			vec![HTMLLexeme::Comment(" a -- b ".into())]
+		);
+		assert_eq!(
+			tokenize("<!-- <!-- b -->"),
+			vec![HTMLLexeme::Comment(" <!-- b ".into())]
+		);
+		assert_eq!(
+			tokenize("<!-- eof"),
+			vec![HTMLLexeme::Comment(" eof".into())]
+		);
+	}
+
+	#[test]
+	fn test_bogus_comment()
+	{
+		assert_eq!(
+			tokenize("<?xml version=\"1.0\"?>"),
+			vec![HTMLLexeme::Comment("?xml version=\"1.0\"?".into())]
+		);
+		assert_eq!(
+			tokenize("<!ELEMENT br EMPTY>"),
+			vec![HTMLLexeme::Comment("ELEMENT br EMPTY".into())]
+		);
+		assert_eq!(tokenize("</ b>"), vec![HTMLLexeme::Comment(" b".into())]);
+	}
+
+	#[test]
+	fn test_doctype()
+	{
+		let doctype = |name: Option<&str>,
+		               public_id: Option<&str>,
+		               system_id: Option<&str>,
+		               force_quirks: bool| {
+			HTMLLexeme::Doctype {
+				name: name.map(String::from),
+				public_id: public_id.map(String::from),
+				system_id: system_id.map(String::from),
+				force_quirks,
+			}
+		};
+
+		assert_eq!(
+			tokenize("<!DOCTYPE html>"),
+			vec![doctype(Some("html"), None, None, false)]
+		);
+		assert_eq!(
+			tokenize("<!doctype HTML>"),
+			vec![doctype(Some("html"), None, None, false)]
+		);
+		assert_eq!(
+			tokenize(
+				"<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \
+				 'http://www.w3.org/TR/html4/strict.dtd'>"
+			),
+			vec![doctype(
+				Some("html"),
+				Some("-//W3C//DTD HTML 4.01//EN"),
+				Some("http://www.w3.org/TR/html4/strict.dtd"),
+				false
+			)]
+		);
+		assert_eq!(
+			tokenize("<!DOCTYPE html SYSTEM \"about:legacy-compat\">"),
+			vec![doctype(
+				Some("html"),
+				None,
+				Some("about:legacy-compat"),
+				false
+			)]
+		);
+		assert_eq!(
+			tokenize("<!DOCTYPE>"),
+			vec![doctype(None, None, None, true)]
+		);
+		assert_eq!(
+			tokenize("<!DOCTYPE html foo>"),
+			vec![doctype(Some("html"), None, None, true)]
+		);
+		assert_eq!(
+			tokenize("<!DOCTYPE html PUBLIC \"foo>"),
+			vec![doctype(Some("html"), Some("foo"), None, true)]
+		);
+		assert_eq!(
+			tokenize("<!DOCTYPE html"),
+			vec![doctype(Some("html"), None, None, true)]
+		);
+		assert_eq!(
+			tokenize("<!DOCTYPE\u{a0}html>"),
+			vec![doctype(Some("\u{a0}html"), None, None, false)]
+		);
+		assert_eq!(
+			tokenize("<!DOCTYPE html PUBLIC\u{b}\"a\"\u{a0}\"b\">"),
+			vec![doctype(Some("html"), None, None, true)]
+		);
+		assert_eq!(
+			tokenize("<!DOCTYPE html PUBLIC \"a\"\u{a0}\"b\">"),
+			vec![doctype(Some("html"), Some("a"), None, true)]
+		);
+	}
+
+	#[test]
+	fn test_self_closing_start_tag()
+	{
+		assert_eq!(
+			tokenize("<br/>"),
+			vec![HTMLLexeme::Tag {
+				name: "br".into(),
+				attributes: vec![],
+				state: TagState::SelfClosed,
+			}]
+		);
+		assert_eq!(
+			tokenize("<input type=\"text\" />"),
+			vec![HTMLLexeme::Tag {
+				name: "input".into(),
+				attributes: vec![("type".into(), "text".into())],
+				state: TagState::SelfClosed,
+			}]
+		);
+		assert_eq!(
+			tokenize("<div / id=a>"),
+			vec![HTMLLexeme::Tag {
+				name: "div".into(),
+				attributes: vec![("id".into(), "a".into())],
+				state: TagState::Opened,
+			}]
+		);
+	}
+
+	#[test]
+	fn test_duplicate_and_end_tag_attributes()
+	{
+		use crate::lexical::error::HTMLLexicalErrorVariant;
+
+		let mut tokenizer = HTMLTokenizer::new("<a x=1 X=2 y x>".chars());
+		let lexemes = tokenizer
+			.by_ref()
+			.filter_map(Result::ok)
+			.map(|token| token.lexeme().clone())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			lexemes[0],
+			HTMLLexeme::Tag {
+				name: "a".into(),
+				attributes: vec![
+					("x".into(), "1".into()),
+					("y".into(), "".into()),
+				],
+				state: TagState::Opened,
+			}
+		);
+		assert!(matches!(
+			tokenizer.diagnostics(),
+			[first, second] if matches!(
+				first.variant(),
+				HTMLLexicalErrorVariant::DuplicateAttribute
+			) && matches!(
+				second.variant(),
+				HTMLLexicalErrorVariant::DuplicateAttribute
+			)
+		));
+
+		let mut tokenizer = HTMLTokenizer::new("</a x=1>".chars());
+		tokenizer.by_ref().for_each(drop);
+		assert!(matches!(
+			tokenizer.diagnostics(),
+			[err] if matches!(
+				err.variant(),
+				HTMLLexicalErrorVariant::EndTagWithAttributes
+			)
+		));
+	}
+
+	#[test]
+	fn test_character_reference()
+	{
+		let text = |source: &str| -> String {
+			tokenize(source)
+				.into_iter()
+				.map(|lexeme| {
+					match lexeme {
+						| HTMLLexeme::Character(ch) => ch,
+						| lexeme => panic!("jeton inattendu: {lexeme:?}"),
+					}
+				})
+				.collect()
+		};
+
+		assert_eq!(text("a &amp; b"), "a & b");
+		assert_eq!(text("&eacute;t&eacute;"), "été");
+		assert_eq!(text("&#x27;&#39;"), "''");
+		assert_eq!(text("&#X41"), "A");
+		assert_eq!(text("&notit;"), "¬it;");
+		assert_eq!(text("&notin;"), "∉");
+		assert_eq!(text("&not-a"), "¬-a");
+		assert_eq!(text("&foo; &"), "&foo; &");
+		assert_eq!(text("&#;"), "&#;");
+		assert_eq!(text("&#0;"), "\u{FFFD}");
+		assert_eq!(text("&#x110000;"), "\u{FFFD}");
+		assert_eq!(text("&#xD800;"), "\u{FFFD}");
+		assert_eq!(text("&#x80;&#x9F;"), "€Ÿ");
+	}
+
+	#[test]
+	fn test_character_reference_in_attribute_value()
+	{
+		let attributes = |source: &str| {
+			match &tokenize(source)[..] {
+				| [HTMLLexeme::Tag { attributes, .. }] => attributes.clone(),
+				| lexemes => panic!("jetons inattendus: {lexemes:?}"),
+			}
+		};
+
+		assert_eq!(
+			attributes("<a title=\"&lt;&#x3E;\">"),
+			vec![("title".into(), "<>".into())]
+		);
+		assert_eq!(
+			attributes("<a href='?a&not=1&copy'>"),
+			vec![("href".into(), "?a&not=1©".into())]
+		);
+		assert_eq!(
+			attributes("<a href=?a&notx&amp>"),
+			vec![("href".into(), "?a&notx&".into())]
+		);
+	}
+
+	#[test]
+	fn test_text_states()
+	{
+		let text_then_end_tag = |text: &str, name: &str| {
+			let mut lexemes: Vec<_> =
+				text.chars().map(HTMLLexeme::Character).collect();
+			lexemes.push(HTMLLexeme::Tag {
+				name: name.into(),
+				attributes: vec![],
+				state: TagState::Closed,
+			});
+			lexemes
+		};
+
+		assert_eq!(
+			tokenize_in(
+				HTMLTokenizerState::Rcdata,
+				"title",
+				"a &amp; <b></TITLE>"
+			),
+			text_then_end_tag("a & <b>", "title")
+		);
+		assert_eq!(
+			tokenize_in(
+				HTMLTokenizerState::Rawtext,
+				"style",
+				"a &amp; </p></styles></style>"
+			),
+			text_then_end_tag("a &amp; </p></styles>", "style")
+		);
+		assert_eq!(
+			tokenize_in(
+				HTMLTokenizerState::Rcdata,
+				"title",
+				"a</title\u{a0}>b</title>"
+			),
+			text_then_end_tag("a</title\u{a0}>b", "title")
+		);
+		assert_eq!(
+			tokenize_in(
+				HTMLTokenizerState::Plaintext,
+				"plaintext",
+				"<b>&amp;</plaintext>"
+			),
+			"<b>&amp;</plaintext>"
+				.chars()
+				.map(HTMLLexeme::Character)
+				.collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn test_script_data_states()
+	{
+		let text_then_end_tag = |text: &str| {
+			let mut lexemes: Vec<_> =
+				text.chars().map(HTMLLexeme::Character).collect();
+			lexemes.push(HTMLLexeme::Tag {
+				name: "script".into(),
+				attributes: vec![],
+				state: TagState::Closed,
+			});
+			lexemes
+		};
+
+		assert_eq!(
+			tokenize_in(
+				HTMLTokenizerState::ScriptData,
+				"script",
+				"if (a<b) {}</script>"
+			),
+			text_then_end_tag("if (a<b) {}")
+		);
+		assert_eq!(
+			tokenize_in(
+				HTMLTokenizerState::ScriptData,
+				"script",
+				"<!-- a </b> --></script>"
+			),
+			text_then_end_tag("<!-- a </b> -->")
+		);
+		assert_eq!(
+			tokenize_in(
+				HTMLTokenizerState::ScriptData,
+				"script",
+				"<!--<script>x</script>-->y</script>"
+			),
+			text_then_end_tag("<!--<script>x</script>-->y")
+		);
+		assert_eq!(
+			tokenize_in(
+				HTMLTokenizerState::ScriptData,
+				"script",
+				"a</script\u{2003}>b</script>"
+			),
+			text_then_end_tag("a</script\u{2003}>b")
+		);
+		assert_eq!(
+			tokenize_in(
+				HTMLTokenizerState::ScriptData,
+				"script",
+				"<!--<script\u{a0}></script>"
+			),
+			text_then_end_tag("<!--<script\u{a0}>")
+		);
+	}
+
+	#[test]
+	fn test_last_start_tag_name()
+	{
+		let mut tokenizer = HTMLTokenizer::new("<title>".chars());
+		let tokens = tokenizer.consume_next().unwrap_or_default();
+		assert!(tokens[0].is_start_tag());
+		assert_eq!(tokenizer.last_start_tag_name.as_deref(), Some("title"));
+	}
+
+	#[test]
+	fn test_cdata_section()
+	{
+		let mut tokenizer = HTMLTokenizer::new("<![CDATA[a<b]]c]]]>d".chars());
+		tokenizer.set_in_foreign_content(true);
+		assert_eq!(
+			tokenize_with(tokenizer),
+			"a<b]]c]d"
+				.chars()
+				.map(HTMLLexeme::Character)
+				.collect::<Vec<_>>()
+		);
+
+		assert_eq!(
+			tokenize("<![CDATA[x]]>"),
+			vec![HTMLLexeme::Comment("[CDATA[x]]".into())]
+		);
+	}
+
+	#[test]
+	fn test_diagnostics()
+	{
+		use crate::lexical::error::HTMLLexicalErrorVariant;
+
+		let mut tokenizer = HTMLTokenizer::new("<!-->a\0".chars());
+		let mut tokens = vec![];
+		while let Ok(emitted) = tokenizer.consume_next() {
+			tokens.extend(emitted);
+			if tokens.last().is_some_and(HTMLToken::is_end_of_stream) {
+				break;
+			}
+		}
+
+		assert_eq!(tokens.len(), 4);
+		assert!(matches!(
+			tokenizer.diagnostics(),
+			[first, second] if matches!(
+				first.variant(),
+				HTMLLexicalErrorVariant::AbruptClosingOfEmptyComment
+			) && matches!(
+				second.variant(),
+				HTMLLexicalErrorVariant::UnexpectedNullCharacter
+			)
+		));
+
+		let mut tokenizer = HTMLTokenizer::new("<!-->".chars())
+			.with_mode(HTMLTokenizerMode::Strict);
+		assert!(matches!(
+			tokenizer.consume_next(),
+			Err(err) if matches!(
+				err.variant(),
+				HTMLLexicalErrorVariant::AbruptClosingOfEmptyComment
+			)
+		));
+		assert!(tokenizer.diagnostics().is_empty());
+
+		let reported = std::rc::Rc::new(std::cell::Cell::new(0));
+		let mut tokenizer = HTMLTokenizer::new("</>".chars())
+			.with_diagnostics_sink({
+				let reported = reported.clone();
+				move |_| reported.set(reported.get() + 1)
+			});
+		_ = tokenizer.consume_next();
+		assert_eq!(reported.get(), 1);
+		assert!(tokenizer.diagnostics().is_empty());
+	}
+
+	#[test]
+	fn test_diagnostic_rendering()
+	{
+		let source = "<p>\n<!-->a</>";
+		let mut tokenizer = HTMLTokenizer::new(source.chars());
+		tokenizer.by_ref().for_each(drop);
+
+		let diagnostics = tokenizer
+			.diagnostics()
+			.iter()
+			.map(HTMLLexicalError::to_diagnostic)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			DiagnosticRenderer::new(source).render_all(&diagnostics),
+			"erreur[abrupt-closing-of-empty-comment]: Fermeture abrupte d'un \
+			 commentaire vide\n --> 2:6\n  |\n2 | <!-->a</>\n  |      \
+			 ^\n\nerreur[missing-end-tag-name]: Caractère '>' manquant\n --> \
+			 2:10\n  |\n2 | <!-->a</>\n  |          ^\n"
+		);
+	}
+
+	#[test]
+	fn test_iterator()
+	{
+		let mut tokenizer = HTMLTokenizer::new("a<".chars());
+		let lexemes: Vec<_> = tokenizer
+			.by_ref()
+			.filter_map(Result::ok)
+			.map(|token| token.lexeme().clone())
+			.collect();
+		assert_eq!(
+			lexemes,
+			vec![
+				HTMLLexeme::Character('a'),
+				HTMLLexeme::Character('<'),
+				HTMLLexeme::EndOfStream,
+			]
+		);
+		assert!(tokenizer.next().is_none());
+		assert_eq!(tokenizer.diagnostics().len(), 1);
+
+		let mut tokenizer = HTMLTokenizer::new("<a href=x>".chars());
+		let token = tokenizer.next().and_then(Result::ok).expect("balise");
+		assert_eq!(token.tag_name(), Some("a"));
+		assert_eq!(
+			token.tag_attributes(),
+			Some(&[("href".to_owned(), "x".to_owned())][..])
+		);
+		assert!(token.is_start_tag() && !token.is_self_closing_tag());
+
+		let mut tokenizer = HTMLTokenizer::new("<!-->a".chars())
+			.with_mode(HTMLTokenizerMode::Strict);
+		assert!(matches!(tokenizer.next(), Some(Err(_))));
+		assert!(tokenizer.next().is_none());
+	}
+
+	#[test]
+	fn test_token_location()
+	{
+		let source = "<p\tid=a>é\r\n<!-- x --></p>";
+		let spans: Vec<_> = HTMLTokenizer::new(source.chars())
+			.filter_map(Result::ok)
+			.map(|token| {
+				let location = token.location();
+				(
+					&source[location.range()],
+					(location.start().line(), location.start().column()),
+					(location.end().line(), location.end().column()),
+				)
+			})
+			.collect();
+
+		assert_eq!(
+			spans,
+			vec![
+				("<p\tid=a>", (1, 1), (1, 9)),
+				("é", (1, 9), (1, 10)),
+				("\r\n", (1, 10), (2, 1)),
+				("<!-- x -->", (2, 1), (2, 11)),
+				("</p>", (2, 11), (2, 15)),
+				("", (2, 15), (2, 15)),
+			]
+		);
+
+		let mut tokenizer = HTMLTokenizer::new("a &amp; b".chars());
+		let ampersand = tokenizer
+			.by_ref()
+			.filter_map(Result::ok)
+			.nth(2)
+			.expect("référence de caractère");
+		assert_eq!(ampersand.location().range(), 2..7);
+
+		let mut tokenizer = HTMLTokenizer::new("x</ab".chars());
+		tokenizer.switch_state(HTMLTokenizerState::Rcdata);
+		tokenizer.set_last_start_tag_name("title");
+		let ranges: Vec<_> = tokenizer
+			.filter_map(Result::ok)
+			.map(|token| token.location().range())
+			.collect();
+		assert_eq!(ranges, vec![0..1, 1..2, 2..3, 3..4, 4..5, 5..5]);
+	}
+}
@@ -10,19 +10,55 @@
 
 #![allow(dead_code)]
 
+mod backend;
+mod dom;
+mod encoding;
 mod lexical;
+mod selector;
 mod syntax;
 
-use std::borrow::Cow;
-use std::collections::BTreeMap;
-
-use html5ever::interface::*;
-use html5ever::tendril::*;
+#[cfg(feature = "html5ever")]
 pub use html5ever::Attribute;
-use html5ever::*;
 
-pub use self::lexical::{HTMLLexicalError, HTMLToken};
-pub use self::syntax::{HTMLParser, HTMLParserError};
+#[cfg(feature = "html5ever")]
+pub use self::backend::HTML5everBackend;
+pub use self::backend::{HTMLDocumentBackend, HTMLEngine, NativeBackend};
+pub use self::dom::{
+	HTMLAncestors,
+	HTMLAttribute,
+	HTMLChildren,
+	HTMLDescendants,
+	HTMLMutation,
+	HTMLMutationError,
+	HTMLMutationObserver,
+	HTMLNamespace,
+	HTMLNode,
+	HTMLNodeData,
+	HTMLNodeId,
+	HTMLQuirksMode,
+	HTMLSerializer,
+	HTMLTree,
+};
+pub use self::encoding::{Encoding, HTMLEncoding, HTMLEncodingConfidence};
+pub use self::lexical::{
+	HTMLLexeme,
+	HTMLLexicalError,
+	HTMLLexicalErrorSink,
+	HTMLLexicalErrorVariant,
+	HTMLToken,
+	HTMLTokenizer,
+	HTMLTokenizerMode,
+	HTMLTokenizerState,
+	TagState,
+};
+pub use self::selector::{HTMLSelector, HTMLSelectorError};
+pub use self::syntax::{
+	HTMLFragmentContext,
+	HTMLInsertionMode,
+	HTMLParser,
+	HTMLParserError,
+	HTMLParserErrorSink,
+};
 
 // --------- //
 // Structure //
@@ -30,17 +66,40 @@ pub use self::syntax::{HTMLParser, HTMLParserError};
 
 pub struct HTMLDocument
 {
-	next_id: usize,
-	pub elements: BTreeMap<usize, HTMLElement>,
+	tree: HTMLTree,
+	/// Erreurs d'analyse récupérables, dans l'ordre d'apparition.
+	errors: Vec<HTMLDocumentParseError>,
+	/// Moteur d'analyse ayant construit le document.
+	engine: HTMLEngine,
+	/// Encodage des octets du document.
+	encoding: HTMLEncoding,
+	/// Observateurs des modifications de l'arbre.
+	observers: Vec<Box<dyn HTMLMutationObserver>>,
+}
+
+/// Fragment HTML analysé dans le contexte d'un élément, ex: le nouveau contenu
+/// d'un élément.
+pub struct HTMLDocumentFragment
+{
+	/// Arbre du fragment: les nœuds du fragment sont les enfants de son
+	/// élément racine `<html>`.
+	tree: HTMLTree,
+	/// Erreurs d'analyse récupérables, dans l'ordre d'apparition.
+	errors: Vec<HTMLDocumentParseError>,
+	/// Moteur d'analyse ayant construit le fragment.
+	engine: HTMLEngine,
 }
 
+/// Erreur d'analyse récupérable, ex: une balise de fin inattendue.
 #[derive(Debug)]
-pub struct HTMLElement
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct HTMLDocumentParseError
 {
-	pub name: QualName,
-	pub attributes: Vec<Attribute>,
-	pub text: Option<Tendril<fmt::UTF8>>,
-	pub parent: usize,
+	/// Ligne (à partir de 1) où l'erreur est survenue.
+	pub line: u64,
+	/// Description de l'erreur.
+	pub message: String,
 }
 
 // -------------- //
@@ -49,208 +108,507 @@ pub struct HTMLElement
 
 impl HTMLDocument
 {
+	pub(crate) fn new(
+		tree: HTMLTree,
+		errors: Vec<HTMLDocumentParseError>,
+		engine: HTMLEngine,
+	) -> Self
+	{
+		Self {
+			tree,
+			errors,
+			engine,
+			encoding: Default::default(),
+			observers: Vec::new(),
+		}
+	}
+
 	pub fn from_file(
 		filepath: impl AsRef<std::path::Path>,
+		engine: HTMLEngine,
 	) -> Result<Self, HTMLParserError>
 	{
-		let this = Self {
-			next_id: 1,
-			elements: BTreeMap::new(),
-		};
-
-		let this = html5ever::parse_document(this, Default::default())
-			.from_utf8()
-			.from_file(filepath.as_ref())?;
-
-		Ok(this)
+		let mut file = std::fs::File::open(filepath)?;
+		Self::from_stream(&mut file, engine)
 	}
 
 	pub fn from_slice(
 		slice: &mut &[u8],
+		engine: HTMLEngine,
 	) -> Result<Self, HTMLParserError>
 	{
-		let this = Self {
-			next_id: 1,
-			elements: BTreeMap::new(),
-		};
-
-		let this = html5ever::parse_document(this, Default::default())
-			.from_utf8()
-			.read_from(slice)?;
-
-		Ok(this)
+		Self::from_stream(slice, engine)
 	}
 
 	pub fn from_stream(
 		stream: &mut impl std::io::Read,
+		engine: HTMLEngine,
 	) -> Result<Self, HTMLParserError>
 	{
-		let this = Self {
-			next_id: 1,
-			elements: BTreeMap::new(),
-		};
+		Self::from_stream_with_content_type(stream, None, engine)
+	}
+
+	/// Analyse le document, décodé selon son type de contenu, ex: l'en-tête
+	/// `Content-Type` de la réponse HTTP, ou selon l'encodage déterminé à
+	/// partir de ses octets, voir [HTMLEncoding::sniff].
+	pub fn from_stream_with_content_type(
+		stream: &mut impl std::io::Read,
+		content_type: Option<&str>,
+		engine: HTMLEngine,
+	) -> Result<Self, HTMLParserError>
+	{
+		let mut bytes = Vec::new();
+		stream.read_to_end(&mut bytes)?;
+
+		let encoding = HTMLEncoding::sniff(&bytes, content_type);
+		let source = encoding.decode(&bytes);
+
+		let mut document =
+			engine.backend().parse_document(&mut source.as_bytes())?;
+		document.encoding = encoding;
+		Ok(document)
+	}
+
+	/// Moteur d'analyse ayant construit le document.
+	pub const fn engine(&self) -> HTMLEngine
+	{
+		self.engine
+	}
+
+	/// Encodage des octets du document.
+	pub const fn encoding(&self) -> HTMLEncoding
+	{
+		self.encoding
+	}
+
+	/// Erreurs d'analyse récupérables rencontrées lors de la construction du
+	/// document.
+	pub fn errors(&self) -> &[HTMLDocumentParseError]
+	{
+		&self.errors
+	}
+
+	/// Arbre du document.
+	pub const fn tree(&self) -> &HTMLTree
+	{
+		&self.tree
+	}
+
+	/// Analyse un fragment dans le contexte d'un élément du document, à la
+	/// manière de `innerHTML`, avec le moteur d'analyse du document.
+	pub fn parse_fragment(
+		&self,
+		context_element: HTMLNodeId,
+		input: &str,
+	) -> Result<HTMLDocumentFragment, HTMLParserError>
+	{
+		let context =
+			HTMLFragmentContext::from_element(&self.tree, context_element)
+				.ok_or(HTMLParserError::InvalidFragmentContext(
+					context_element,
+				))?;
+		HTMLDocumentFragment::from_slice(
+			&context,
+			&mut input.as_bytes(),
+			self.engine,
+		)
+	}
 
-		let this = html5ever::parse_document(this, Default::default())
-			.from_utf8()
-			.read_from(stream)?;
+	/// Premier élément du document qui correspond au sélecteur.
+	pub fn query_selector(&self, selector: &HTMLSelector)
+		-> Option<HTMLNodeId>
+	{
+		self.tree.query_selector(self.tree.document(), selector)
+	}
 
-		Ok(this)
+	/// Éléments du document, dans l'ordre du document, qui correspondent au
+	/// sélecteur.
+	pub fn query_selector_all(&self, selector: &HTMLSelector)
+		-> Vec<HTMLNodeId>
+	{
+		self.tree.query_selector_all(self.tree.document(), selector)
+	}
+
+	/// Sérialisation HTML du document.
+	pub fn to_html(&self) -> String
+	{
+		self.tree.outer_html(self.tree.document())
 	}
 
-	fn get_id(&mut self) -> usize
+	/// Élément `<title>` du document.
+	pub fn title(&self) -> Option<String>
 	{
-		let id = self.next_id;
-		self.next_id += 2;
-		id
+		self.tree
+			.descendants(self.tree.document())
+			.find(|id| self.tree[*id].is_html("title"))
+			.map(|id| self.tree.text_content(id))
 	}
 }
 
-impl TreeSink for HTMLDocument
+impl HTMLDocumentFragment
 {
-	type Handle = usize;
-	type Output = Self;
+	pub(crate) fn new(
+		tree: HTMLTree,
+		errors: Vec<HTMLDocumentParseError>,
+		engine: HTMLEngine,
+	) -> Self
+	{
+		Self {
+			tree,
+			errors,
+			engine,
+		}
+	}
 
-	fn finish(self) -> Self
+	pub fn from_slice(
+		context: &HTMLFragmentContext,
+		slice: &mut &[u8],
+		engine: HTMLEngine,
+	) -> Result<Self, HTMLParserError>
 	{
-		self
+		Self::from_stream(context, slice, engine)
 	}
 
-	fn parse_error(&mut self, _: Cow<'static, str>) {}
+	pub fn from_stream(
+		context: &HTMLFragmentContext,
+		stream: &mut impl std::io::Read,
+		engine: HTMLEngine,
+	) -> Result<Self, HTMLParserError>
+	{
+		engine.backend().parse_fragment(context, stream)
+	}
 
-	fn get_document(&mut self) -> usize
+	/// Moteur d'analyse ayant construit le fragment.
+	pub const fn engine(&self) -> HTMLEngine
 	{
-		0
+		self.engine
 	}
 
-	fn get_template_contents(&mut self, target: &usize) -> usize
+	/// Erreurs d'analyse récupérables rencontrées lors de la construction du
+	/// fragment.
+	pub fn errors(&self) -> &[HTMLDocumentParseError]
 	{
-		if let Some(expanded_name!(html "template")) =
-			self.elements.get(target).map(|el| el.name.expanded())
-		{
-			target + 1
-		} else {
-			panic!("n'est pas un élément template")
-		}
+		&self.errors
+	}
+
+	/// Arbre du fragment.
+	pub const fn tree(&self) -> &HTMLTree
+	{
+		&self.tree
 	}
 
-	fn set_quirks_mode(&mut self, _: QuirksMode) {}
+	/// Élément racine `<html>`, dont les enfants sont les nœuds du fragment.
+	pub fn root(&self) -> HTMLNodeId
+	{
+		self.tree
+			.children(self.tree.document())
+			.next()
+			.expect("l'élément racine du fragment")
+	}
 
-	fn same_node(&self, x: &usize, y: &usize) -> bool
+	/// Nœuds du fragment.
+	pub fn children(&self) -> HTMLChildren<'_>
 	{
-		x == y
+		self.tree.children(self.root())
 	}
 
-	fn elem_name(&self, target: &usize) -> ExpandedName
+	/// Sérialisation HTML du fragment.
+	pub fn to_html(&self) -> String
 	{
-		self.elements
-			.get(target)
-			.expect("n'est pas un élément")
-			.name
-			.expanded()
+		self.tree.inner_html(self.root())
 	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
 
-	fn create_element(
-		&mut self,
-		name: QualName,
-		attributes: Vec<Attribute>,
-		_: ElementFlags,
-	) -> usize
+impl std::fmt::Display for HTMLDocumentParseError
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
 	{
-		let id = self.get_id();
-		self.elements.insert(
-			id,
-			HTMLElement {
-				name,
-				attributes,
-				text: Default::default(),
-				parent: Default::default(),
-			},
-		);
-		id
+		write!(f, "ligne {}: {}", self.line, self.message)
 	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
 
-	fn create_comment(&mut self, _: StrTendril) -> usize
+	/// Analyse la source avec chacun des moteurs disponibles.
+	fn parse_all(source: &str) -> Vec<HTMLDocument>
 	{
-		self.get_id()
+		HTMLEngine::ALL
+			.iter()
+			.map(|engine| {
+				HTMLDocument::from_slice(&mut source.as_bytes(), *engine)
+					.unwrap()
+			})
+			.collect()
 	}
 
-	fn create_pi(&mut self, _: StrTendril, _: StrTendril) -> usize
+	fn find(doc: &HTMLDocument, name: &str) -> HTMLNodeId
 	{
-		unimplemented!()
+		let tree = doc.tree();
+		tree.descendants(tree.document())
+			.find(|id| tree[*id].is_html(name))
+			.unwrap()
 	}
 
-	fn append(&mut self, parent: &usize, child: NodeOrText<usize>)
+	fn outline(tree: &HTMLTree, id: HTMLNodeId) -> String
 	{
-		match child {
-			| AppendNode(node_id) => {
-				match self.elements.get_mut(&node_id) {
-					| Some(node_el) => node_el.parent = *parent,
-					| None => {}
-				}
+		let children = tree
+			.children(id)
+			.map(|child| outline(tree, child))
+			.collect::<Vec<_>>()
+			.join(" ");
+		match tree[id].data() {
+			| HTMLNodeData::Element { name, .. } if children.is_empty() => {
+				name.to_owned()
 			}
-			| AppendText(text) => {
-				match self.elements.get_mut(parent) {
-					| Some(parent_el) => {
-						if let Some(tendril) = parent_el.text.as_mut() {
-							tendril.push_tendril(&text);
-						} else {
-							parent_el.text.replace(text);
-						}
-					}
-					| None => {}
-				}
+			| HTMLNodeData::Element { name, .. } => {
+				format!("{name}({children})")
 			}
+			| HTMLNodeData::Text(text) => format!("{text:?}"),
+			| HTMLNodeData::Comment(data) => format!("<!--{data}-->"),
+			| _ => children,
 		}
 	}
 
-	fn append_before_sibling(&mut self, _: &usize, _: NodeOrText<usize>) {}
+	fn assert_body_outline(source: &str, expected: &str)
+	{
+		for doc in parse_all(source) {
+			let body = find(&doc, "body");
+			assert_eq!(
+				outline(doc.tree(), body),
+				expected,
+				"moteur: {}",
+				doc.engine()
+			);
+		}
+	}
+
+	#[test]
+	fn test_document_keeps_interleaved_text()
+	{
+		assert_body_outline(
+			"<title>Titre</title><p>a <b>b</b> c</p><!-- x -->",
+			r#"body(p("a " b("b") " c") <!-- x -->)"#,
+		);
+
+		for doc in parse_all("<title>Titre</title>") {
+			assert_eq!(doc.title().as_deref(), Some("Titre"));
+		}
+	}
+
+	#[test]
+	fn test_document_adoption_agency()
+	{
+		assert_body_outline("<b><p>a</b>b", r#"body(b p(b("a") "b"))"#);
+	}
+
+	#[test]
+	fn test_document_foster_parenting()
+	{
+		assert_body_outline(
+			"<table>a<tr><td>b</td>c</table>",
+			r#"body("ac" table(tbody(tr(td("b")))))"#,
+		);
+	}
 
-	fn append_based_on_parent_node(
-		&mut self,
-		element: &Self::Handle,
-		_: &Self::Handle,
-		child: NodeOrText<Self::Handle>,
-	)
+	#[test]
+	fn test_document_duplicate_html_and_body_attributes()
 	{
-		self.append_before_sibling(element, child);
+		let source =
+			"<html lang=fr><body id=a><html lang=en dir=ltr><body class=b>";
+		for doc in parse_all(source) {
+			let tree = doc.tree();
+			let html = tree.document_element().unwrap();
+			assert_eq!(tree[html].attribute("lang"), Some("fr"));
+			assert_eq!(tree[html].attribute("dir"), Some("ltr"));
+
+			let body = find(&doc, "body");
+			assert_eq!(tree[body].attribute("id"), Some("a"));
+			assert_eq!(tree[body].attribute("class"), Some("b"));
+		}
 	}
 
-	fn append_doctype_to_document(
-		&mut self,
-		_: StrTendril,
-		_: StrTendril,
-		_: StrTendril,
-	)
+	#[test]
+	fn test_document_doctype_and_quirks_mode()
 	{
+		for doc in parse_all("<!DOCTYPE html><p>") {
+			let tree = doc.tree();
+			let doctype = tree.doctype().unwrap();
+			assert!(matches!(
+				tree[doctype].data(),
+				HTMLNodeData::Doctype { name, .. } if name == "html"
+			));
+			assert_eq!(tree.quirks_mode(), HTMLQuirksMode::NoQuirks);
+		}
+
+		for doc in parse_all("<p>") {
+			assert_eq!(doc.tree().doctype(), None);
+			assert_eq!(doc.tree().quirks_mode(), HTMLQuirksMode::Quirks);
+		}
 	}
 
-	fn add_attrs_if_missing(&mut self, target: &usize, attrs: Vec<Attribute>)
+	#[test]
+	fn test_document_parse_errors_with_lines()
 	{
-		if !self.elements.contains_key(target) {
-			return;
+		for doc in parse_all("<!DOCTYPE html>\n<p>a\n\n</i>\n") {
+			assert_eq!(doc.errors().len(), 1, "moteur: {}", doc.engine());
+			assert_eq!(doc.errors()[0].line, 4);
+			assert!(doc.errors()[0].to_string().starts_with("ligne 4: "));
 		}
 
-		for attr in attrs.into_iter() {
-			println!("    {:?} = {}", attr.name, attr.value);
+		for doc in parse_all("<!DOCTYPE html><p>a") {
+			assert!(doc.errors().is_empty(), "moteur: {}", doc.engine());
 		}
 	}
 
-	fn associate_with_form(
-		&mut self,
-		_: &usize,
-		_: &usize,
-		_: (&usize, Option<&usize>),
-	)
+	#[test]
+	fn test_document_crlf_keeps_source_offsets()
 	{
+		let source = "<p>a\r\nb\rc</p>\r\n<b>x</b>";
+		assert_body_outline(source, r#"body(p("a\nb\nc") "\n" b("x"))"#);
+
+		let doc = HTMLDocument::from_slice(
+			&mut source.as_bytes(),
+			HTMLEngine::Native,
+		)
+		.unwrap();
+		let b = find(&doc, "b");
+		let location = doc.tree()[b].location().unwrap();
+		assert_eq!(&source[location.range()], "<b>");
+		assert_eq!(
+			(location.start().line(), location.start().column()),
+			(4, 1)
+		);
 	}
 
-	fn remove_from_parent(&mut self, _: &usize) {}
+	#[test]
+	fn test_document_form_owner()
+	{
+		for doc in parse_all("<form><table><input></table></form>") {
+			assert_eq!(
+				doc.tree().form_owner(find(&doc, "input")),
+				Some(find(&doc, "form")),
+				"moteur: {}",
+				doc.engine()
+			);
+		}
+	}
 
-	fn reparent_children(&mut self, _: &usize, _: &usize) {}
+	#[test]
+	fn test_parse_fragment()
+	{
+		for doc in parse_all("<table><tr><td>a</table><textarea></textarea>") {
+			let table = find(&doc, "table");
+			let fragment = doc.parse_fragment(table, "<tr><td>b<td>c").unwrap();
+			assert_eq!(fragment.engine(), doc.engine());
+			assert_eq!(
+				fragment.to_html(),
+				"<tbody><tr><td>b</td><td>c</td></tr></tbody>"
+			);
+
+			let textarea = find(&doc, "textarea");
+			let fragment =
+				doc.parse_fragment(textarea, "<b>&amp;</textarea>").unwrap();
+			assert_eq!(fragment.children().count(), 1);
+			assert_eq!(
+				fragment.tree().text_content(fragment.root()),
+				"<b>&</textarea>"
+			);
+
+			let document = doc.tree().document();
+			assert!(matches!(
+				doc.parse_fragment(document, "<p>"),
+				Err(HTMLParserError::InvalidFragmentContext(id)) if id == document
+			));
+		}
+	}
 
-	fn mark_script_already_started(&mut self, _: &usize) {}
+	#[test]
+	fn test_parse_fragment_in_foreign_context()
+	{
+		for engine in HTMLEngine::ALL {
+			let context = HTMLFragmentContext::new(HTMLNamespace::Svg, "g");
+			let fragment = HTMLDocumentFragment::from_slice(
+				&context,
+				&mut "<circle/><p>x".as_bytes(),
+				*engine,
+			)
+			.unwrap();
+			assert_eq!(
+				outline(fragment.tree(), fragment.root()),
+				"html(circle p(\"x\"))"
+			);
+			assert_eq!(
+				fragment.tree().dump_children_lines(fragment.root())[0].1,
+				"| <svg circle>"
+			);
+		}
+	}
 
-	fn set_current_line(&mut self, _: u64) {}
+	#[test]
+	fn test_query_selector()
+	{
+		let source = "<!DOCTYPE html><title>Titre</title><ul><li><a \
+		              href=/a>A</a><li><a href=https://b>B</a></ul>";
+		let selector: HTMLSelector =
+			"li:nth-child(2) a[href^=http]".parse().unwrap();
+
+		for doc in parse_all(source) {
+			let title = doc
+				.query_selector(&HTMLSelector::parse("head > title").unwrap());
+			assert_eq!(title, Some(find(&doc, "title")));
+
+			let links = doc.query_selector_all(&selector);
+			assert_eq!(links.len(), 1);
+			assert_eq!(doc.tree().text_content(links[0]), "B");
+
+			let ul = find(&doc, "ul");
+			let all = HTMLSelector::parse("*").unwrap();
+			assert_eq!(doc.tree().query_selector_all(ul, &all).len(), 4);
+		}
+	}
 
-	fn pop(&mut self, _: &usize) {}
+	#[test]
+	fn test_document_encoding()
+	{
+		let source = b"<meta charset=windows-1252><title>Caf\xE9</title>";
+		for engine in HTMLEngine::ALL {
+			let doc =
+				HTMLDocument::from_slice(&mut &source[..], *engine).unwrap();
+			assert_eq!(doc.title().as_deref(), Some("Café"));
+			assert_eq!(doc.encoding().name(), "windows-1252");
+			assert_eq!(
+				doc.encoding().confidence,
+				HTMLEncodingConfidence::Tentative
+			);
+		}
+
+		let source = "<title>Café</title>".as_bytes();
+		let doc = HTMLDocument::from_stream_with_content_type(
+			&mut &source[..],
+			Some("text/html; charset=utf-8"),
+			HTMLEngine::default(),
+		)
+		.unwrap();
+		assert_eq!(doc.title().as_deref(), Some("Café"));
+		assert_eq!(doc.encoding().confidence, HTMLEncodingConfidence::Certain);
+	}
+
+	#[test]
+	fn test_engine_from_str()
+	{
+		assert_eq!("native".parse(), Ok(HTMLEngine::Native));
+		assert!("gecko".parse::<HTMLEngine>().is_err());
+		assert!(HTMLEngine::ALL.contains(&HTMLEngine::default()));
+	}
 }
@@ -0,0 +1,129 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Liste de sélecteurs CSS analysée, ex: `ul > li:first-child, a[href^=http]`.
+///
+/// Une fois analysée, la liste peut être réutilisée pour plusieurs
+/// recherches, voir
+/// [HTMLTree::query_selector](crate::HTMLTree::query_selector).
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct HTMLSelector
+{
+	pub(crate) selectors: Vec<HTMLComplexSelector>,
+}
+
+/// Sélecteurs composés séparés par des combinateurs, ex: `ul > li a`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub(crate) struct HTMLComplexSelector
+{
+	/// Sélecteur composé le plus à droite: celui de l'élément sélectionné.
+	pub(crate) subject: HTMLCompoundSelector,
+	/// Sélecteurs composés précédents, de droite à gauche, chacun associé au
+	/// combinateur qui le suit.
+	pub(crate) ancestors: Vec<(HTMLCombinator, HTMLCompoundSelector)>,
+}
+
+/// Sélecteurs simples qui s'appliquent au même élément, ex: `a.lien[href]`.
+/// Un sélecteur composé vide correspond au sélecteur universel `*`.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub(crate) struct HTMLCompoundSelector
+{
+	pub(crate) simple_selectors: Vec<HTMLSimpleSelector>,
+}
+
+/// Condition sur la valeur d'un attribut, ex: `^="https:" i`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub(crate) struct HTMLAttributeMatcher
+{
+	pub(crate) operator: HTMLAttributeOperator,
+	pub(crate) value: String,
+	/// Comparaison insensible (drapeau `i`) ou sensible (drapeau `s`) à la
+	/// casse ASCII. Sans drapeau, la casse dépend de l'attribut.
+	pub(crate) case_insensitive: Option<bool>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub(crate) enum HTMLCombinator
+{
+	/// `A B`
+	Descendant,
+	/// `A > B`
+	Child,
+	/// `A + B`
+	NextSibling,
+	/// `A ~ B`
+	SubsequentSibling,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub(crate) enum HTMLSimpleSelector
+{
+	/// `div`
+	Type(String),
+	/// `#id`
+	Id(String),
+	/// `.classe`
+	Class(String),
+	/// `[nom]`, `[nom=valeur]`, ...
+	Attribute
+	{
+		name: String,
+		matcher: Option<HTMLAttributeMatcher>,
+	},
+	/// `:not(liste)`
+	Not(HTMLSelector),
+	/// `:nth-child(An+B)`, `:first-child` étant `:nth-child(1)`.
+	NthChild
+	{
+		a: i32, b: i32
+	},
+	/// `:empty`
+	Empty,
+}
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub(crate) enum HTMLAttributeOperator
+{
+	/// `[nom=valeur]`
+	Equals,
+	/// `[nom~=valeur]`: l'un des mots de la valeur.
+	Includes,
+	/// `[nom|=valeur]`: la valeur, ou la valeur suivie de `-`.
+	DashMatch,
+	/// `[nom^=valeur]`
+	Prefix,
+	/// `[nom$=valeur]`
+	Suffix,
+	/// `[nom*=valeur]`
+	Substring,
+}
@@ -0,0 +1,39 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Erreur d'analyse d'un sélecteur CSS.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+#[derive(thiserror::Error)]
+pub enum HTMLSelectorError
+{
+	#[error("Sélecteur invalide, fin inattendue")]
+	UnexpectedEnd,
+
+	#[error(
+		"Sélecteur invalide, caractère inattendu « {found} » à la position \
+		 {position}"
+	)]
+	UnexpectedCharacter
+	{
+		found: char, position: usize
+	},
+
+	#[error("Pseudo-classe non prise en charge: « {0} »")]
+	UnsupportedPseudoClass(String),
+
+	#[error("Argument de :nth-child invalide: « {0} »")]
+	InvalidNthChild(String),
+}
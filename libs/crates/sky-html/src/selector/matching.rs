@@ -0,0 +1,490 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::{
+	HTMLAttributeMatcher,
+	HTMLAttributeOperator,
+	HTMLCombinator,
+	HTMLComplexSelector,
+	HTMLCompoundSelector,
+	HTMLSelector,
+	HTMLSimpleSelector,
+};
+use crate::{
+	HTMLNamespace,
+	HTMLNodeData,
+	HTMLNodeId,
+	HTMLQuirksMode,
+	HTMLTree,
+};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Attributs des éléments HTML dont la valeur est comparée sans tenir compte
+/// de la casse ASCII, en l'absence de drapeau `i` ou `s` (voir « Case-
+/// sensitivity of selectors » de la spécification HTML).
+#[rustfmt::skip]
+const CASE_INSENSITIVE_ATTRIBUTES: [&str; 46] = [
+	"accept", "accept-charset", "align", "alink", "axis", "bgcolor", "charset",
+	"checked", "clear", "codetype", "color", "compact", "declare", "defer",
+	"dir", "direction", "disabled", "enctype", "face", "frame", "hreflang",
+	"http-equiv", "lang", "language", "link", "media", "method", "multiple",
+	"nohref", "noresize", "noshade", "nowrap", "readonly", "rel", "rev",
+	"rules", "scope", "scrolling", "selected", "shape", "target", "text",
+	"type", "valign", "valuetype", "vlink",
+];
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl HTMLSelector
+{
+	/// Vérifie que l'élément correspond à l'un des sélecteurs de la liste.
+	/// Un nœud qui n'est pas un élément ne correspond jamais.
+	pub fn matches(&self, tree: &HTMLTree, id: HTMLNodeId) -> bool
+	{
+		tree[id].is_element()
+			&& self
+				.selectors
+				.iter()
+				.any(|selector| selector.matches(tree, id))
+	}
+}
+
+impl HTMLComplexSelector
+{
+	fn matches(&self, tree: &HTMLTree, id: HTMLNodeId) -> bool
+	{
+		self.subject.matches(tree, id)
+			&& Self::matches_ancestors(&self.ancestors, tree, id)
+	}
+
+	/// Les sélecteurs composés sont comparés de droite à gauche; les
+	/// combinateurs descendant et frère suivant reviennent en arrière
+	/// lorsqu'un candidat ne permet pas d'aller au bout de la chaîne.
+	fn matches_ancestors(
+		ancestors: &[(HTMLCombinator, HTMLCompoundSelector)],
+		tree: &HTMLTree,
+		id: HTMLNodeId,
+	) -> bool
+	{
+		let Some(((combinator, compound), rest)) = ancestors.split_first()
+		else {
+			return true;
+		};
+
+		let candidate_matches = |candidate: HTMLNodeId| {
+			compound.matches(tree, candidate)
+				&& Self::matches_ancestors(rest, tree, candidate)
+		};
+
+		match combinator {
+			| HTMLCombinator::Child => {
+				parent_element(tree, id).is_some_and(candidate_matches)
+			}
+			| HTMLCombinator::Descendant => {
+				let mut ancestors =
+					std::iter::successors(parent_element(tree, id), |node| {
+						parent_element(tree, *node)
+					});
+				ancestors.any(candidate_matches)
+			}
+			| HTMLCombinator::NextSibling => {
+				previous_element_sibling(tree, id)
+					.is_some_and(candidate_matches)
+			}
+			| HTMLCombinator::SubsequentSibling => {
+				let mut siblings = std::iter::successors(
+					previous_element_sibling(tree, id),
+					|node| previous_element_sibling(tree, *node),
+				);
+				siblings.any(candidate_matches)
+			}
+		}
+	}
+}
+
+impl HTMLCompoundSelector
+{
+	fn matches(&self, tree: &HTMLTree, id: HTMLNodeId) -> bool
+	{
+		self.simple_selectors
+			.iter()
+			.all(|selector| selector.matches(tree, id))
+	}
+}
+
+impl HTMLSimpleSelector
+{
+	fn matches(&self, tree: &HTMLTree, id: HTMLNodeId) -> bool
+	{
+		let HTMLNodeData::Element {
+			namespace,
+			name: element_name,
+			..
+		} = tree[id].data()
+		else {
+			return false;
+		};
+		let is_html = *namespace == HTMLNamespace::Html;
+		// NOTE: en mode quirks, les identifiants et les classes sont
+		// comparés sans tenir compte de la casse ASCII.
+		let quirks = tree.quirks_mode() == HTMLQuirksMode::Quirks;
+
+		match self {
+			| Self::Type(name) => {
+				if is_html {
+					element_name.eq_ignore_ascii_case(name)
+				} else {
+					element_name == name
+				}
+			}
+			| Self::Id(id_name) => {
+				tree[id]
+					.attribute("id")
+					.is_some_and(|value| eq(value, id_name, quirks))
+			}
+			| Self::Class(class_name) => {
+				tree[id].attribute("class").is_some_and(|value| {
+					value
+						.split_ascii_whitespace()
+						.any(|class| eq(class, class_name, quirks))
+				})
+			}
+			| Self::Attribute { name, matcher } => {
+				tree[id]
+					.attributes()
+					.iter()
+					.filter(|attribute| attribute.namespace.is_none())
+					.filter(|attribute| {
+						if is_html {
+							attribute.name.eq_ignore_ascii_case(name)
+						} else {
+							attribute.name == *name
+						}
+					})
+					.any(|attribute| {
+						matcher.as_ref().is_none_or(|matcher| {
+							let case_insensitive = is_html
+								&& CASE_INSENSITIVE_ATTRIBUTES
+									.contains(&attribute.name.as_str());
+							matcher.matches(&attribute.value, case_insensitive)
+						})
+					})
+			}
+			| Self::Not(selector) => !selector.matches(tree, id),
+			| Self::NthChild { a, b } => {
+				let index = std::iter::successors(Some(id), |node| {
+					previous_element_sibling(tree, *node)
+				})
+				.count() as i64;
+				nth(*a, *b, index)
+			}
+			| Self::Empty => {
+				tree.children(id).all(|child| {
+					!tree[child].is_element()
+						&& !matches!(tree[child].data(), HTMLNodeData::Text(_))
+				})
+			}
+		}
+	}
+}
+
+impl HTMLAttributeMatcher
+{
+	/// Vérifie que la valeur de l'attribut correspond. Un drapeau `i` ou `s`
+	/// l'emporte sur la casse par défaut de l'attribut.
+	fn matches(&self, value: &str, case_insensitive: bool) -> bool
+	{
+		let case_insensitive =
+			self.case_insensitive.unwrap_or(case_insensitive);
+		let expected = self.value.as_str();
+		let (value, expected) = if case_insensitive {
+			(value.to_ascii_lowercase(), expected.to_ascii_lowercase())
+		} else {
+			(value.to_owned(), expected.to_owned())
+		};
+
+		match self.operator {
+			| HTMLAttributeOperator::Equals => value == expected,
+			| HTMLAttributeOperator::Includes => {
+				value.split_ascii_whitespace().any(|word| word == expected)
+			}
+			| HTMLAttributeOperator::DashMatch => {
+				value == expected
+					|| value
+						.strip_prefix(&expected)
+						.is_some_and(|rest| rest.starts_with('-'))
+			}
+			// NOTE: une valeur vide ne correspond jamais pour ces opérateurs.
+			| HTMLAttributeOperator::Prefix => {
+				!expected.is_empty() && value.starts_with(&expected)
+			}
+			| HTMLAttributeOperator::Suffix => {
+				!expected.is_empty() && value.ends_with(&expected)
+			}
+			| HTMLAttributeOperator::Substring => {
+				!expected.is_empty() && value.contains(&expected)
+			}
+		}
+	}
+}
+
+/// Recherche par sélecteurs.
+impl HTMLTree
+{
+	/// Vérifie que l'élément correspond au sélecteur.
+	pub fn matches(&self, id: HTMLNodeId, selector: &HTMLSelector) -> bool
+	{
+		selector.matches(self, id)
+	}
+
+	/// Premier élément descendant du nœud, dans l'ordre du document, qui
+	/// correspond au sélecteur.
+	pub fn query_selector(
+		&self,
+		scope: HTMLNodeId,
+		selector: &HTMLSelector,
+	) -> Option<HTMLNodeId>
+	{
+		self.descendants(scope)
+			.find(|descendant| selector.matches(self, *descendant))
+	}
+
+	/// Éléments descendants du nœud, dans l'ordre du document, qui
+	/// correspondent au sélecteur.
+	pub fn query_selector_all(
+		&self,
+		scope: HTMLNodeId,
+		selector: &HTMLSelector,
+	) -> Vec<HTMLNodeId>
+	{
+		self.descendants(scope)
+			.filter(|descendant| selector.matches(self, *descendant))
+			.collect()
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+fn eq(left: &str, right: &str, case_insensitive: bool) -> bool
+{
+	if case_insensitive {
+		left.eq_ignore_ascii_case(right)
+	} else {
+		left == right
+	}
+}
+
+/// Vérifie qu'il existe un entier `n >= 0` tel que `a*n + b = index`.
+///
+/// NOTE: le calcul est fait sur 64 bits: `a` et `b` peuvent valoir les bornes
+/// de `i32` sans provoquer de dépassement.
+fn nth(a: i32, b: i32, index: i64) -> bool
+{
+	let (a, b) = (i64::from(a), i64::from(b));
+	if a == 0 {
+		return index == b;
+	}
+	let difference = index - b;
+	difference % a == 0 && difference / a >= 0
+}
+
+fn parent_element(tree: &HTMLTree, id: HTMLNodeId) -> Option<HTMLNodeId>
+{
+	tree.parent(id).filter(|parent| tree[*parent].is_element())
+}
+
+fn previous_element_sibling(
+	tree: &HTMLTree,
+	id: HTMLNodeId,
+) -> Option<HTMLNodeId>
+{
+	std::iter::successors(tree.previous_sibling(id), |node| {
+		tree.previous_sibling(*node)
+	})
+	.find(|node| tree[*node].is_element())
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::HTMLAttribute;
+
+	/// ```html
+	/// <ul id=liste class="a b">
+	///   <li lang=fr-CA>un</li>
+	///   <li></li>
+	///   <li><a href="https://example.org/doc.PDF">trois</a></li>
+	/// </ul>
+	/// <svg viewBox="0 0 1 1"></svg>
+	/// ```
+	fn tree() -> (HTMLTree, Vec<HTMLNodeId>)
+	{
+		let mut tree = HTMLTree::new();
+		let ul = tree.create_element(
+			HTMLNamespace::Html,
+			"ul",
+			vec![
+				HTMLAttribute::new("id", "liste"),
+				HTMLAttribute::new("class", "a b"),
+			],
+		);
+		let li1 = tree.create_element(
+			HTMLNamespace::Html,
+			"li",
+			vec![HTMLAttribute::new("lang", "fr-CA")],
+		);
+		let li2 = tree.create_element(HTMLNamespace::Html, "li", Vec::new());
+		let li3 = tree.create_element(HTMLNamespace::Html, "li", Vec::new());
+		let a = tree.create_element(
+			HTMLNamespace::Html,
+			"a",
+			vec![HTMLAttribute::new("href", "https://example.org/doc.PDF")],
+		);
+		let svg = tree.create_element(
+			HTMLNamespace::Svg,
+			"svg",
+			vec![HTMLAttribute::new("viewBox", "0 0 1 1")],
+		);
+
+		tree.append(tree.document(), ul);
+		for li in [li1, li2, li3] {
+			tree.append_text(ul, "\n");
+			tree.append(ul, li);
+		}
+		tree.append_text(li1, "un");
+		tree.append(li3, a);
+		tree.append_text(a, "trois");
+		tree.append(tree.document(), svg);
+
+		(tree, vec![ul, li1, li2, li3, a, svg])
+	}
+
+	fn query(tree: &HTMLTree, selector: &str) -> Vec<HTMLNodeId>
+	{
+		let selector = HTMLSelector::parse(selector).unwrap();
+		tree.query_selector_all(tree.document(), &selector)
+	}
+
+	#[test]
+	fn test_simple_selectors()
+	{
+		let (tree, nodes) = tree();
+		let [ul, li1, li2, li3, a, svg] = nodes[..] else {
+			unreachable!()
+		};
+
+		assert_eq!(query(&tree, "LI"), [li1, li2, li3]);
+		assert_eq!(query(&tree, "#liste.b"), [ul]);
+		assert_eq!(query(&tree, ".c"), []);
+		assert_eq!(query(&tree, "[lang|=fr]"), [li1]);
+		assert_eq!(query(&tree, "[href$='.pdf']"), []);
+		assert_eq!(query(&tree, "[href$='.pdf' i]"), [a]);
+		assert_eq!(query(&tree, "[href^=''], [class~=b]"), [ul]);
+		assert_eq!(query(&tree, "[viewBox], [viewbox]"), [svg]);
+		assert_eq!(query(&tree, "SVG"), []);
+		assert_eq!(query(&tree, ":empty"), [li2, svg]);
+	}
+
+	#[test]
+	fn test_combinators()
+	{
+		let (tree, nodes) = tree();
+		let [ul, li1, li2, li3, a, _] = nodes[..] else {
+			unreachable!()
+		};
+
+		assert_eq!(query(&tree, "ul a"), [a]);
+		assert_eq!(query(&tree, "ul > a"), []);
+		assert_eq!(query(&tree, "li + li"), [li2, li3]);
+		assert_eq!(query(&tree, "[lang] ~ *"), [li2, li3]);
+		assert_eq!(query(&tree, "ul li ~ li > a"), [a]);
+		assert_eq!(query(&tree, "li:not(:first-child, :empty)"), [li3]);
+		assert_eq!(query(&tree, ":not(li) > li:first-child"), [li1]);
+
+		let selector = HTMLSelector::parse("a").unwrap();
+		assert_eq!(tree.query_selector(li3, &selector), Some(a));
+		assert_eq!(tree.query_selector(li1, &selector), None);
+		assert!(tree.matches(a, &HTMLSelector::parse("ul a").unwrap()));
+		assert!(!tree.matches(ul, &HTMLSelector::parse("* ul").unwrap()));
+	}
+
+	#[test]
+	fn test_nth_child()
+	{
+		let (tree, nodes) = tree();
+		let [_, li1, li2, li3, ..] = nodes[..] else {
+			unreachable!()
+		};
+
+		assert_eq!(query(&tree, "li:nth-child(odd)"), [li1, li3]);
+		assert_eq!(query(&tree, "li:nth-child(2n)"), [li2]);
+		assert_eq!(query(&tree, "li:nth-child(-n+2)"), [li1, li2]);
+		assert_eq!(query(&tree, "li:nth-child(3)"), [li3]);
+		assert_eq!(query(&tree, "li:nth-child(n+4)"), []);
+		assert_eq!(query(&tree, "li:nth-child(-1n-2147483648)"), []);
+		assert_eq!(query(&tree, "li:nth-child(-2147483648n+3)"), [li3]);
+		assert_eq!(query(&tree, "li:nth-child(2147483647n+1)"), [li1]);
+		assert_eq!(
+			query(&tree, "li:nth-child(-n+2147483647)"),
+			[li1, li2, li3]
+		);
+	}
+
+	#[test]
+	fn test_case_insensitive_attribute_values()
+	{
+		let mut tree = HTMLTree::new();
+		let input = tree.create_element(
+			HTMLNamespace::Html,
+			"input",
+			vec![
+				HTMLAttribute::new("type", "TEXT"),
+				HTMLAttribute::new("name", "Nom"),
+			],
+		);
+		let rect = tree.create_element(
+			HTMLNamespace::Svg,
+			"rect",
+			vec![HTMLAttribute::new("type", "TEXT")],
+		);
+		tree.append(tree.document(), input);
+		tree.append(tree.document(), rect);
+
+		assert_eq!(query(&tree, "input[type=text]"), [input]);
+		assert_eq!(query(&tree, "[type^=te]"), [input]);
+		assert_eq!(query(&tree, "[type=text s]"), []);
+		assert_eq!(query(&tree, "[type=TEXT s]"), [input, rect]);
+		assert_eq!(query(&tree, "[name=nom]"), []);
+		assert_eq!(query(&tree, "[name=nom i]"), [input]);
+		assert_eq!(query(&tree, "[type=text i]"), [input, rect]);
+	}
+
+	#[test]
+	fn test_quirks_mode()
+	{
+		let (mut tree, nodes) = tree();
+		assert_eq!(query(&tree, "#LISTE"), []);
+
+		tree.set_quirks_mode(HTMLQuirksMode::Quirks);
+		assert_eq!(query(&tree, "#LISTE.B"), [nodes[0]]);
+	}
+}
@@ -0,0 +1,593 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::str;
+
+use super::{
+	HTMLAttributeMatcher,
+	HTMLAttributeOperator,
+	HTMLCombinator,
+	HTMLComplexSelector,
+	HTMLCompoundSelector,
+	HTMLSelector,
+	HTMLSelectorError,
+	HTMLSimpleSelector,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Analyseur d'une liste de sélecteurs CSS.
+struct HTMLSelectorParser<'a>
+{
+	input: &'a str,
+	/// Position, en octets, du prochain caractère à consommer.
+	position: usize,
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+fn is_name_start(ch: char) -> bool
+{
+	ch.is_ascii_alphabetic() || ch == '_' || !ch.is_ascii()
+}
+
+fn is_name(ch: char) -> bool
+{
+	is_name_start(ch) || ch.is_ascii_digit() || ch == '-'
+}
+
+/// Analyse l'argument `An+B` de `:nth-child()`, ex: `odd`, `2n+1`, `-n+3`.
+fn parse_nth(argument: &str) -> Option<(i32, i32)>
+{
+	let argument = argument
+		.chars()
+		.filter(|ch| !ch.is_ascii_whitespace())
+		.collect::<String>()
+		.to_ascii_lowercase();
+
+	match argument.as_str() {
+		| "odd" => return Some((2, 1)),
+		| "even" => return Some((2, 0)),
+		| _ => {}
+	}
+
+	let Some((a, b)) = argument.split_once('n') else {
+		return Some((0, argument.parse().ok()?));
+	};
+
+	let a = match a {
+		| "" | "+" => 1,
+		| "-" => -1,
+		| a => a.parse().ok()?,
+	};
+	let b = match b {
+		| "" => 0,
+		| b if b.starts_with(['+', '-']) => b.parse().ok()?,
+		| _ => return None,
+	};
+
+	Some((a, b))
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl HTMLSelector
+{
+	/// Analyse une liste de sélecteurs, ex: `h1, h2`, `a[href$=".pdf" i]`.
+	pub fn parse(input: &str) -> Result<Self, HTMLSelectorError>
+	{
+		let mut parser = HTMLSelectorParser::new(input);
+		let selector = parser.parse_selector_list()?;
+		parser.skip_whitespace();
+		if parser.peek().is_some() {
+			return Err(parser.unexpected());
+		}
+		Ok(selector)
+	}
+}
+
+impl<'a> HTMLSelectorParser<'a>
+{
+	fn new(input: &'a str) -> Self
+	{
+		Self { input, position: 0 }
+	}
+}
+
+impl HTMLSelectorParser<'_>
+{
+	fn peek(&self) -> Option<char>
+	{
+		self.input[self.position..].chars().next()
+	}
+
+	fn consume(&mut self) -> Option<char>
+	{
+		let ch = self.peek()?;
+		self.position += ch.len_utf8();
+		Some(ch)
+	}
+
+	fn consume_if(&mut self, expected: char) -> bool
+	{
+		let found = self.peek() == Some(expected);
+		if found {
+			self.position += expected.len_utf8();
+		}
+		found
+	}
+
+	fn expect(&mut self, expected: char) -> Result<(), HTMLSelectorError>
+	{
+		if self.consume_if(expected) {
+			Ok(())
+		} else {
+			Err(self.unexpected())
+		}
+	}
+
+	/// Ignore les espaces blancs, et indique s'il y en avait.
+	fn skip_whitespace(&mut self) -> bool
+	{
+		let start = self.position;
+		while self.peek().is_some_and(|ch| ch.is_ascii_whitespace()) {
+			self.consume();
+		}
+		self.position != start
+	}
+
+	fn unexpected(&self) -> HTMLSelectorError
+	{
+		match self.peek() {
+			| Some(found) => {
+				HTMLSelectorError::UnexpectedCharacter {
+					found,
+					position: self.position,
+				}
+			}
+			| None => HTMLSelectorError::UnexpectedEnd,
+		}
+	}
+}
+
+impl HTMLSelectorParser<'_>
+{
+	fn parse_selector_list(&mut self)
+		-> Result<HTMLSelector, HTMLSelectorError>
+	{
+		let mut selectors = Vec::new();
+		loop {
+			self.skip_whitespace();
+			selectors.push(self.parse_complex_selector()?);
+			self.skip_whitespace();
+			if !self.consume_if(',') {
+				break;
+			}
+		}
+		Ok(HTMLSelector { selectors })
+	}
+
+	fn parse_complex_selector(
+		&mut self,
+	) -> Result<HTMLComplexSelector, HTMLSelectorError>
+	{
+		let mut compounds = vec![self.parse_compound_selector()?];
+		let mut combinators = Vec::new();
+
+		loop {
+			let whitespace = self.skip_whitespace();
+			let combinator = match self.peek() {
+				| Some('>') => HTMLCombinator::Child,
+				| Some('+') => HTMLCombinator::NextSibling,
+				| Some('~') => HTMLCombinator::SubsequentSibling,
+				| Some(',' | ')') | None => break,
+				| Some(_) if whitespace => HTMLCombinator::Descendant,
+				| Some(_) => return Err(self.unexpected()),
+			};
+			if combinator != HTMLCombinator::Descendant {
+				self.consume();
+				self.skip_whitespace();
+			}
+
+			combinators.push(combinator);
+			compounds.push(self.parse_compound_selector()?);
+		}
+
+		let subject = compounds.pop().expect("au moins un sélecteur composé");
+		let ancestors = combinators
+			.into_iter()
+			.rev()
+			.zip(compounds.into_iter().rev())
+			.collect();
+
+		Ok(HTMLComplexSelector { subject, ancestors })
+	}
+
+	fn parse_compound_selector(
+		&mut self,
+	) -> Result<HTMLCompoundSelector, HTMLSelectorError>
+	{
+		let start = self.position;
+		let mut simple_selectors = Vec::new();
+
+		if !self.consume_if('*') && self.is_identifier_start() {
+			simple_selectors
+				.push(HTMLSimpleSelector::Type(self.parse_identifier()?));
+		}
+
+		loop {
+			let selector = match self.peek() {
+				| Some('#') => {
+					self.consume();
+					HTMLSimpleSelector::Id(self.parse_identifier()?)
+				}
+				| Some('.') => {
+					self.consume();
+					HTMLSimpleSelector::Class(self.parse_identifier()?)
+				}
+				| Some('[') => self.parse_attribute_selector()?,
+				| Some(':') => self.parse_pseudo_class()?,
+				| _ => break,
+			};
+			simple_selectors.push(selector);
+		}
+
+		if self.position == start {
+			return Err(self.unexpected());
+		}
+
+		Ok(HTMLCompoundSelector { simple_selectors })
+	}
+
+	fn parse_attribute_selector(
+		&mut self,
+	) -> Result<HTMLSimpleSelector, HTMLSelectorError>
+	{
+		self.expect('[')?;
+		self.skip_whitespace();
+		let name = self.parse_identifier()?;
+		self.skip_whitespace();
+
+		if self.consume_if(']') {
+			return Ok(HTMLSimpleSelector::Attribute {
+				name,
+				matcher: None,
+			});
+		}
+
+		let operator = match self.peek() {
+			| Some('=') => HTMLAttributeOperator::Equals,
+			| Some('~') => HTMLAttributeOperator::Includes,
+			| Some('|') => HTMLAttributeOperator::DashMatch,
+			| Some('^') => HTMLAttributeOperator::Prefix,
+			| Some('$') => HTMLAttributeOperator::Suffix,
+			| Some('*') => HTMLAttributeOperator::Substring,
+			| _ => return Err(self.unexpected()),
+		};
+		self.consume();
+		if operator != HTMLAttributeOperator::Equals {
+			self.expect('=')?;
+		}
+		self.skip_whitespace();
+
+		let value = match self.peek() {
+			| Some('"' | '\'') => self.parse_string()?,
+			| _ => self.parse_identifier()?,
+		};
+		self.skip_whitespace();
+
+		let case_insensitive = match self.peek() {
+			| Some('i' | 'I') => Some(true),
+			| Some('s' | 'S') => Some(false),
+			| _ => {
+				self.expect(']')?;
+				return Ok(HTMLSimpleSelector::Attribute {
+					name,
+					matcher: Some(HTMLAttributeMatcher {
+						operator,
+						value,
+						case_insensitive: None,
+					}),
+				});
+			}
+		};
+		self.consume();
+		self.skip_whitespace();
+		self.expect(']')?;
+
+		Ok(HTMLSimpleSelector::Attribute {
+			name,
+			matcher: Some(HTMLAttributeMatcher {
+				operator,
+				value,
+				case_insensitive,
+			}),
+		})
+	}
+
+	fn parse_pseudo_class(
+		&mut self,
+	) -> Result<HTMLSimpleSelector, HTMLSelectorError>
+	{
+		self.expect(':')?;
+		if self.peek() == Some(':') {
+			self.consume();
+			let name = self.parse_identifier().unwrap_or_default();
+			return Err(HTMLSelectorError::UnsupportedPseudoClass(format!(
+				":{name}"
+			)));
+		}
+
+		let name = self.parse_identifier()?.to_ascii_lowercase();
+
+		if !self.consume_if('(') {
+			return match name.as_str() {
+				| "first-child" => {
+					Ok(HTMLSimpleSelector::NthChild { a: 0, b: 1 })
+				}
+				| "empty" => Ok(HTMLSimpleSelector::Empty),
+				| _ => Err(HTMLSelectorError::UnsupportedPseudoClass(name)),
+			};
+		}
+
+		let selector = match name.as_str() {
+			| "not" => HTMLSimpleSelector::Not(self.parse_selector_list()?),
+			| "nth-child" => {
+				let start = self.position;
+				while self.peek().is_some_and(|ch| ch != ')') {
+					self.consume();
+				}
+				let argument = &self.input[start..self.position];
+				let (a, b) = parse_nth(argument).ok_or_else(|| {
+					HTMLSelectorError::InvalidNthChild(
+						argument.trim().to_owned(),
+					)
+				})?;
+				HTMLSimpleSelector::NthChild { a, b }
+			}
+			| _ => {
+				return Err(HTMLSelectorError::UnsupportedPseudoClass(
+					format!("{name}()"),
+				));
+			}
+		};
+		self.skip_whitespace();
+		self.expect(')')?;
+
+		Ok(selector)
+	}
+
+	fn is_identifier_start(&self) -> bool
+	{
+		let mut chars = self.input[self.position..].chars();
+		match chars.next() {
+			| Some('-') => {
+				chars.next().is_some_and(|ch| {
+					is_name_start(ch) || ch == '-' || ch == '\\'
+				})
+			}
+			| Some(ch) => is_name_start(ch) || ch == '\\',
+			| None => false,
+		}
+	}
+
+	fn parse_identifier(&mut self) -> Result<String, HTMLSelectorError>
+	{
+		if !self.is_identifier_start() {
+			return Err(self.unexpected());
+		}
+
+		let mut identifier = String::new();
+		while let Some(ch) = self.peek() {
+			if ch == '\\' {
+				self.consume();
+				identifier.push(self.parse_escape()?);
+			} else if is_name(ch) {
+				self.consume();
+				identifier.push(ch);
+			} else {
+				break;
+			}
+		}
+		Ok(identifier)
+	}
+
+	fn parse_string(&mut self) -> Result<String, HTMLSelectorError>
+	{
+		let quote = self.consume().ok_or(HTMLSelectorError::UnexpectedEnd)?;
+
+		let mut string = String::new();
+		loop {
+			match self.peek() {
+				| None => return Err(HTMLSelectorError::UnexpectedEnd),
+				| Some('\n') => return Err(self.unexpected()),
+				| Some(ch) if ch == quote => {
+					self.consume();
+					break;
+				}
+				| Some('\\') => {
+					self.consume();
+					// NOTE: un saut de ligne échappé continue la chaîne.
+					if !self.consume_if('\n') {
+						string.push(self.parse_escape()?);
+					}
+				}
+				| Some(ch) => {
+					self.consume();
+					string.push(ch);
+				}
+			}
+		}
+		Ok(string)
+	}
+
+	/// Caractère échappé, après `\`: un caractère quelconque, ou son point
+	/// de code en hexadécimal (6 chiffres au plus) suivi d'un espace
+	/// optionnel.
+	fn parse_escape(&mut self) -> Result<char, HTMLSelectorError>
+	{
+		let start = self.position;
+		while self.position - start < 6
+			&& self.peek().is_some_and(|ch| ch.is_ascii_hexdigit())
+		{
+			self.consume();
+		}
+
+		if self.position == start {
+			return match self.peek() {
+				| None | Some('\n') => Err(self.unexpected()),
+				| Some(_) => Ok(self.consume().unwrap_or_default()),
+			};
+		}
+
+		let code_point =
+			u32::from_str_radix(&self.input[start..self.position], 16)
+				.unwrap_or_default();
+		if self.peek().is_some_and(|ch| ch.is_ascii_whitespace()) {
+			self.consume();
+		}
+
+		Ok(char::from_u32(code_point)
+			.filter(|ch| *ch != '\0')
+			.unwrap_or(char::REPLACEMENT_CHARACTER))
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl str::FromStr for HTMLSelector
+{
+	type Err = HTMLSelectorError;
+
+	fn from_str(input: &str) -> Result<Self, Self::Err>
+	{
+		Self::parse(input)
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_parse_complex_selector()
+	{
+		let selector = HTMLSelector::parse("ul > li.a + *[x] ~ b c").unwrap();
+		let [complex] = selector.selectors.as_slice() else {
+			panic!("un seul sélecteur attendu");
+		};
+
+		assert_eq!(
+			complex.subject.simple_selectors,
+			[HTMLSimpleSelector::Type("c".to_owned())]
+		);
+		assert_eq!(
+			complex
+				.ancestors
+				.iter()
+				.map(|(combinator, _)| *combinator)
+				.collect::<Vec<_>>(),
+			[
+				HTMLCombinator::Descendant,
+				HTMLCombinator::SubsequentSibling,
+				HTMLCombinator::NextSibling,
+				HTMLCombinator::Child,
+			]
+		);
+		assert_eq!(
+			complex.ancestors[1].1.simple_selectors,
+			[HTMLSimpleSelector::Attribute {
+				name: "x".to_owned(),
+				matcher: None
+			}]
+		);
+	}
+
+	#[test]
+	fn test_parse_attribute_selector()
+	{
+		let selector = HTMLSelector::parse(r#"[ href ^= "a\"b" i ]"#).unwrap();
+		assert_eq!(
+			selector.selectors[0].subject.simple_selectors,
+			[HTMLSimpleSelector::Attribute {
+				name: "href".to_owned(),
+				matcher: Some(HTMLAttributeMatcher {
+					operator: HTMLAttributeOperator::Prefix,
+					value: "a\"b".to_owned(),
+					case_insensitive: Some(true),
+				}),
+			}]
+		);
+
+		let selector = HTMLSelector::parse(r"#\31 0\.5").unwrap();
+		assert_eq!(
+			selector.selectors[0].subject.simple_selectors,
+			[HTMLSimpleSelector::Id("10.5".to_owned())]
+		);
+	}
+
+	#[test]
+	fn test_parse_nth()
+	{
+		assert_eq!(parse_nth("odd"), Some((2, 1)));
+		assert_eq!(parse_nth(" EVEN "), Some((2, 0)));
+		assert_eq!(parse_nth("3"), Some((0, 3)));
+		assert_eq!(parse_nth("-n + 3"), Some((-1, 3)));
+		assert_eq!(parse_nth("2n-1"), Some((2, -1)));
+		assert_eq!(parse_nth("n"), Some((1, 0)));
+		assert_eq!(parse_nth("2n1"), None);
+		assert_eq!(parse_nth("x"), None);
+	}
+
+	#[test]
+	fn test_parse_errors()
+	{
+		assert_eq!(
+			HTMLSelector::parse(""),
+			Err(HTMLSelectorError::UnexpectedEnd)
+		);
+		assert_eq!(
+			HTMLSelector::parse("a,,b"),
+			Err(HTMLSelectorError::UnexpectedCharacter {
+				found: ',',
+				position: 2
+			})
+		);
+		assert_eq!(
+			HTMLSelector::parse("a >"),
+			Err(HTMLSelectorError::UnexpectedEnd)
+		);
+		assert_eq!(
+			HTMLSelector::parse("a:hover"),
+			Err(HTMLSelectorError::UnsupportedPseudoClass(
+				"hover".to_owned()
+			))
+		);
+		assert_eq!(
+			HTMLSelector::parse(":nth-child(2x)"),
+			Err(HTMLSelectorError::InvalidNthChild("2x".to_owned()))
+		);
+		assert!(HTMLSelector::parse("p::before").is_err());
+		assert!(HTMLSelector::parse(":not(a").is_err());
+	}
+}
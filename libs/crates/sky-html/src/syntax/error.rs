@@ -8,7 +8,21 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use crate::HTMLLexicalError;
+use sky_lang::Location;
+
+use crate::{HTMLLexicalError, HTMLNodeId};
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Collecteur des erreurs d'analyse récupérables de l'analyseur: erreurs du
+/// fournisseur de jeton et erreurs de construction de l'arbre.
+pub trait HTMLParserErrorSink
+{
+	/// Reçoit une erreur d'analyse.
+	fn report(&mut self, error: HTMLParserError);
+}
 
 // ----------- //
 // Énumération //
@@ -21,6 +35,44 @@ pub enum HTMLParserError
 	#[error("{0}")]
 	LexicalParser(#[from] HTMLLexicalError),
 
+	#[error(
+		"Erreur de construction de l'arbre, raison: « {reason} », à la \
+		 position {location}"
+	)]
+	TreeConstruction
+	{
+		reason: &'static str,
+		location: Location,
+	},
+
 	#[error("{0}")]
 	IO(#[from] std::io::Error),
+
+	#[error(
+		"Le nœud {0:?} n'est pas un élément, il ne peut pas servir de \
+		 contexte à un fragment"
+	)]
+	InvalidFragmentContext(HTMLNodeId),
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl HTMLParserErrorSink for Vec<HTMLParserError>
+{
+	fn report(&mut self, error: HTMLParserError)
+	{
+		self.push(error);
+	}
+}
+
+impl<F> HTMLParserErrorSink for F
+where
+	F: FnMut(HTMLParserError),
+{
+	fn report(&mut self, error: HTMLParserError)
+	{
+		self(error);
+	}
 }
@@ -0,0 +1,151 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use sky_lang::UnitCodePointExt;
+
+use super::{HTMLInsertionMode, HTMLParser, HTMLParserError};
+use crate::{
+	HTMLAttribute,
+	HTMLNamespace,
+	HTMLNodeId,
+	HTMLQuirksMode,
+	HTMLTokenizerState,
+	HTMLTree,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Élément de contexte de l'analyse d'un fragment HTML, ex: l'élément dont
+/// le contenu est remplacé.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct HTMLFragmentContext
+{
+	/// Espace de noms de l'élément.
+	pub namespace: HTMLNamespace,
+	/// Nom local de l'élément.
+	pub name: String,
+	/// Attributs de l'élément.
+	pub attributes: Vec<HTMLAttribute>,
+	/// Mode de rendu du document de l'élément.
+	pub quirks_mode: HTMLQuirksMode,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl HTMLFragmentContext
+{
+	pub fn new(namespace: HTMLNamespace, name: impl Into<String>) -> Self
+	{
+		Self {
+			namespace,
+			name: name.into(),
+			attributes: Default::default(),
+			quirks_mode: Default::default(),
+		}
+	}
+
+	/// Contexte d'un élément HTML, ex: `body`, `table`, `textarea`.
+	pub fn html(name: impl Into<String>) -> Self
+	{
+		Self::new(HTMLNamespace::Html, name)
+	}
+
+	/// Contexte d'un élément de l'arbre. L'élément garde ses attributs et le
+	/// mode de rendu de l'arbre.
+	pub fn from_element(tree: &HTMLTree, id: HTMLNodeId) -> Option<Self>
+	{
+		let element = &tree[id];
+		Some(Self {
+			namespace: element.namespace()?,
+			name: element.element_name()?.to_owned(),
+			attributes: element.attributes().to_vec(),
+			quirks_mode: tree.quirks_mode(),
+		})
+	}
+
+	pub fn with_attributes(mut self, attributes: Vec<HTMLAttribute>) -> Self
+	{
+		self.attributes = attributes;
+		self
+	}
+
+	pub fn with_quirks_mode(mut self, quirks_mode: HTMLQuirksMode) -> Self
+	{
+		self.quirks_mode = quirks_mode;
+		self
+	}
+
+	/// État initial du tokenizer, selon l'élément de contexte. Les scripts
+	/// n'étant pas exécutés, le contenu de `<noscript>` est analysé.
+	fn tokenizer_state(&self) -> HTMLTokenizerState
+	{
+		if self.namespace != HTMLNamespace::Html {
+			return HTMLTokenizerState::Data;
+		}
+
+		match self.name.as_str() {
+			| "title" | "textarea" => HTMLTokenizerState::Rcdata,
+			| "style" | "xmp" | "iframe" | "noembed" | "noframes" => {
+				HTMLTokenizerState::Rawtext
+			}
+			| "script" => HTMLTokenizerState::ScriptData,
+			| "plaintext" => HTMLTokenizerState::Plaintext,
+			| _ => HTMLTokenizerState::Data,
+		}
+	}
+}
+
+impl<Input: Iterator> HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	/// 13.4 Parsing HTML fragments: construit l'arbre d'un fragment analysé
+	/// dans le contexte de l'élément donné. Les nœuds du fragment sont les
+	/// enfants de l'élément racine `<html>` de l'arbre.
+	pub fn parse_fragment(
+		mut self,
+		context: &HTMLFragmentContext,
+	) -> Result<HTMLTree, HTMLParserError>
+	{
+		self.tree.set_quirks_mode(context.quirks_mode);
+		self.switch_tokenizer_state(context.tokenizer_state());
+
+		let root =
+			self.tree
+				.create_element(HTMLNamespace::Html, "html", Vec::new());
+		self.tree.append(self.tree.document(), root);
+		self.stack_of_open_elements.push(root);
+
+		if context.namespace == HTMLNamespace::Html
+			&& context.name == "template"
+		{
+			self.template_insertion_modes
+				.push(HTMLInsertionMode::InTemplate);
+		}
+
+		// NOTE: l'élément de contexte n'est rattaché à aucun parent, il
+		// n'apparaît donc pas dans l'arbre du fragment.
+		let context_element = self.tree.create_element(
+			context.namespace,
+			context.name.clone(),
+			context.attributes.clone(),
+		);
+		self.context_element.replace(context_element);
+		self.reset_insertion_mode_appropriately();
+
+		self.parse()
+	}
+}
@@ -0,0 +1,46 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Mode d'insertion de la construction de l'arbre.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum HTMLInsertionMode
+{
+	#[default]
+	Initial,
+	BeforeHtml,
+	BeforeHead,
+	InHead,
+	InHeadNoscript,
+	AfterHead,
+	InBody,
+	Text,
+	InTable,
+	InTableText,
+	InCaption,
+	InColumnGroup,
+	InTableBody,
+	InRow,
+	InCell,
+	InSelect,
+	InSelectInTable,
+	InTemplate,
+	AfterBody,
+	InFrameset,
+	AfterFrameset,
+	AfterAfterBody,
+	AfterAfterFrameset,
+}
@@ -9,7 +9,33 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 mod error;
+mod fragment;
+mod insertion_mode;
 mod parser;
+mod rules
+{
+	mod body;
+	mod document;
+	mod foreign;
+	mod frameset;
+	mod head;
+	mod select;
+	mod table;
+	mod template;
 
-pub use self::error::HTMLParserError;
+	pub use self::body::*;
+	pub use self::document::*;
+	pub use self::foreign::*;
+	pub use self::frameset::*;
+	pub use self::head::*;
+	pub use self::select::*;
+	pub use self::table::*;
+	pub use self::template::*;
+}
+mod token;
+
+pub use self::error::{HTMLParserError, HTMLParserErrorSink};
+pub use self::fragment::HTMLFragmentContext;
+pub use self::insertion_mode::HTMLInsertionMode;
 pub use self::parser::HTMLParser;
+pub(crate) use self::token::HTMLTreeToken;
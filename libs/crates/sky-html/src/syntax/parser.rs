@@ -8,21 +8,201 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use sky_lang::UnitCodePointExt;
+use sky_lang::{Location, UnitCodePointExt};
 
-use super::HTMLParserError;
-use crate::lexical::HTMLTokenizer;
-use crate::HTMLToken;
+use super::rules::*;
+use super::{
+	HTMLInsertionMode,
+	HTMLParserError,
+	HTMLParserErrorSink,
+	HTMLTreeToken,
+};
+use crate::dom::{
+	HTMLAttribute,
+	HTMLNamespace,
+	HTMLNode,
+	HTMLNodeData,
+	HTMLNodeId,
+	HTMLTree,
+};
+use crate::lexical::{HTMLTokenizer, HTMLTokenizerState};
 
 // --------- //
 // Structure //
 // --------- //
 
+/// Analyseur HTML: construit l'arbre du document à partir des jetons émis
+/// par le [HTMLTokenizer].
 pub struct HTMLParser<Input: Iterator>
 {
 	tokenizer: HTMLTokenizer<Input>,
+	pub(crate) tree: HTMLTree,
+	pub(crate) insertion_mode: HTMLInsertionMode,
+	pub(crate) original_insertion_mode: HTMLInsertionMode,
+	pub(crate) template_insertion_modes: Vec<HTMLInsertionMode>,
+	pub(crate) stack_of_open_elements: Vec<HTMLNodeId>,
+	pub(crate) list_of_active_formatting_elements:
+		Vec<HTMLActiveFormattingElement>,
+	pub(crate) head_element: Option<HTMLNodeId>,
+	pub(crate) form_element: Option<HTMLNodeId>,
+	/// Élément de contexte, lors de l'analyse d'un fragment. Il ne fait pas
+	/// partie de l'arbre construit.
+	pub(crate) context_element: Option<HTMLNodeId>,
+	pub(crate) frameset_ok: bool,
+	pub(crate) foster_parenting: bool,
+	pub(crate) pending_table_character_tokens: String,
+	/// Le prochain jeton est ignoré s'il s'agit d'un saut de ligne, ex:
+	/// après `<pre>`.
+	pub(crate) ignore_line_feed: bool,
+	stopped: bool,
+	token_location: Location,
+	error_sink: Option<Box<dyn HTMLParserErrorSink>>,
 }
 
+/// Emplacement d'insertion d'un nœud.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+pub(crate) struct HTMLInsertionPlace
+{
+	pub(crate) parent: HTMLNodeId,
+	/// Le nœud est inséré avant ce nœud, sinon à la fin des enfants du
+	/// parent.
+	pub(crate) before: Option<HTMLNodeId>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub(crate) enum HTMLActiveFormattingElement
+{
+	Marker,
+	Element(HTMLNodeId),
+}
+
+/// Portées particulières de la pile des éléments ouverts.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub(crate) enum HTMLScope
+{
+	Default,
+	ListItem,
+	Button,
+	Table,
+	Select,
+}
+
+// -------- //
+// Constant //
+// -------- //
+
+const IMPLIED_END_TAGS: [&str; 10] = [
+	"dd", "dt", "li", "optgroup", "option", "p", "rb", "rp", "rt", "rtc",
+];
+
+const IMPLIED_END_TAGS_THOROUGHLY: [&str; 18] = [
+	"caption", "colgroup", "dd", "dt", "li", "optgroup", "option", "p", "rb",
+	"rp", "rt", "rtc", "tbody", "td", "tfoot", "th", "thead", "tr",
+];
+
+#[rustfmt::skip]
+/// Éléments associables à un formulaire. Tous, sauf `<img>`, sont des
+/// éléments listés: l'attribut `form` les associe explicitement.
+const FORM_ASSOCIATED_ELEMENTS: [&str; 8] = [
+	"button", "fieldset", "img", "input", "object", "output", "select",
+	"textarea",
+];
+
+const SPECIAL_HTML_ELEMENTS: [&str; 83] = [
+	"address",
+	"applet",
+	"area",
+	"article",
+	"aside",
+	"base",
+	"basefont",
+	"bgsound",
+	"blockquote",
+	"body",
+	"br",
+	"button",
+	"caption",
+	"center",
+	"col",
+	"colgroup",
+	"dd",
+	"details",
+	"dir",
+	"div",
+	"dl",
+	"dt",
+	"embed",
+	"fieldset",
+	"figcaption",
+	"figure",
+	"footer",
+	"form",
+	"frame",
+	"frameset",
+	"h1",
+	"h2",
+	"h3",
+	"h4",
+	"h5",
+	"h6",
+	"head",
+	"header",
+	"hgroup",
+	"hr",
+	"html",
+	"iframe",
+	"img",
+	"input",
+	"keygen",
+	"li",
+	"link",
+	"listing",
+	"main",
+	"marquee",
+	"menu",
+	"meta",
+	"nav",
+	"noembed",
+	"noframes",
+	"noscript",
+	"object",
+	"ol",
+	"p",
+	"param",
+	"plaintext",
+	"pre",
+	"script",
+	"search",
+	"section",
+	"select",
+	"source",
+	"style",
+	"summary",
+	"table",
+	"tbody",
+	"td",
+	"template",
+	"textarea",
+	"tfoot",
+	"th",
+	"thead",
+	"title",
+	"tr",
+	"track",
+	"ul",
+	"wbr",
+	"xmp",
+];
+
 // -------------- //
 // Implémentation //
 // -------------- //
@@ -32,7 +212,36 @@ impl<Input: Iterator> HTMLParser<Input>
 	pub fn new(input: Input) -> Self
 	{
 		let tokenizer = HTMLTokenizer::new(input);
-		Self { tokenizer }
+		Self {
+			tokenizer,
+			tree: HTMLTree::new(),
+			insertion_mode: Default::default(),
+			original_insertion_mode: Default::default(),
+			template_insertion_modes: Default::default(),
+			stack_of_open_elements: Default::default(),
+			list_of_active_formatting_elements: Default::default(),
+			head_element: Default::default(),
+			form_element: Default::default(),
+			context_element: Default::default(),
+			frameset_ok: true,
+			foster_parenting: false,
+			pending_table_character_tokens: Default::default(),
+			ignore_line_feed: false,
+			stopped: false,
+			token_location: Default::default(),
+			error_sink: Default::default(),
+		}
+	}
+
+	/// Transmet les erreurs d'analyse récupérables au collecteur donné. Sans
+	/// collecteur, elles sont ignorées.
+	pub fn with_error_sink(
+		mut self,
+		sink: impl HTMLParserErrorSink + 'static,
+	) -> Self
+	{
+		self.error_sink.replace(Box::new(sink));
+		self
 	}
 }
 
@@ -40,9 +249,1120 @@ impl<Input: Iterator> HTMLParser<Input>
 where
 	Input::Item: UnitCodePointExt,
 {
-	// TODO: tâche non aboutie.
-	pub fn parse(mut self) -> Result<Vec<HTMLToken>, HTMLParserError>
+	/// Construit l'arbre du document.
+	pub fn parse(mut self) -> Result<HTMLTree, HTMLParserError>
+	{
+		while !self.stopped {
+			let Some(token) = self.tokenizer.next() else {
+				break;
+			};
+			let token = token?;
+
+			self.report_lexical_errors();
+			self.token_location = token.location();
+			let first_node = self.tree.next_node_id();
+			self.process_token(HTMLTreeToken::from(&token));
+			self.tree.locate_nodes_from(first_node, self.token_location);
+
+			let in_foreign_content = self
+				.adjusted_current_node()
+				.is_some_and(|node| !self.is_html_element(node));
+			self.tokenizer.set_in_foreign_content(in_foreign_content);
+		}
+
+		self.report_lexical_errors();
+
+		Ok(self.tree)
+	}
+
+	fn report_lexical_errors(&mut self)
+	{
+		for error in self.tokenizer.take_diagnostics() {
+			if let Some(sink) = self.error_sink.as_mut() {
+				sink.report(error.into());
+			}
+		}
+	}
+
+	/// 13.2.6 Tree construction: le répartiteur de la construction de
+	/// l'arbre.
+	pub(crate) fn process_token(&mut self, token: HTMLTreeToken<'_>)
+	{
+		if std::mem::take(&mut self.ignore_line_feed)
+			&& matches!(token, HTMLTreeToken::Character('\n'))
+		{
+			return;
+		}
+
+		if self.is_html_content(token) {
+			self.process_using_rules(self.insertion_mode, token);
+		} else {
+			self.handle_foreign_content(token);
+		}
+	}
+
+	/// Traite le jeton selon les règles du mode d'insertion donné.
+	#[rustfmt::skip]
+	pub(crate) fn process_using_rules(
+		&mut self,
+		mode: HTMLInsertionMode,
+		token: HTMLTreeToken<'_>,
+	)
+	{
+		match mode {
+			// 13.2.6.4.1 The "initial" insertion mode
+			| HTMLInsertionMode::Initial => self.handle_initial_insertion_mode(token),
+			// 13.2.6.4.2 The "before html" insertion mode
+			| HTMLInsertionMode::BeforeHtml => self.handle_before_html_insertion_mode(token),
+			// 13.2.6.4.3 The "before head" insertion mode
+			| HTMLInsertionMode::BeforeHead => self.handle_before_head_insertion_mode(token),
+			// 13.2.6.4.4 The "in head" insertion mode
+			| HTMLInsertionMode::InHead => self.handle_in_head_insertion_mode(token),
+			// 13.2.6.4.5 The "in head noscript" insertion mode
+			| HTMLInsertionMode::InHeadNoscript => self.handle_in_head_noscript_insertion_mode(token),
+			// 13.2.6.4.6 The "after head" insertion mode
+			| HTMLInsertionMode::AfterHead => self.handle_after_head_insertion_mode(token),
+			// 13.2.6.4.7 The "in body" insertion mode
+			| HTMLInsertionMode::InBody => self.handle_in_body_insertion_mode(token),
+			// 13.2.6.4.8 The "text" insertion mode
+			| HTMLInsertionMode::Text => self.handle_text_insertion_mode(token),
+			// 13.2.6.4.9 The "in table" insertion mode
+			| HTMLInsertionMode::InTable => self.handle_in_table_insertion_mode(token),
+			// 13.2.6.4.10 The "in table text" insertion mode
+			| HTMLInsertionMode::InTableText => self.handle_in_table_text_insertion_mode(token),
+			// 13.2.6.4.11 The "in caption" insertion mode
+			| HTMLInsertionMode::InCaption => self.handle_in_caption_insertion_mode(token),
+			// 13.2.6.4.12 The "in column group" insertion mode
+			| HTMLInsertionMode::InColumnGroup => self.handle_in_column_group_insertion_mode(token),
+			// 13.2.6.4.13 The "in table body" insertion mode
+			| HTMLInsertionMode::InTableBody => self.handle_in_table_body_insertion_mode(token),
+			// 13.2.6.4.14 The "in row" insertion mode
+			| HTMLInsertionMode::InRow => self.handle_in_row_insertion_mode(token),
+			// 13.2.6.4.15 The "in cell" insertion mode
+			| HTMLInsertionMode::InCell => self.handle_in_cell_insertion_mode(token),
+			// 13.2.6.4.16 The "in select" insertion mode
+			| HTMLInsertionMode::InSelect => self.handle_in_select_insertion_mode(token),
+			// 13.2.6.4.17 The "in select in table" insertion mode
+			| HTMLInsertionMode::InSelectInTable => self.handle_in_select_in_table_insertion_mode(token),
+			// 13.2.6.4.18 The "in template" insertion mode
+			| HTMLInsertionMode::InTemplate => self.handle_in_template_insertion_mode(token),
+			// 13.2.6.4.19 The "after body" insertion mode
+			| HTMLInsertionMode::AfterBody => self.handle_after_body_insertion_mode(token),
+			// 13.2.6.4.20 The "in frameset" insertion mode
+			| HTMLInsertionMode::InFrameset => self.handle_in_frameset_insertion_mode(token),
+			// 13.2.6.4.21 The "after frameset" insertion mode
+			| HTMLInsertionMode::AfterFrameset => self.handle_after_frameset_insertion_mode(token),
+			// 13.2.6.4.22 The "after after body" insertion mode
+			| HTMLInsertionMode::AfterAfterBody => self.handle_after_after_body_insertion_mode(token),
+			// 13.2.6.4.23 The "after after frameset" insertion mode
+			| HTMLInsertionMode::AfterAfterFrameset => self.handle_after_after_frameset_insertion_mode(token),
+		}
+	}
+
+	/// Retraite le jeton selon les règles du mode d'insertion courant.
+	pub(crate) fn reprocess(&mut self, token: HTMLTreeToken<'_>)
+	{
+		self.process_using_rules(self.insertion_mode, token);
+	}
+
+	/// Le jeton doit-il être traité selon les règles du contenu HTML, plutôt
+	/// que celles du contenu étranger?
+	fn is_html_content(&self, token: HTMLTreeToken<'_>) -> bool
+	{
+		let Some(node) = self.adjusted_current_node() else {
+			return true;
+		};
+
+		if self.is_html_element(node) {
+			return true;
+		}
+
+		let is_character = matches!(token, HTMLTreeToken::Character(_));
+
+		if self.is_mathml_text_integration_point(node) {
+			match token {
+				| HTMLTreeToken::StartTag { name, .. }
+					if name != "mglyph" && name != "malignmark" =>
+				{
+					return true;
+				}
+				| _ if is_character => return true,
+				| _ => {}
+			}
+		}
+
+		if self.tree[node].is(HTMLNamespace::MathML, "annotation-xml")
+			&& matches!(token, HTMLTreeToken::StartTag { name: "svg", .. })
+		{
+			return true;
+		}
+
+		if self.is_html_integration_point(node)
+			&& (is_character || matches!(token, HTMLTreeToken::StartTag { .. }))
+		{
+			return true;
+		}
+
+		matches!(token, HTMLTreeToken::EndOfStream)
+	}
+
+	/// Signale une erreur de construction de l'arbre.
+	pub(crate) fn parse_error(&mut self, reason: &'static str)
+	{
+		if let Some(sink) = self.error_sink.as_mut() {
+			sink.report(HTMLParserError::TreeConstruction {
+				reason,
+				location: self.token_location,
+			});
+		}
+	}
+
+	/// Bascule l'état du fournisseur de jeton, ex: après `<title>`.
+	pub(crate) fn switch_tokenizer_state(&mut self, state: HTMLTokenizerState)
+	{
+		self.tokenizer.switch_state(state);
+	}
+
+	/// 13.2.7 The end: arrête l'analyse.
+	pub(crate) fn stop_parsing(&mut self)
+	{
+		self.stack_of_open_elements.clear();
+		self.stopped = true;
+	}
+}
+
+// Pile des éléments ouverts.
+impl<Input: Iterator> HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	pub(crate) fn node(&self, id: HTMLNodeId) -> &HTMLNode
+	{
+		&self.tree[id]
+	}
+
+	pub(crate) fn is_html_element(&self, id: HTMLNodeId) -> bool
+	{
+		self.tree[id].namespace() == Some(HTMLNamespace::Html)
+	}
+
+	/// Vérifie que le nœud est un élément HTML dont le nom fait partie de la
+	/// liste donnée.
+	pub(crate) fn is_html_element_named(
+		&self,
+		id: HTMLNodeId,
+		names: &[&str],
+	) -> bool
+	{
+		self.is_html_element(id)
+			&& self.tree[id]
+				.element_name()
+				.is_some_and(|name| names.contains(&name))
+	}
+
+	pub(crate) fn current_node(&self) -> HTMLNodeId
+	{
+		*self
+			.stack_of_open_elements
+			.last()
+			.expect("la pile des éléments ouverts ne doit pas être vide")
+	}
+
+	/// Nœud courant, ou l'élément de contexte lorsque la pile ne contient que
+	/// l'élément racine d'un fragment.
+	pub(crate) fn adjusted_current_node(&self) -> Option<HTMLNodeId>
+	{
+		match self.stack_of_open_elements.as_slice() {
+			| [_] if self.context_element.is_some() => self.context_element,
+			| stack => stack.last().copied(),
+		}
+	}
+
+	/// Vérifie que le nœud courant est un élément HTML dont le nom fait
+	/// partie de la liste donnée.
+	pub(crate) fn current_node_is(&self, names: &[&str]) -> bool
+	{
+		self.stack_of_open_elements
+			.last()
+			.is_some_and(|node| self.is_html_element_named(*node, names))
+	}
+
+	/// Vérifie que la pile contient un élément HTML ayant le nom donné.
+	pub(crate) fn stack_contains(&self, name: &str) -> bool
+	{
+		self.stack_of_open_elements
+			.iter()
+			.any(|node| self.is_html_element_named(*node, &[name]))
+	}
+
+	pub(crate) fn is_special(&self, id: HTMLNodeId) -> bool
+	{
+		let node = &self.tree[id];
+		let Some(name) = node.element_name() else {
+			return false;
+		};
+
+		match node.namespace() {
+			| Some(HTMLNamespace::Html) => {
+				SPECIAL_HTML_ELEMENTS.contains(&name)
+			}
+			| Some(HTMLNamespace::MathML) => {
+				matches!(
+					name,
+					"mi" | "mo" | "mn" | "ms" | "mtext" | "annotation-xml"
+				)
+			}
+			| Some(HTMLNamespace::Svg) => {
+				matches!(name, "foreignObject" | "desc" | "title")
+			}
+			| _ => false,
+		}
+	}
+
+	pub(crate) fn is_mathml_text_integration_point(
+		&self,
+		id: HTMLNodeId,
+	) -> bool
+	{
+		let node = &self.tree[id];
+		node.namespace() == Some(HTMLNamespace::MathML)
+			&& matches!(
+				node.element_name(),
+				Some("mi" | "mo" | "mn" | "ms" | "mtext")
+			)
+	}
+
+	pub(crate) fn is_html_integration_point(&self, id: HTMLNodeId) -> bool
+	{
+		let node = &self.tree[id];
+
+		if node.is(HTMLNamespace::MathML, "annotation-xml") {
+			return node.attribute("encoding").is_some_and(|encoding| {
+				encoding.eq_ignore_ascii_case("text/html")
+					|| encoding.eq_ignore_ascii_case("application/xhtml+xml")
+			});
+		}
+
+		node.namespace() == Some(HTMLNamespace::Svg)
+			&& matches!(
+				node.element_name(),
+				Some("foreignObject" | "desc" | "title")
+			)
+	}
+
+	fn is_scope_boundary(&self, id: HTMLNodeId, scope: HTMLScope) -> bool
+	{
+		match scope {
+			| HTMLScope::Select => {
+				!self.is_html_element_named(id, &["optgroup", "option"])
+			}
+			| HTMLScope::Table => {
+				self.is_html_element_named(id, &["html", "table", "template"])
+			}
+			| _ => {
+				let node = &self.tree[id];
+				let is_default_boundary = self.is_html_element_named(
+					id,
+					&[
+						"applet", "caption", "html", "table", "td", "th",
+						"marquee", "object", "template",
+					],
+				) || self
+					.is_mathml_text_integration_point(id)
+					|| node.is(HTMLNamespace::MathML, "annotation-xml")
+					|| node.is(HTMLNamespace::Svg, "foreignObject")
+					|| node.is(HTMLNamespace::Svg, "desc")
+					|| node.is(HTMLNamespace::Svg, "title");
+
+				is_default_boundary
+					|| (scope == HTMLScope::ListItem
+						&& self.is_html_element_named(id, &["ol", "ul"]))
+					|| (scope == HTMLScope::Button
+						&& self.is_html_element_named(id, &["button"]))
+			}
+		}
+	}
+
+	fn has_in_scope_where(
+		&self,
+		predicate: impl Fn(HTMLNodeId) -> bool,
+		scope: HTMLScope,
+	) -> bool
+	{
+		for node in self.stack_of_open_elements.iter().rev().copied() {
+			if predicate(node) {
+				return true;
+			}
+			if self.is_scope_boundary(node, scope) {
+				return false;
+			}
+		}
+		false
+	}
+
+	/// Vérifie que la pile contient, dans la portée donnée, un élément HTML
+	/// dont le nom fait partie de la liste.
+	pub(crate) fn has_element_in_scope(
+		&self,
+		names: &[&str],
+		scope: HTMLScope,
+	) -> bool
+	{
+		self.has_in_scope_where(
+			|node| self.is_html_element_named(node, names),
+			scope,
+		)
+	}
+
+	/// Vérifie que la pile contient le nœud donné, dans la portée donnée.
+	pub(crate) fn has_node_in_scope(
+		&self,
+		target: HTMLNodeId,
+		scope: HTMLScope,
+	) -> bool
+	{
+		self.has_in_scope_where(|node| node == target, scope)
+	}
+
+	/// Dépile jusqu'à ce qu'un élément HTML dont le nom fait partie de la
+	/// liste ait été dépilé.
+	pub(crate) fn pop_until(&mut self, names: &[&str])
+	{
+		while let Some(node) = self.stack_of_open_elements.pop() {
+			if self.is_html_element_named(node, names) {
+				break;
+			}
+		}
+	}
+
+	/// Dépile jusqu'à ce que le nœud donné ait été dépilé.
+	pub(crate) fn pop_until_node(&mut self, target: HTMLNodeId)
+	{
+		while let Some(node) = self.stack_of_open_elements.pop() {
+			if node == target {
+				break;
+			}
+		}
+	}
+
+	pub(crate) fn remove_from_stack(&mut self, target: HTMLNodeId)
+	{
+		self.stack_of_open_elements.retain(|node| *node != target);
+	}
+
+	/// Dépile jusqu'à ce que le nœud courant soit un élément HTML dont le nom
+	/// fait partie de la liste, ex: "clear the stack back to a table
+	/// context".
+	pub(crate) fn clear_stack_back_to(&mut self, names: &[&str])
+	{
+		while !self.current_node_is(names) {
+			self.stack_of_open_elements.pop();
+		}
+	}
+
+	/// 13.2.6.3 Closing elements that have implied end tags
+	pub(crate) fn generate_implied_end_tags(&mut self, except: Option<&str>)
+	{
+		while let Some(&node) = self.stack_of_open_elements.last() {
+			let is_implied = self
+				.is_html_element_named(node, &IMPLIED_END_TAGS)
+				&& except.is_none_or(|name| {
+					!self.is_html_element_named(node, &[name])
+				});
+			if !is_implied {
+				break;
+			}
+			self.stack_of_open_elements.pop();
+		}
+	}
+
+	pub(crate) fn generate_all_implied_end_tags_thoroughly(&mut self)
+	{
+		while self.current_node_is(&IMPLIED_END_TAGS_THOROUGHLY) {
+			self.stack_of_open_elements.pop();
+		}
+	}
+
+	/// Ferme un élément `<p>`.
+	pub(crate) fn close_p_element(&mut self)
+	{
+		self.generate_implied_end_tags(Some("p"));
+		if !self.current_node_is(&["p"]) {
+			self.parse_error("unexpected-p-element-end");
+		}
+		self.pop_until(&["p"]);
+	}
+
+	/// 13.2.4.1 The insertion mode: réinitialise le mode d'insertion de
+	/// manière appropriée.
+	pub(crate) fn reset_insertion_mode_appropriately(&mut self)
+	{
+		for (index, node) in self
+			.stack_of_open_elements
+			.iter()
+			.copied()
+			.enumerate()
+			.rev()
+		{
+			let last = index == 0;
+			let node = match self.context_element {
+				| Some(context_element) if last => context_element,
+				| _ => node,
+			};
+
+			if !self.is_html_element(node) {
+				if last {
+					break;
+				}
+				continue;
+			}
+
+			let name = self.tree[node].element_name().unwrap_or_default();
+
+			self.insertion_mode = match name {
+				| "select" => {
+					let in_table = !last
+						&& self.stack_of_open_elements[..index]
+							.iter()
+							.rev()
+							.take_while(|ancestor| {
+								!self.is_html_element_named(
+									**ancestor,
+									&["template"],
+								)
+							})
+							.any(|ancestor| {
+								self.is_html_element_named(
+									*ancestor,
+									&["table"],
+								)
+							});
+
+					if in_table {
+						HTMLInsertionMode::InSelectInTable
+					} else {
+						HTMLInsertionMode::InSelect
+					}
+				}
+				| "td" | "th" if !last => HTMLInsertionMode::InCell,
+				| "tr" => HTMLInsertionMode::InRow,
+				| "tbody" | "thead" | "tfoot" => HTMLInsertionMode::InTableBody,
+				| "caption" => HTMLInsertionMode::InCaption,
+				| "colgroup" => HTMLInsertionMode::InColumnGroup,
+				| "table" => HTMLInsertionMode::InTable,
+				| "template" => {
+					*self
+						.template_insertion_modes
+						.last()
+						.expect("le mode d'insertion du template courant")
+				}
+				| "head" if !last => HTMLInsertionMode::InHead,
+				| "body" => HTMLInsertionMode::InBody,
+				| "frameset" => HTMLInsertionMode::InFrameset,
+				| "html" => {
+					if self.head_element.is_none() {
+						HTMLInsertionMode::BeforeHead
+					} else {
+						HTMLInsertionMode::AfterHead
+					}
+				}
+				| _ if last => HTMLInsertionMode::InBody,
+				| _ => continue,
+			};
+			return;
+		}
+
+		self.insertion_mode = HTMLInsertionMode::InBody;
+	}
+}
+
+// Insertion des nœuds.
+impl<Input: Iterator> HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	/// 13.2.6.1 Creating and inserting nodes: emplacement approprié pour
+	/// insérer un nœud.
+	pub(crate) fn appropriate_place_for_inserting_node(
+		&self,
+		override_target: Option<HTMLNodeId>,
+	) -> HTMLInsertionPlace
+	{
+		let target = override_target.unwrap_or_else(|| self.current_node());
+
+		let place = if self.foster_parenting
+			&& self.is_html_element_named(
+				target,
+				&["table", "tbody", "tfoot", "thead", "tr"],
+			) {
+			self.foster_parenting_place()
+		} else {
+			HTMLInsertionPlace {
+				parent: target,
+				before: None,
+			}
+		};
+
+		match self.tree[place.parent].template_contents() {
+			| Some(contents) if self.is_html_element(place.parent) => {
+				HTMLInsertionPlace {
+					parent: contents,
+					before: None,
+				}
+			}
+			| _ => place,
+		}
+	}
+
+	fn foster_parenting_place(&self) -> HTMLInsertionPlace
+	{
+		let position_of = |name: &str| {
+			self.stack_of_open_elements
+				.iter()
+				.rposition(|node| self.is_html_element_named(*node, &[name]))
+		};
+
+		let last_template = position_of("template");
+		let last_table = position_of("table");
+
+		match (last_template, last_table) {
+			| (Some(template), table)
+				if table.is_none_or(|table| template > table) =>
+			{
+				HTMLInsertionPlace {
+					parent: self.stack_of_open_elements[template],
+					before: None,
+				}
+			}
+
+			| (_, None) => {
+				HTMLInsertionPlace {
+					parent: self.stack_of_open_elements[0],
+					before: None,
+				}
+			}
+
+			| (_, Some(table)) => {
+				let table_node = self.stack_of_open_elements[table];
+				match self.tree[table_node].parent() {
+					| Some(parent) => {
+						HTMLInsertionPlace {
+							parent,
+							before: Some(table_node),
+						}
+					}
+					| None => {
+						HTMLInsertionPlace {
+							parent: self.stack_of_open_elements[table - 1],
+							before: None,
+						}
+					}
+				}
+			}
+		}
+	}
+
+	pub(crate) fn insert_node_at(
+		&mut self,
+		place: HTMLInsertionPlace,
+		node: HTMLNodeId,
+	)
+	{
+		match place.before {
+			| Some(reference) => self.tree.insert_before(reference, node),
+			| None => self.tree.append(place.parent, node),
+		}
+	}
+
+	/// Attributs d'une balise de début; seul le premier attribut d'un même
+	/// nom est conservé.
+	pub(crate) fn attributes_for_token(
+		token: HTMLTreeToken<'_>,
+	) -> Vec<HTMLAttribute>
+	{
+		let HTMLTreeToken::StartTag { attributes, .. } = token else {
+			return Vec::new();
+		};
+
+		let mut output: Vec<HTMLAttribute> =
+			Vec::with_capacity(attributes.len());
+		for (name, value) in attributes {
+			if output.iter().all(|attr| &attr.name != name) {
+				output.push(HTMLAttribute::new(name, value));
+			}
+		}
+		output
+	}
+
+	/// Insère un élément à l'emplacement approprié et l'empile.
+	pub(crate) fn insert_element(
+		&mut self,
+		namespace: HTMLNamespace,
+		name: &str,
+		attributes: Vec<HTMLAttribute>,
+	) -> HTMLNodeId
+	{
+		let place = self.appropriate_place_for_inserting_node(None);
+		let element = self.tree.create_element(namespace, name, attributes);
+		if namespace == HTMLNamespace::Html {
+			self.associate_with_form(element, place.parent);
+		}
+		self.insert_node_at(place, element);
+		self.stack_of_open_elements.push(element);
+		element
+	}
+
+	/// 13.2.6.1 Creating and inserting nodes: associe un élément associable
+	/// à un formulaire au pointeur de l'élément form, lorsque le parent prévu
+	/// et le formulaire sont dans le même arbre.
+	fn associate_with_form(&mut self, element: HTMLNodeId, parent: HTMLNodeId)
+	{
+		let Some(form) = self.form_element else {
+			return;
+		};
+
+		let node = &self.tree[element];
+		let name = node.element_name().unwrap_or_default();
+		if !FORM_ASSOCIATED_ELEMENTS.contains(&name)
+			|| self.stack_contains("template")
+			|| (name != "img" && node.attribute("form").is_some())
+		{
+			return;
+		}
+
+		if self.tree.root(parent) == self.tree.root(form) {
+			self.tree.set_form_owner(element, form);
+		}
+	}
+
+	/// Insère un élément HTML pour la balise de début donnée.
+	pub(crate) fn insert_html_element(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	) -> HTMLNodeId
+	{
+		let HTMLTreeToken::StartTag { name, .. } = token else {
+			unreachable!("un élément est créé pour une balise de début")
+		};
+		let attributes = Self::attributes_for_token(token);
+		self.insert_element(HTMLNamespace::Html, name, attributes)
+	}
+
+	/// Insère un élément HTML sans attribut, ex: pour un `<head>` implicite.
+	pub(crate) fn insert_html_element_named(&mut self, name: &str)
+		-> HTMLNodeId
+	{
+		self.insert_element(HTMLNamespace::Html, name, Vec::new())
+	}
+
+	pub(crate) fn insert_character(&mut self, ch: char)
+	{
+		let place = self.appropriate_place_for_inserting_node(None);
+
+		if matches!(self.tree[place.parent].data(), HTMLNodeData::Document) {
+			return;
+		}
+
+		let mut buffer = [0; 4];
+		let text = ch.encode_utf8(&mut buffer);
+		match place.before {
+			| Some(reference) => self.tree.insert_text_before(reference, text),
+			| None => self.tree.append_text(place.parent, text),
+		}
+	}
+
+	/// Insère un commentaire à la fin des enfants du parent donné, sinon à
+	/// l'emplacement approprié.
+	pub(crate) fn insert_comment(
+		&mut self,
+		data: &str,
+		parent: Option<HTMLNodeId>,
+	)
+	{
+		let place = match parent {
+			| Some(parent) => {
+				HTMLInsertionPlace {
+					parent,
+					before: None,
+				}
+			}
+			| None => self.appropriate_place_for_inserting_node(None),
+		};
+		let comment = self.tree.create_comment(data);
+		self.insert_node_at(place, comment);
+	}
+
+	/// 13.2.6.2 Parsing elements that contain only text: algorithmes
+	/// génériques d'analyse des éléments RAWTEXT et RCDATA.
+	pub(crate) fn parse_generic_text_element(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+		state: HTMLTokenizerState,
+	)
+	{
+		self.insert_html_element(token);
+		self.switch_tokenizer_state(state);
+		self.original_insertion_mode = self.insertion_mode;
+		self.insertion_mode = HTMLInsertionMode::Text;
+	}
+}
+
+// Liste des éléments de mise en forme actifs.
+impl<Input: Iterator> HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	pub(crate) fn push_active_formatting_marker(&mut self)
+	{
+		self.list_of_active_formatting_elements
+			.push(HTMLActiveFormattingElement::Marker);
+	}
+
+	/// Ajoute l'élément à la liste des éléments de mise en forme actifs. La
+	/// clause de l'Arche de Noé limite à trois les éléments identiques
+	/// depuis le dernier marqueur.
+	pub(crate) fn push_active_formatting_element(&mut self, element: HTMLNodeId)
+	{
+		let mut identical = Vec::new();
+		for (index, entry) in self
+			.list_of_active_formatting_elements
+			.iter()
+			.enumerate()
+			.rev()
+		{
+			match entry {
+				| HTMLActiveFormattingElement::Marker => break,
+				| HTMLActiveFormattingElement::Element(other) => {
+					if self.are_identical_elements(element, *other) {
+						identical.push(index);
+					}
+				}
+			}
+		}
+
+		if identical.len() >= 3 {
+			let earliest = *identical.last().unwrap();
+			self.list_of_active_formatting_elements.remove(earliest);
+		}
+
+		self.list_of_active_formatting_elements
+			.push(HTMLActiveFormattingElement::Element(element));
+	}
+
+	fn are_identical_elements(&self, a: HTMLNodeId, b: HTMLNodeId) -> bool
+	{
+		let (a, b) = (&self.tree[a], &self.tree[b]);
+		a.element_name() == b.element_name()
+			&& a.namespace() == b.namespace()
+			&& a.attributes().len() == b.attributes().len()
+			&& a.attributes()
+				.iter()
+				.all(|attr| b.attributes().contains(attr))
+	}
+
+	pub(crate) fn position_in_active_formatting_elements(
+		&self,
+		element: HTMLNodeId,
+	) -> Option<usize>
+	{
+		self.list_of_active_formatting_elements
+			.iter()
+			.position(|entry| {
+				*entry == HTMLActiveFormattingElement::Element(element)
+			})
+	}
+
+	pub(crate) fn remove_from_active_formatting_elements(
+		&mut self,
+		element: HTMLNodeId,
+	)
+	{
+		if let Some(index) =
+			self.position_in_active_formatting_elements(element)
+		{
+			self.list_of_active_formatting_elements.remove(index);
+		}
+	}
+
+	/// Dernier élément de la liste, depuis le dernier marqueur, qui est un
+	/// élément HTML ayant le nom donné.
+	pub(crate) fn active_formatting_element_named(
+		&self,
+		name: &str,
+	) -> Option<HTMLNodeId>
+	{
+		self.list_of_active_formatting_elements
+			.iter()
+			.rev()
+			.map_while(|entry| {
+				match entry {
+					| HTMLActiveFormattingElement::Marker => None,
+					| HTMLActiveFormattingElement::Element(element) => {
+						Some(*element)
+					}
+				}
+			})
+			.find(|element| self.is_html_element_named(*element, &[name]))
+	}
+
+	/// Crée un nouvel élément identique à l'élément donné (même nom, même
+	/// espace de noms, mêmes attributs), détaché de l'arbre.
+	pub(crate) fn clone_element(&mut self, element: HTMLNodeId) -> HTMLNodeId
+	{
+		let node = &self.tree[element];
+		let namespace = node.namespace().unwrap_or_default();
+		let name = node.element_name().unwrap_or_default().to_owned();
+		let attributes = node.attributes().to_vec();
+		self.tree.create_element(namespace, name, attributes)
+	}
+
+	/// Reconstruit les éléments de mise en forme actifs.
+	pub(crate) fn reconstruct_active_formatting_elements(&mut self)
+	{
+		let is_open_or_marker = |this: &Self, entry| {
+			match entry {
+				| HTMLActiveFormattingElement::Marker => true,
+				| HTMLActiveFormattingElement::Element(element) => {
+					this.stack_of_open_elements.contains(&element)
+				}
+			}
+		};
+
+		let Some(&last) = self.list_of_active_formatting_elements.last() else {
+			return;
+		};
+		if is_open_or_marker(self, last) {
+			return;
+		}
+
+		let mut index = self.list_of_active_formatting_elements.len() - 1;
+		while index > 0 {
+			let entry = self.list_of_active_formatting_elements[index - 1];
+			if is_open_or_marker(self, entry) {
+				break;
+			}
+			index -= 1;
+		}
+
+		for index in index..self.list_of_active_formatting_elements.len() {
+			let HTMLActiveFormattingElement::Element(element) =
+				self.list_of_active_formatting_elements[index]
+			else {
+				continue;
+			};
+
+			let new_element = self.clone_element(element);
+			let place = self.appropriate_place_for_inserting_node(None);
+			self.insert_node_at(place, new_element);
+			self.stack_of_open_elements.push(new_element);
+			self.list_of_active_formatting_elements[index] =
+				HTMLActiveFormattingElement::Element(new_element);
+		}
+	}
+
+	pub(crate) fn clear_active_formatting_elements_up_to_last_marker(&mut self)
+	{
+		while let Some(entry) = self.list_of_active_formatting_elements.pop() {
+			if entry == HTMLActiveFormattingElement::Marker {
+				break;
+			}
+		}
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::dom::HTMLQuirksMode;
+
+	fn parse(source: &str) -> HTMLTree
+	{
+		HTMLParser::new(source.chars())
+			.parse()
+			.expect("l'analyse ne devrait pas échouer")
+	}
+
+	/// Représentation de l'arbre au format des tests html5lib, sans la ligne
+	/// `#document`.
+	fn dump(tree: &HTMLTree) -> String
+	{
+		tree.dump()
+			.strip_prefix("#document\n")
+			.expect("la représentation commence par #document")
+			.to_owned()
+	}
+
+	#[test]
+	fn test_implied_html_head_body()
+	{
+		assert_eq!(
+			dump(&parse("Bonjour")),
+			"| <html>\n|   <head>\n|   <body>\n|     \"Bonjour\"\n"
+		);
+	}
+
+	#[test]
+	fn test_doctype_and_no_quirks()
+	{
+		let tree = parse("<!DOCTYPE html><title>a</title>");
+		assert_eq!(tree.quirks_mode(), HTMLQuirksMode::NoQuirks);
+		assert_eq!(
+			dump(&tree),
+			"| <!DOCTYPE html>\n| <html>\n|   <head>\n|     <title>\n|       \
+			 \"a\"\n|   <body>\n"
+		);
+	}
+
+	#[test]
+	fn test_quirks_mode_without_doctype()
+	{
+		assert_eq!(parse("<p>a").quirks_mode(), HTMLQuirksMode::Quirks);
+	}
+
+	#[test]
+	fn test_text_order()
+	{
+		let tree = parse("<p>a <b>b</b> c</p>");
+		assert_eq!(
+			dump(&tree),
+			"| <html>\n|   <head>\n|   <body>\n|     <p>\n|       \"a \"\n|       \
+			 <b>\n|         \"b\"\n|       \" c\"\n"
+		);
+	}
+
+	#[test]
+	fn test_adoption_agency()
+	{
+		let tree = parse("<b><p>a</b>b");
+		assert_eq!(
+			dump(&tree),
+			"| <html>\n|   <head>\n|   <body>\n|     <b>\n|     <p>\n|       \
+			 <b>\n|         \"a\"\n|       \"b\"\n"
+		);
+	}
+
+	#[test]
+	fn test_misnested_formatting_elements()
+	{
+		let tree = parse("<a><b>x</a>y");
+		assert_eq!(
+			dump(&tree),
+			"| <html>\n|   <head>\n|   <body>\n|     <a>\n|       <b>\n|         \
+			 \"x\"\n|     <b>\n|       \"y\"\n"
+		);
+	}
+
+	#[test]
+	fn test_foster_parenting()
+	{
+		let tree = parse("<table>a<tr>");
+		assert_eq!(
+			dump(&tree),
+			"| <html>\n|   <head>\n|   <body>\n|     \"a\"\n|     <table>\n|       \
+			 <tbody>\n|         <tr>\n"
+		);
+	}
+
+	#[test]
+	fn test_table_whitespace_stays_in_table()
+	{
+		let tree = parse("<table> <td>x</td></table>");
+		assert_eq!(
+			dump(&tree),
+			"| <html>\n|   <head>\n|   <body>\n|     <table>\n|       \" \
+			 \"\n|       <tbody>\n|         <tr>\n|           <td>\n|             \
+			 \"x\"\n"
+		);
+	}
+
+	#[test]
+	fn test_noahs_ark()
+	{
+		let tree = parse("<p><b><b><b><b><p>x");
+		let html = dump(&tree);
+		let reopened = html
+			.lines()
+			.skip_while(|line| !line.contains("<p>"))
+			.skip(1)
+			.skip_while(|line| !line.contains("<p>"))
+			.filter(|line| line.contains("<b>"))
+			.count();
+		assert_eq!(reopened, 3);
+	}
+
+	#[test]
+	fn test_implied_end_tags()
+	{
+		let tree = parse("<ul><li>a<li>b</ul><p>c<p>d");
+		assert_eq!(
+			dump(&tree),
+			"| <html>\n|   <head>\n|   <body>\n|     <ul>\n|       <li>\n|         \
+			 \"a\"\n|       <li>\n|         \"b\"\n|     <p>\n|       \"c\"\n|     \
+			 <p>\n|       \"d\"\n"
+		);
+	}
+
+	#[test]
+	fn test_template_contents()
+	{
+		let tree = parse("<template><p>a</p></template>");
+		assert_eq!(
+			dump(&tree),
+			"| <html>\n|   <head>\n|     <template>\n|       content\n|         \
+			 <p>\n|           \"a\"\n|   <body>\n"
+		);
+	}
+
+	#[test]
+	fn test_svg_adjustments()
+	{
+		let tree = parse("<svg viewbox='0 0 1 1'><foreignobject><p>a");
+		assert_eq!(
+			dump(&tree),
+			"| <html>\n|   <head>\n|   <body>\n|     <svg svg>\n|       \
+			 viewBox=\"0 0 1 1\"\n|       <svg foreignObject>\n|         \
+			 <p>\n|           \"a\"\n"
+		);
+	}
+
+	#[test]
+	fn test_frameset()
+	{
+		let tree = parse("<frameset><frame></frameset><!-- x -->");
+		assert_eq!(
+			dump(&tree),
+			"| <html>\n|   <head>\n|   <frameset>\n|     <frame>\n|   <!--  x  \
+			 -->\n"
+		);
+	}
+
+	#[test]
+	fn test_select_in_table()
+	{
+		let tree = parse("<table><tr><td><select><option>a<td>b");
+		assert_eq!(
+			dump(&tree),
+			"| <html>\n|   <head>\n|   <body>\n|     <table>\n|       \
+			 <tbody>\n|         <tr>\n|           <td>\n|             \
+			 <select>\n|               <option>\n|                 \"a\"\n|           \
+			 <td>\n|             \"b\"\n"
+		);
+	}
+
+	#[test]
+	fn test_parse_errors_are_reported()
 	{
-		todo!()
+		let errors = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+		let sink = errors.clone();
+		HTMLParser::new("<p></i>".chars())
+			.with_error_sink(move |err| sink.borrow_mut().push(err))
+			.parse()
+			.unwrap();
+		assert!(!errors.borrow().is_empty());
 	}
 }
@@ -0,0 +1,834 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use sky_lang::UnitCodePointExt;
+
+use crate::dom::{HTMLNamespace, HTMLNodeId, HTMLQuirksMode};
+use crate::lexical::HTMLTokenizerState;
+use crate::syntax::parser::{HTMLActiveFormattingElement, HTMLScope};
+use crate::syntax::rules::HTMLParserForeignContentRules;
+use crate::syntax::{HTMLInsertionMode, HTMLParser, HTMLTreeToken};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLParserBodyRules
+{
+	fn handle_in_body_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_text_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+}
+
+// -------- //
+// Constant //
+// -------- //
+
+const HEADINGS: [&str; 6] = ["h1", "h2", "h3", "h4", "h5", "h6"];
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<Input: Iterator> HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	/// Règles "any other end tag" du mode d'insertion "in body".
+	fn handle_any_other_end_tag_in_body(&mut self, name: &str)
+	{
+		for index in (0..self.stack_of_open_elements.len()).rev() {
+			let node = self.stack_of_open_elements[index];
+
+			if self.is_html_element_named(node, &[name]) {
+				self.generate_implied_end_tags(Some(name));
+				if self.current_node() != node {
+					self.parse_error("unexpected-end-tag");
+				}
+				self.stack_of_open_elements.truncate(index);
+				return;
+			}
+
+			if self.is_special(node) {
+				self.parse_error("unexpected-end-tag");
+				return;
+			}
+		}
+	}
+
+	/// 13.2.6.4.7 The "in body" insertion mode: algorithme de l'agence
+	/// d'adoption, qui répare les éléments de mise en forme mal imbriqués,
+	/// ex: `<b><p>a</b>b</p>`.
+	fn run_adoption_agency_algorithm(&mut self, subject: &str)
+	{
+		let current_node = self.current_node();
+		if self.is_html_element_named(current_node, &[subject])
+			&& self
+				.position_in_active_formatting_elements(current_node)
+				.is_none()
+		{
+			self.stack_of_open_elements.pop();
+			return;
+		}
+
+		for _ in 0..8 {
+			let Some(formatting_element) =
+				self.active_formatting_element_named(subject)
+			else {
+				self.handle_any_other_end_tag_in_body(subject);
+				return;
+			};
+
+			let Some(formatting_element_index) = self
+				.stack_of_open_elements
+				.iter()
+				.position(|node| *node == formatting_element)
+			else {
+				self.parse_error("adoption-agency-1.2");
+				self.remove_from_active_formatting_elements(formatting_element);
+				return;
+			};
+
+			if !self.has_node_in_scope(formatting_element, HTMLScope::Default) {
+				self.parse_error("adoption-agency-4.4");
+				return;
+			}
+
+			if formatting_element != self.current_node() {
+				self.parse_error("adoption-agency-1.3");
+			}
+
+			let furthest_block = self.stack_of_open_elements
+				[formatting_element_index + 1..]
+				.iter()
+				.copied()
+				.find(|node| self.is_special(*node));
+
+			let Some(furthest_block) = furthest_block else {
+				self.stack_of_open_elements
+					.truncate(formatting_element_index);
+				self.remove_from_active_formatting_elements(formatting_element);
+				return;
+			};
+
+			let common_ancestor =
+				self.stack_of_open_elements[formatting_element_index - 1];
+
+			// NOTE: le signet est l'élément après lequel insérer le nouvel
+			// élément de mise en forme dans la liste; à défaut, il remplace
+			// l'élément de mise en forme.
+			let mut bookmark: Option<HTMLNodeId> = None;
+
+			let mut node_index = self
+				.stack_of_open_elements
+				.iter()
+				.position(|node| *node == furthest_block)
+				.unwrap();
+			let mut last_node = furthest_block;
+
+			let mut inner_loop_counter = 0;
+			loop {
+				inner_loop_counter += 1;
+				node_index -= 1;
+				let mut node = self.stack_of_open_elements[node_index];
+
+				if node == formatting_element {
+					break;
+				}
+
+				let mut afe_index =
+					self.position_in_active_formatting_elements(node);
+
+				if inner_loop_counter > 3 {
+					if let Some(index) = afe_index.take() {
+						self.list_of_active_formatting_elements.remove(index);
+					}
+				}
+
+				let Some(afe_index) = afe_index else {
+					self.stack_of_open_elements.remove(node_index);
+					continue;
+				};
+
+				let new_element = self.clone_element(node);
+				self.list_of_active_formatting_elements[afe_index] =
+					HTMLActiveFormattingElement::Element(new_element);
+				self.stack_of_open_elements[node_index] = new_element;
+				node = new_element;
+
+				if last_node == furthest_block {
+					bookmark.replace(new_element);
+				}
+
+				self.tree.append(node, last_node);
+				last_node = node;
+			}
+
+			let place = self
+				.appropriate_place_for_inserting_node(Some(common_ancestor));
+			self.insert_node_at(place, last_node);
+
+			let new_element = self.clone_element(formatting_element);
+			self.tree.reparent_children(furthest_block, new_element);
+			self.tree.append(furthest_block, new_element);
+
+			let new_entry = HTMLActiveFormattingElement::Element(new_element);
+			match bookmark {
+				| Some(previous) => {
+					self.remove_from_active_formatting_elements(
+						formatting_element,
+					);
+					let index = self
+						.position_in_active_formatting_elements(previous)
+						.unwrap();
+					self.list_of_active_formatting_elements
+						.insert(index + 1, new_entry);
+				}
+				| None => {
+					let index = self
+						.position_in_active_formatting_elements(
+							formatting_element,
+						)
+						.unwrap();
+					self.list_of_active_formatting_elements[index] = new_entry;
+				}
+			}
+
+			self.remove_from_stack(formatting_element);
+			let furthest_block_index = self
+				.stack_of_open_elements
+				.iter()
+				.position(|node| *node == furthest_block)
+				.unwrap();
+			self.stack_of_open_elements
+				.insert(furthest_block_index + 1, new_element);
+		}
+	}
+
+	/// Insère un élément vide, ex: `<br>`, `<img>`.
+	fn insert_void_element(&mut self, token: HTMLTreeToken<'_>)
+	{
+		self.reconstruct_active_formatting_elements();
+		self.insert_html_element(token);
+		self.stack_of_open_elements.pop();
+		self.frameset_ok = false;
+	}
+
+	/// Ferme l'élément `<p>` s'il est dans la portée bouton.
+	fn close_p_element_in_button_scope(&mut self)
+	{
+		if self.has_element_in_scope(&["p"], HTMLScope::Button) {
+			self.close_p_element();
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLParserBodyRules for HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_in_body_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character('\0') => {
+				self.parse_error("unexpected-null-character");
+			}
+
+			| HTMLTreeToken::Character(ch) => {
+				self.reconstruct_active_formatting_elements();
+				self.insert_character(ch);
+				if !token.is_whitespace() {
+					self.frameset_ok = false;
+				}
+			}
+
+			| HTMLTreeToken::Comment(data) => self.insert_comment(data, None),
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.parse_error("unexpected-start-tag");
+				if self.stack_contains("template") {
+					return;
+				}
+				let html = self.stack_of_open_elements[0];
+				let attributes = Self::attributes_for_token(token);
+				self.tree.add_attributes_if_missing(html, attributes);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name:
+					"base" | "basefont" | "bgsound" | "link" | "meta" | "noframes"
+					| "script" | "style" | "template" | "title",
+				..
+			}
+			| HTMLTreeToken::EndTag { name: "template" } => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+
+			| HTMLTreeToken::StartTag { name: "body", .. } => {
+				self.parse_error("unexpected-start-tag");
+
+				let body = self.stack_of_open_elements.get(1).copied();
+				let Some(body) = body.filter(|body| {
+					self.is_html_element_named(*body, &["body"])
+				}) else {
+					return;
+				};
+				if self.stack_contains("template") {
+					return;
+				}
+
+				self.frameset_ok = false;
+				let attributes = Self::attributes_for_token(token);
+				self.tree.add_attributes_if_missing(body, attributes);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "frameset", ..
+			} => {
+				self.parse_error("unexpected-start-tag");
+
+				let body = self.stack_of_open_elements.get(1).copied();
+				let Some(body) = body.filter(|body| {
+					self.is_html_element_named(*body, &["body"])
+				}) else {
+					return;
+				};
+				if !self.frameset_ok {
+					return;
+				}
+
+				self.tree.remove_from_parent(body);
+				self.stack_of_open_elements.truncate(1);
+				self.insert_html_element(token);
+				self.insertion_mode = HTMLInsertionMode::InFrameset;
+			}
+
+			| HTMLTreeToken::EndOfStream => {
+				if !self.template_insertion_modes.is_empty() {
+					self.process_using_rules(
+						HTMLInsertionMode::InTemplate,
+						token,
+					);
+				} else {
+					self.stop_parsing();
+				}
+			}
+
+			| HTMLTreeToken::EndTag { name: "body" } => {
+				if !self.has_element_in_scope(&["body"], HTMLScope::Default) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.insertion_mode = HTMLInsertionMode::AfterBody;
+			}
+
+			| HTMLTreeToken::EndTag { name: "html" } => {
+				if !self.has_element_in_scope(&["body"], HTMLScope::Default) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.insertion_mode = HTMLInsertionMode::AfterBody;
+				self.reprocess(token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name:
+					"address" | "article" | "aside" | "blockquote" | "center"
+					| "details" | "dialog" | "dir" | "div" | "dl" | "fieldset"
+					| "figcaption" | "figure" | "footer" | "header" | "hgroup"
+					| "main" | "menu" | "nav" | "ol" | "p" | "search" | "section"
+					| "summary" | "ul",
+				..
+			} => {
+				self.close_p_element_in_button_scope();
+				self.insert_html_element(token);
+			}
+
+			| HTMLTreeToken::StartTag { name, .. }
+				if HEADINGS.contains(&name) =>
+			{
+				self.close_p_element_in_button_scope();
+				if self.current_node_is(&HEADINGS) {
+					self.parse_error("unexpected-start-tag");
+					self.stack_of_open_elements.pop();
+				}
+				self.insert_html_element(token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "pre" | "listing",
+				..
+			} => {
+				self.close_p_element_in_button_scope();
+				self.insert_html_element(token);
+				self.ignore_line_feed = true;
+				self.frameset_ok = false;
+			}
+
+			| HTMLTreeToken::StartTag { name: "form", .. } => {
+				let has_template = self.stack_contains("template");
+				if self.form_element.is_some() && !has_template {
+					self.parse_error("unexpected-start-tag");
+					return;
+				}
+
+				self.close_p_element_in_button_scope();
+				let form = self.insert_html_element(token);
+				if !has_template {
+					self.form_element.replace(form);
+				}
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: name @ ("li" | "dd" | "dt"),
+				..
+			} => {
+				self.frameset_ok = false;
+
+				let closing: &[&str] =
+					if name == "li" { &["li"] } else { &["dd", "dt"] };
+
+				for node in
+					self.stack_of_open_elements.clone().into_iter().rev()
+				{
+					if self.is_html_element_named(node, closing) {
+						let node_name = self.tree[node]
+							.element_name()
+							.unwrap_or_default()
+							.to_owned();
+						self.generate_implied_end_tags(Some(&node_name));
+						if !self.current_node_is(&[&node_name]) {
+							self.parse_error("unexpected-start-tag");
+						}
+						self.pop_until(&[&node_name]);
+						break;
+					}
+
+					if self.is_special(node)
+						&& !self.is_html_element_named(
+							node,
+							&["address", "div", "p"],
+						) {
+						break;
+					}
+				}
+
+				self.close_p_element_in_button_scope();
+				self.insert_html_element(token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "plaintext", ..
+			} => {
+				self.close_p_element_in_button_scope();
+				self.insert_html_element(token);
+				self.switch_tokenizer_state(HTMLTokenizerState::Plaintext);
+			}
+
+			| HTMLTreeToken::StartTag { name: "button", .. } => {
+				if self.has_element_in_scope(&["button"], HTMLScope::Default) {
+					self.parse_error("unexpected-start-tag");
+					self.generate_implied_end_tags(None);
+					self.pop_until(&["button"]);
+				}
+				self.reconstruct_active_formatting_elements();
+				self.insert_html_element(token);
+				self.frameset_ok = false;
+			}
+
+			| HTMLTreeToken::EndTag {
+				name:
+					name @ ("address" | "article" | "aside" | "blockquote"
+					| "button" | "center" | "details" | "dialog" | "dir"
+					| "div" | "dl" | "fieldset" | "figcaption" | "figure"
+					| "footer" | "header" | "hgroup" | "listing" | "main"
+					| "menu" | "nav" | "ol" | "pre" | "search" | "section"
+					| "summary" | "ul"),
+			} => {
+				if !self.has_element_in_scope(&[name], HTMLScope::Default) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.generate_implied_end_tags(None);
+				if !self.current_node_is(&[name]) {
+					self.parse_error("unexpected-end-tag");
+				}
+				self.pop_until(&[name]);
+			}
+
+			| HTMLTreeToken::EndTag { name: "form" } => {
+				if !self.stack_contains("template") {
+					let node = self.form_element.take();
+					let Some(node) = node.filter(|node| {
+						self.has_node_in_scope(*node, HTMLScope::Default)
+					}) else {
+						self.parse_error("unexpected-end-tag");
+						return;
+					};
+
+					self.generate_implied_end_tags(None);
+					if self.current_node() != node {
+						self.parse_error("unexpected-end-tag");
+					}
+					self.remove_from_stack(node);
+				} else {
+					if !self.has_element_in_scope(&["form"], HTMLScope::Default)
+					{
+						self.parse_error("unexpected-end-tag");
+						return;
+					}
+					self.generate_implied_end_tags(None);
+					if !self.current_node_is(&["form"]) {
+						self.parse_error("unexpected-end-tag");
+					}
+					self.pop_until(&["form"]);
+				}
+			}
+
+			| HTMLTreeToken::EndTag { name: "p" } => {
+				if !self.has_element_in_scope(&["p"], HTMLScope::Button) {
+					self.parse_error("unexpected-end-tag");
+					self.insert_html_element_named("p");
+				}
+				self.close_p_element();
+			}
+
+			| HTMLTreeToken::EndTag { name: "li" } => {
+				if !self.has_element_in_scope(&["li"], HTMLScope::ListItem) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.generate_implied_end_tags(Some("li"));
+				if !self.current_node_is(&["li"]) {
+					self.parse_error("unexpected-end-tag");
+				}
+				self.pop_until(&["li"]);
+			}
+
+			| HTMLTreeToken::EndTag {
+				name: name @ ("dd" | "dt"),
+			} => {
+				if !self.has_element_in_scope(&[name], HTMLScope::Default) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.generate_implied_end_tags(Some(name));
+				if !self.current_node_is(&[name]) {
+					self.parse_error("unexpected-end-tag");
+				}
+				self.pop_until(&[name]);
+			}
+
+			| HTMLTreeToken::EndTag { name } if HEADINGS.contains(&name) => {
+				if !self.has_element_in_scope(&HEADINGS, HTMLScope::Default) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.generate_implied_end_tags(None);
+				if !self.current_node_is(&[name]) {
+					self.parse_error("unexpected-end-tag");
+				}
+				self.pop_until(&HEADINGS);
+			}
+
+			| HTMLTreeToken::StartTag { name: "a", .. } => {
+				if let Some(element) = self.active_formatting_element_named("a")
+				{
+					self.parse_error("unexpected-start-tag");
+					self.run_adoption_agency_algorithm("a");
+					self.remove_from_active_formatting_elements(element);
+					self.remove_from_stack(element);
+				}
+				self.reconstruct_active_formatting_elements();
+				let element = self.insert_html_element(token);
+				self.push_active_formatting_element(element);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name:
+					"b" | "big" | "code" | "em" | "font" | "i" | "s" | "small"
+					| "strike" | "strong" | "tt" | "u",
+				..
+			} => {
+				self.reconstruct_active_formatting_elements();
+				let element = self.insert_html_element(token);
+				self.push_active_formatting_element(element);
+			}
+
+			| HTMLTreeToken::StartTag { name: "nobr", .. } => {
+				self.reconstruct_active_formatting_elements();
+				if self.has_element_in_scope(&["nobr"], HTMLScope::Default) {
+					self.parse_error("unexpected-start-tag");
+					self.run_adoption_agency_algorithm("nobr");
+					self.reconstruct_active_formatting_elements();
+				}
+				let element = self.insert_html_element(token);
+				self.push_active_formatting_element(element);
+			}
+
+			| HTMLTreeToken::EndTag {
+				name:
+					name @ ("a" | "b" | "big" | "code" | "em" | "font" | "i"
+					| "nobr" | "s" | "small" | "strike" | "strong" | "tt"
+					| "u"),
+			} => {
+				self.run_adoption_agency_algorithm(name);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "applet" | "marquee" | "object",
+				..
+			} => {
+				self.reconstruct_active_formatting_elements();
+				self.insert_html_element(token);
+				self.push_active_formatting_marker();
+				self.frameset_ok = false;
+			}
+
+			| HTMLTreeToken::EndTag {
+				name: name @ ("applet" | "marquee" | "object"),
+			} => {
+				if !self.has_element_in_scope(&[name], HTMLScope::Default) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.generate_implied_end_tags(None);
+				if !self.current_node_is(&[name]) {
+					self.parse_error("unexpected-end-tag");
+				}
+				self.pop_until(&[name]);
+				self.clear_active_formatting_elements_up_to_last_marker();
+			}
+
+			| HTMLTreeToken::StartTag { name: "table", .. } => {
+				if self.tree.quirks_mode() != HTMLQuirksMode::Quirks {
+					self.close_p_element_in_button_scope();
+				}
+				self.insert_html_element(token);
+				self.frameset_ok = false;
+				self.insertion_mode = HTMLInsertionMode::InTable;
+			}
+
+			| HTMLTreeToken::EndTag { name: "br" } => {
+				self.parse_error("unexpected-end-tag");
+				self.insert_void_element(HTMLTreeToken::StartTag {
+					name: "br",
+					attributes: &[],
+					self_closing: false,
+				});
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "area" | "br" | "embed" | "img" | "keygen" | "wbr",
+				..
+			} => {
+				self.insert_void_element(token);
+			}
+
+			| HTMLTreeToken::StartTag { name: "input", .. } => {
+				let is_hidden = token
+					.attribute("type")
+					.is_some_and(|ty| ty.eq_ignore_ascii_case("hidden"));
+
+				self.reconstruct_active_formatting_elements();
+				self.insert_html_element(token);
+				self.stack_of_open_elements.pop();
+				if !is_hidden {
+					self.frameset_ok = false;
+				}
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "param" | "source" | "track",
+				..
+			} => {
+				self.insert_html_element(token);
+				self.stack_of_open_elements.pop();
+			}
+
+			| HTMLTreeToken::StartTag { name: "hr", .. } => {
+				self.close_p_element_in_button_scope();
+				self.insert_html_element(token);
+				self.stack_of_open_elements.pop();
+				self.frameset_ok = false;
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "image",
+				attributes,
+				self_closing,
+			} => {
+				self.parse_error("unexpected-start-tag");
+				self.reprocess(HTMLTreeToken::StartTag {
+					name: "img",
+					attributes,
+					self_closing,
+				});
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "textarea", ..
+			} => {
+				self.insert_html_element(token);
+				self.ignore_line_feed = true;
+				self.switch_tokenizer_state(HTMLTokenizerState::Rcdata);
+				self.original_insertion_mode = self.insertion_mode;
+				self.frameset_ok = false;
+				self.insertion_mode = HTMLInsertionMode::Text;
+			}
+
+			| HTMLTreeToken::StartTag { name: "xmp", .. } => {
+				self.close_p_element_in_button_scope();
+				self.reconstruct_active_formatting_elements();
+				self.frameset_ok = false;
+				self.parse_generic_text_element(
+					token,
+					HTMLTokenizerState::Rawtext,
+				);
+			}
+
+			| HTMLTreeToken::StartTag { name: "iframe", .. } => {
+				self.frameset_ok = false;
+				self.parse_generic_text_element(
+					token,
+					HTMLTokenizerState::Rawtext,
+				);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "noembed", ..
+			} => {
+				self.parse_generic_text_element(
+					token,
+					HTMLTokenizerState::Rawtext,
+				);
+			}
+
+			| HTMLTreeToken::StartTag { name: "select", .. } => {
+				self.reconstruct_active_formatting_elements();
+				self.insert_html_element(token);
+				self.frameset_ok = false;
+				self.insertion_mode = match self.insertion_mode {
+					| HTMLInsertionMode::InTable
+					| HTMLInsertionMode::InCaption
+					| HTMLInsertionMode::InTableBody
+					| HTMLInsertionMode::InRow
+					| HTMLInsertionMode::InCell => HTMLInsertionMode::InSelectInTable,
+					| _ => HTMLInsertionMode::InSelect,
+				};
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "optgroup" | "option",
+				..
+			} => {
+				if self.current_node_is(&["option"]) {
+					self.stack_of_open_elements.pop();
+				}
+				self.reconstruct_active_formatting_elements();
+				self.insert_html_element(token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "rb" | "rtc", ..
+			} => {
+				if self.has_element_in_scope(&["ruby"], HTMLScope::Default) {
+					self.generate_implied_end_tags(None);
+					if !self.current_node_is(&["ruby"]) {
+						self.parse_error("unexpected-start-tag");
+					}
+				}
+				self.insert_html_element(token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "rp" | "rt", ..
+			} => {
+				if self.has_element_in_scope(&["ruby"], HTMLScope::Default) {
+					self.generate_implied_end_tags(Some("rtc"));
+					if !self.current_node_is(&["ruby", "rtc"]) {
+						self.parse_error("unexpected-start-tag");
+					}
+				}
+				self.insert_html_element(token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "math",
+				self_closing,
+				..
+			} => {
+				self.reconstruct_active_formatting_elements();
+				self.insert_foreign_element(token, HTMLNamespace::MathML);
+				if self_closing {
+					self.stack_of_open_elements.pop();
+				}
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "svg",
+				self_closing,
+				..
+			} => {
+				self.reconstruct_active_formatting_elements();
+				self.insert_foreign_element(token, HTMLNamespace::Svg);
+				if self_closing {
+					self.stack_of_open_elements.pop();
+				}
+			}
+
+			| HTMLTreeToken::StartTag {
+				name:
+					"caption" | "col" | "colgroup" | "frame" | "head" | "tbody"
+					| "td" | "tfoot" | "th" | "thead" | "tr",
+				..
+			} => {
+				self.parse_error("unexpected-start-tag");
+			}
+
+			| HTMLTreeToken::StartTag { .. } => {
+				self.reconstruct_active_formatting_elements();
+				self.insert_html_element(token);
+			}
+
+			| HTMLTreeToken::EndTag { name } => {
+				self.handle_any_other_end_tag_in_body(name);
+			}
+		}
+	}
+
+	fn handle_text_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character(ch) => self.insert_character(ch),
+
+			| HTMLTreeToken::EndOfStream => {
+				self.parse_error("eof-in-text");
+				self.stack_of_open_elements.pop();
+				self.insertion_mode = self.original_insertion_mode;
+				self.reprocess(token);
+			}
+
+			// NOTE: les scripts ne sont pas exécutés.
+			| _ => {
+				self.stack_of_open_elements.pop();
+				self.insertion_mode = self.original_insertion_mode;
+			}
+		}
+	}
+}
@@ -0,0 +1,312 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use sky_lang::UnitCodePointExt;
+
+use crate::dom::{HTMLNamespace, HTMLQuirksMode};
+use crate::syntax::{HTMLInsertionMode, HTMLParser, HTMLTreeToken};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLParserDocumentRules
+{
+	fn handle_initial_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_before_html_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_after_body_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_after_after_body_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	);
+}
+
+// -------- //
+// Constant //
+// -------- //
+
+#[rustfmt::skip]
+const QUIRKS_PUBLIC_ID_PREFIXES: [&str; 55] = [
+	"+//silmaril//dtd html pro v0r11 19970101//",
+	"-//as//dtd html 3.0 aswedit + extensions//",
+	"-//advasoft ltd//dtd html 3.0 aswedit + extensions//",
+	"-//ietf//dtd html 2.0 level 1//",
+	"-//ietf//dtd html 2.0 level 2//",
+	"-//ietf//dtd html 2.0 strict level 1//",
+	"-//ietf//dtd html 2.0 strict level 2//",
+	"-//ietf//dtd html 2.0 strict//",
+	"-//ietf//dtd html 2.0//",
+	"-//ietf//dtd html 2.1e//",
+	"-//ietf//dtd html 3.0//",
+	"-//ietf//dtd html 3.2 final//",
+	"-//ietf//dtd html 3.2//",
+	"-//ietf//dtd html 3//",
+	"-//ietf//dtd html level 0//",
+	"-//ietf//dtd html level 1//",
+	"-//ietf//dtd html level 2//",
+	"-//ietf//dtd html level 3//",
+	"-//ietf//dtd html strict level 0//",
+	"-//ietf//dtd html strict level 1//",
+	"-//ietf//dtd html strict level 2//",
+	"-//ietf//dtd html strict level 3//",
+	"-//ietf//dtd html strict//",
+	"-//ietf//dtd html//",
+	"-//metrius//dtd metrius presentational//",
+	"-//microsoft//dtd internet explorer 2.0 html strict//",
+	"-//microsoft//dtd internet explorer 2.0 html//",
+	"-//microsoft//dtd internet explorer 2.0 tables//",
+	"-//microsoft//dtd internet explorer 3.0 html strict//",
+	"-//microsoft//dtd internet explorer 3.0 html//",
+	"-//microsoft//dtd internet explorer 3.0 tables//",
+	"-//netscape comm. corp.//dtd html//",
+	"-//netscape comm. corp.//dtd strict html//",
+	"-//o'reilly and associates//dtd html 2.0//",
+	"-//o'reilly and associates//dtd html extended 1.0//",
+	"-//o'reilly and associates//dtd html extended relaxed 1.0//",
+	"-//sq//dtd html 2.0 hotmetal + extensions//",
+	"-//softquad software//dtd hotmetal pro 6.0::19990601::extensions to html 4.0//",
+	"-//softquad//dtd hotmetal pro 4.0::19970916::extensions to html 4.0//",
+	"-//spyglass//dtd html 2.0 extended//",
+	"-//sun microsystems corp.//dtd hotjava html//",
+	"-//sun microsystems corp.//dtd hotjava strict html//",
+	"-//w3c//dtd html 3 1995-03-24//",
+	"-//w3c//dtd html 3.2 draft//",
+	"-//w3c//dtd html 3.2 final//",
+	"-//w3c//dtd html 3.2//",
+	"-//w3c//dtd html 3.2s draft//",
+	"-//w3c//dtd html 4.0 frameset//",
+	"-//w3c//dtd html 4.0 transitional//",
+	"-//w3c//dtd html experimental 19960712//",
+	"-//w3c//dtd html experimental 970421//",
+	"-//w3c//dtd w3 html//",
+	"-//w3o//dtd w3 html 3.0//",
+	"-//webtechs//dtd mozilla html 2.0//",
+	"-//webtechs//dtd mozilla html//",
+];
+
+const QUIRKS_PUBLIC_IDS: [&str; 3] = [
+	"-//w3o//dtd w3 html strict 3.0//en//",
+	"-/w3c/dtd html 4.0 transitional/en",
+	"html",
+];
+
+const QUIRKS_SYSTEM_ID: &str =
+	"http://www.ibm.com/data/dtd/v11/ibmxhtml1-transitional.dtd";
+
+const HTML401_PUBLIC_ID_PREFIXES: [&str; 2] = [
+	"-//w3c//dtd html 4.01 frameset//",
+	"-//w3c//dtd html 4.01 transitional//",
+];
+
+const LIMITED_QUIRKS_PUBLIC_ID_PREFIXES: [&str; 2] = [
+	"-//w3c//dtd xhtml 1.0 frameset//",
+	"-//w3c//dtd xhtml 1.0 transitional//",
+];
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Détermine le mode de rendu du document à partir de son DOCTYPE.
+fn quirks_mode_for_doctype(
+	name: Option<&str>,
+	public_id: Option<&str>,
+	system_id: Option<&str>,
+	force_quirks: bool,
+) -> HTMLQuirksMode
+{
+	let public = public_id.unwrap_or_default().to_ascii_lowercase();
+	let system = system_id.map(str::to_ascii_lowercase);
+	let starts_with_any =
+		|prefixes: &[&str]| prefixes.iter().any(|p| public.starts_with(p));
+
+	if force_quirks
+		|| name != Some("html")
+		|| QUIRKS_PUBLIC_IDS.contains(&public.as_str())
+		|| system.as_deref() == Some(QUIRKS_SYSTEM_ID)
+		|| starts_with_any(&QUIRKS_PUBLIC_ID_PREFIXES)
+		|| (system.is_none() && starts_with_any(&HTML401_PUBLIC_ID_PREFIXES))
+	{
+		return HTMLQuirksMode::Quirks;
+	}
+
+	if starts_with_any(&LIMITED_QUIRKS_PUBLIC_ID_PREFIXES)
+		|| (system.is_some() && starts_with_any(&HTML401_PUBLIC_ID_PREFIXES))
+	{
+		return HTMLQuirksMode::LimitedQuirks;
+	}
+
+	HTMLQuirksMode::NoQuirks
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLParserDocumentRules for HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_initial_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| _ if token.is_whitespace() => {}
+
+			| HTMLTreeToken::Comment(data) => {
+				self.insert_comment(data, Some(self.tree.document()));
+			}
+
+			| HTMLTreeToken::Doctype {
+				name,
+				public_id,
+				system_id,
+				force_quirks,
+			} => {
+				let is_valid = name == Some("html")
+					&& public_id.is_none()
+					&& system_id.is_none_or(|id| id == "about:legacy-compat");
+				if !is_valid {
+					self.parse_error("unexpected-doctype");
+				}
+
+				let doctype = self.tree.create_doctype(
+					name.unwrap_or_default(),
+					public_id.unwrap_or_default(),
+					system_id.unwrap_or_default(),
+				);
+				self.tree.append(self.tree.document(), doctype);
+				self.tree.set_quirks_mode(quirks_mode_for_doctype(
+					name,
+					public_id,
+					system_id,
+					force_quirks,
+				));
+				self.insertion_mode = HTMLInsertionMode::BeforeHtml;
+			}
+
+			| _ => {
+				self.parse_error("missing-doctype");
+				self.tree.set_quirks_mode(HTMLQuirksMode::Quirks);
+				self.insertion_mode = HTMLInsertionMode::BeforeHtml;
+				self.reprocess(token);
+			}
+		}
+	}
+
+	fn handle_before_html_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::Comment(data) => {
+				self.insert_comment(data, Some(self.tree.document()));
+			}
+
+			| _ if token.is_whitespace() => {}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				let attributes = Self::attributes_for_token(token);
+				let html = self.tree.create_element(
+					HTMLNamespace::Html,
+					"html",
+					attributes,
+				);
+				self.tree.append(self.tree.document(), html);
+				self.stack_of_open_elements.push(html);
+				self.insertion_mode = HTMLInsertionMode::BeforeHead;
+			}
+
+			| HTMLTreeToken::EndTag { name }
+				if !matches!(name, "head" | "body" | "html" | "br") =>
+			{
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| _ => {
+				let html = self.tree.create_element(
+					HTMLNamespace::Html,
+					"html",
+					Vec::new(),
+				);
+				self.tree.append(self.tree.document(), html);
+				self.stack_of_open_elements.push(html);
+				self.insertion_mode = HTMLInsertionMode::BeforeHead;
+				self.reprocess(token);
+			}
+		}
+	}
+
+	fn handle_after_body_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| _ if token.is_whitespace() => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::Comment(data) => {
+				let html = self.stack_of_open_elements[0];
+				self.insert_comment(data, Some(html));
+			}
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::EndTag { name: "html" } => {
+				self.insertion_mode = HTMLInsertionMode::AfterAfterBody;
+			}
+
+			| HTMLTreeToken::EndOfStream => self.stop_parsing(),
+
+			| _ => {
+				self.parse_error("unexpected-token-after-body");
+				self.insertion_mode = HTMLInsertionMode::InBody;
+				self.reprocess(token);
+			}
+		}
+	}
+
+	fn handle_after_after_body_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	)
+	{
+		match token {
+			| HTMLTreeToken::Comment(data) => {
+				self.insert_comment(data, Some(self.tree.document()));
+			}
+
+			| HTMLTreeToken::Doctype { .. }
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+			| _ if token.is_whitespace() => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::EndOfStream => self.stop_parsing(),
+
+			| _ => {
+				self.parse_error("unexpected-token-after-body");
+				self.insertion_mode = HTMLInsertionMode::InBody;
+				self.reprocess(token);
+			}
+		}
+	}
+}
@@ -0,0 +1,347 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use sky_lang::UnitCodePointExt;
+
+use crate::dom::{HTMLAttribute, HTMLNamespace, HTMLNodeId};
+use crate::syntax::{HTMLParser, HTMLTreeToken};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLParserForeignContentRules
+{
+	/// 13.2.6.5 The rules for parsing tokens in foreign content
+	fn handle_foreign_content(&mut self, token: HTMLTreeToken<'_>);
+
+	/// Insère un élément étranger (MathML, SVG) pour la balise de début
+	/// donnée, après ajustement de son nom et de ses attributs.
+	fn insert_foreign_element(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+		namespace: HTMLNamespace,
+	) -> HTMLNodeId;
+}
+
+// -------- //
+// Constant //
+// -------- //
+
+#[rustfmt::skip]
+const SVG_TAG_NAME_ADJUSTMENTS: [(&str, &str); 37] = [
+	("altglyph", "altGlyph"),
+	("altglyphdef", "altGlyphDef"),
+	("altglyphitem", "altGlyphItem"),
+	("animatecolor", "animateColor"),
+	("animatemotion", "animateMotion"),
+	("animatetransform", "animateTransform"),
+	("clippath", "clipPath"),
+	("feblend", "feBlend"),
+	("fecolormatrix", "feColorMatrix"),
+	("fecomponenttransfer", "feComponentTransfer"),
+	("fecomposite", "feComposite"),
+	("feconvolvematrix", "feConvolveMatrix"),
+	("fediffuselighting", "feDiffuseLighting"),
+	("fedisplacementmap", "feDisplacementMap"),
+	("fedistantlight", "feDistantLight"),
+	("fedropshadow", "feDropShadow"),
+	("feflood", "feFlood"),
+	("fefunca", "feFuncA"),
+	("fefuncb", "feFuncB"),
+	("fefuncg", "feFuncG"),
+	("fefuncr", "feFuncR"),
+	("fegaussianblur", "feGaussianBlur"),
+	("feimage", "feImage"),
+	("femerge", "feMerge"),
+	("femergenode", "feMergeNode"),
+	("femorphology", "feMorphology"),
+	("feoffset", "feOffset"),
+	("fepointlight", "fePointLight"),
+	("fespecularlighting", "feSpecularLighting"),
+	("fespotlight", "feSpotLight"),
+	("fetile", "feTile"),
+	("feturbulence", "feTurbulence"),
+	("foreignobject", "foreignObject"),
+	("glyphref", "glyphRef"),
+	("lineargradient", "linearGradient"),
+	("radialgradient", "radialGradient"),
+	("textpath", "textPath"),
+];
+
+#[rustfmt::skip]
+const SVG_ATTRIBUTE_ADJUSTMENTS: [(&str, &str); 58] = [
+	("attributename", "attributeName"),
+	("attributetype", "attributeType"),
+	("basefrequency", "baseFrequency"),
+	("baseprofile", "baseProfile"),
+	("calcmode", "calcMode"),
+	("clippathunits", "clipPathUnits"),
+	("diffuseconstant", "diffuseConstant"),
+	("edgemode", "edgeMode"),
+	("filterunits", "filterUnits"),
+	("glyphref", "glyphRef"),
+	("gradienttransform", "gradientTransform"),
+	("gradientunits", "gradientUnits"),
+	("kernelmatrix", "kernelMatrix"),
+	("kernelunitlength", "kernelUnitLength"),
+	("keypoints", "keyPoints"),
+	("keysplines", "keySplines"),
+	("keytimes", "keyTimes"),
+	("lengthadjust", "lengthAdjust"),
+	("limitingconeangle", "limitingConeAngle"),
+	("markerheight", "markerHeight"),
+	("markerunits", "markerUnits"),
+	("markerwidth", "markerWidth"),
+	("maskcontentunits", "maskContentUnits"),
+	("maskunits", "maskUnits"),
+	("numoctaves", "numOctaves"),
+	("pathlength", "pathLength"),
+	("patterncontentunits", "patternContentUnits"),
+	("patterntransform", "patternTransform"),
+	("patternunits", "patternUnits"),
+	("pointsatx", "pointsAtX"),
+	("pointsaty", "pointsAtY"),
+	("pointsatz", "pointsAtZ"),
+	("preservealpha", "preserveAlpha"),
+	("preserveaspectratio", "preserveAspectRatio"),
+	("primitiveunits", "primitiveUnits"),
+	("refx", "refX"),
+	("refy", "refY"),
+	("repeatcount", "repeatCount"),
+	("repeatdur", "repeatDur"),
+	("requiredextensions", "requiredExtensions"),
+	("requiredfeatures", "requiredFeatures"),
+	("specularconstant", "specularConstant"),
+	("specularexponent", "specularExponent"),
+	("spreadmethod", "spreadMethod"),
+	("startoffset", "startOffset"),
+	("stddeviation", "stdDeviation"),
+	("stitchtiles", "stitchTiles"),
+	("surfacescale", "surfaceScale"),
+	("systemlanguage", "systemLanguage"),
+	("tablevalues", "tableValues"),
+	("targetx", "targetX"),
+	("targety", "targetY"),
+	("textlength", "textLength"),
+	("viewbox", "viewBox"),
+	("viewtarget", "viewTarget"),
+	("xchannelselector", "xChannelSelector"),
+	("ychannelselector", "yChannelSelector"),
+	("zoomandpan", "zoomAndPan"),
+];
+
+#[rustfmt::skip]
+const FOREIGN_ATTRIBUTE_ADJUSTMENTS: [(&str, HTMLNamespace, &str); 11] = [
+	("xlink:actuate", HTMLNamespace::XLink, "actuate"),
+	("xlink:arcrole", HTMLNamespace::XLink, "arcrole"),
+	("xlink:href", HTMLNamespace::XLink, "href"),
+	("xlink:role", HTMLNamespace::XLink, "role"),
+	("xlink:show", HTMLNamespace::XLink, "show"),
+	("xlink:title", HTMLNamespace::XLink, "title"),
+	("xlink:type", HTMLNamespace::XLink, "type"),
+	("xml:lang", HTMLNamespace::Xml, "lang"),
+	("xml:space", HTMLNamespace::Xml, "space"),
+	("xmlns", HTMLNamespace::Xmlns, "xmlns"),
+	("xmlns:xlink", HTMLNamespace::Xmlns, "xlink"),
+];
+
+/// Balises qui font sortir du contenu étranger, ainsi que `<font>` lorsqu'elle
+/// a un attribut `color`, `face` ou `size`.
+#[rustfmt::skip]
+const BREAKOUT_TAG_NAMES: [&str; 44] = [
+	"b", "big", "blockquote", "body", "br", "center", "code", "dd", "div",
+	"dl", "dt", "em", "embed", "h1", "h2", "h3", "h4", "h5", "h6", "head",
+	"hr", "i", "img", "li", "listing", "menu", "meta", "nobr", "ol", "p",
+	"pre", "ruby", "s", "small", "span", "strong", "strike", "sub", "sup",
+	"table", "tt", "u", "ul", "var",
+];
+
+// -------- //
+// Fonction //
+// -------- //
+
+fn adjust<'a>(table: &[(&str, &'a str)], name: &'a str) -> &'a str
+{
+	table
+		.iter()
+		.find(|(from, _)| *from == name)
+		.map_or(name, |(_, to)| to)
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<Input: Iterator> HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn is_breakout_start_tag(token: HTMLTreeToken<'_>) -> bool
+	{
+		match token {
+			| HTMLTreeToken::StartTag { name: "font", .. } => {
+				["color", "face", "size"]
+					.iter()
+					.any(|attr| token.attribute(attr).is_some())
+			}
+			| HTMLTreeToken::StartTag { name, .. } => {
+				BREAKOUT_TAG_NAMES.contains(&name)
+			}
+			| _ => false,
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLParserForeignContentRules for HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_foreign_content(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character('\0') => {
+				self.parse_error("unexpected-null-character");
+				self.insert_character(char::REPLACEMENT_CHARACTER);
+			}
+
+			| HTMLTreeToken::Character(ch) => {
+				self.insert_character(ch);
+				if !token.is_whitespace() {
+					self.frameset_ok = false;
+				}
+			}
+
+			| HTMLTreeToken::Comment(data) => self.insert_comment(data, None),
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::EndTag { name: "br" | "p" } => {
+				self.parse_error("unexpected-html-element-in-foreign-content");
+				while !self.is_html_element(self.current_node())
+					&& !self
+						.is_mathml_text_integration_point(self.current_node())
+					&& !self.is_html_integration_point(self.current_node())
+				{
+					self.stack_of_open_elements.pop();
+				}
+				self.reprocess(token);
+			}
+			| _ if Self::is_breakout_start_tag(token) => {
+				self.parse_error("unexpected-html-element-in-foreign-content");
+				while !self.is_html_element(self.current_node())
+					&& !self
+						.is_mathml_text_integration_point(self.current_node())
+					&& !self.is_html_integration_point(self.current_node())
+				{
+					self.stack_of_open_elements.pop();
+				}
+				self.reprocess(token);
+			}
+
+			| HTMLTreeToken::StartTag { self_closing, .. } => {
+				let namespace = self
+					.adjusted_current_node()
+					.and_then(|node| self.node(node).namespace())
+					.unwrap_or_default();
+				self.insert_foreign_element(token, namespace);
+				if self_closing {
+					self.stack_of_open_elements.pop();
+				}
+			}
+
+			| HTMLTreeToken::EndTag { name } => {
+				let first = self.current_node();
+				let matches_name = |this: &Self, node: HTMLNodeId| {
+					this.node(node)
+						.element_name()
+						.is_some_and(|n| n.eq_ignore_ascii_case(name))
+				};
+
+				if !matches_name(self, first) {
+					self.parse_error("unexpected-end-tag");
+				}
+
+				for index in (1..self.stack_of_open_elements.len()).rev() {
+					let node = self.stack_of_open_elements[index];
+
+					if matches_name(self, node) {
+						self.stack_of_open_elements.truncate(index);
+						return;
+					}
+
+					let previous = self.stack_of_open_elements[index - 1];
+					if self.is_html_element(previous) {
+						self.reprocess(token);
+						return;
+					}
+				}
+			}
+
+			| HTMLTreeToken::EndOfStream => self.reprocess(token),
+		}
+	}
+
+	fn insert_foreign_element(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+		namespace: HTMLNamespace,
+	) -> HTMLNodeId
+	{
+		let HTMLTreeToken::StartTag { name, .. } = token else {
+			unreachable!("un élément est créé pour une balise de début")
+		};
+
+		let name = match namespace {
+			| HTMLNamespace::Svg => adjust(&SVG_TAG_NAME_ADJUSTMENTS, name),
+			| _ => name,
+		};
+
+		let attributes = Self::attributes_for_token(token)
+			.into_iter()
+			.map(|mut attribute| {
+				match namespace {
+					| HTMLNamespace::MathML
+						if attribute.name == "definitionurl" =>
+					{
+						attribute.name = "definitionURL".to_owned();
+					}
+					| HTMLNamespace::Svg => {
+						attribute.name =
+							adjust(&SVG_ATTRIBUTE_ADJUSTMENTS, &attribute.name)
+								.to_owned();
+					}
+					| _ => {}
+				}
+
+				if let Some((_, ns, local)) = FOREIGN_ATTRIBUTE_ADJUSTMENTS
+					.iter()
+					.find(|(from, ..)| *from == attribute.name)
+				{
+					attribute = HTMLAttribute {
+						namespace: Some(*ns),
+						name: (*local).to_owned(),
+						value: attribute.value,
+					};
+				}
+
+				attribute
+			})
+			.collect();
+
+		self.insert_element(namespace, name, attributes)
+	}
+}
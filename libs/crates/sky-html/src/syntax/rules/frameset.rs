@@ -0,0 +1,162 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use sky_lang::UnitCodePointExt;
+
+use crate::dom::HTMLNodeId;
+use crate::syntax::{HTMLInsertionMode, HTMLParser, HTMLTreeToken};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLParserFramesetRules
+{
+	fn handle_in_frameset_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_after_frameset_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	);
+
+	fn handle_after_after_frameset_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLParserFramesetRules for HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_in_frameset_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character(ch) if token.is_whitespace() => {
+				self.insert_character(ch);
+			}
+
+			| HTMLTreeToken::Comment(data) => self.insert_comment(data, None),
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "frameset", ..
+			} => {
+				self.insert_html_element(token);
+			}
+
+			| HTMLTreeToken::EndTag { name: "frameset" } => {
+				if self.stack_of_open_elements.len() == 1 {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.stack_of_open_elements.pop();
+				if !self.current_node_is(&["frameset"]) {
+					self.insertion_mode = HTMLInsertionMode::AfterFrameset;
+				}
+			}
+
+			| HTMLTreeToken::StartTag { name: "frame", .. } => {
+				self.insert_html_element(token);
+				self.stack_of_open_elements.pop();
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "noframes", ..
+			} => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+
+			| HTMLTreeToken::EndOfStream => {
+				if self.stack_of_open_elements.len() != 1 {
+					self.parse_error("eof-in-frameset");
+				}
+				self.stop_parsing();
+			}
+
+			| _ => self.parse_error("unexpected-token-in-frameset"),
+		}
+	}
+
+	fn handle_after_frameset_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character(ch) if token.is_whitespace() => {
+				self.insert_character(ch);
+			}
+
+			| HTMLTreeToken::Comment(data) => self.insert_comment(data, None),
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::EndTag { name: "html" } => {
+				self.insertion_mode = HTMLInsertionMode::AfterAfterFrameset;
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "noframes", ..
+			} => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+
+			| HTMLTreeToken::EndOfStream => self.stop_parsing(),
+
+			| _ => self.parse_error("unexpected-token-after-frameset"),
+		}
+	}
+
+	fn handle_after_after_frameset_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	)
+	{
+		match token {
+			| HTMLTreeToken::Comment(data) => {
+				self.insert_comment(data, Some(HTMLNodeId::DOCUMENT));
+			}
+
+			| HTMLTreeToken::Doctype { .. }
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::Character(_) if token.is_whitespace() => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "noframes", ..
+			} => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+
+			| HTMLTreeToken::EndOfStream => self.stop_parsing(),
+
+			| _ => self.parse_error("unexpected-token-after-frameset"),
+		}
+	}
+}
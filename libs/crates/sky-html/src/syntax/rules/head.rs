@@ -0,0 +1,311 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use sky_lang::UnitCodePointExt;
+
+use crate::dom::HTMLNamespace;
+use crate::lexical::HTMLTokenizerState;
+use crate::syntax::{HTMLInsertionMode, HTMLParser, HTMLTreeToken};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLParserHeadRules
+{
+	fn handle_before_head_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_in_head_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_in_head_noscript_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	);
+
+	fn handle_after_head_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLParserHeadRules for HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_before_head_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| _ if token.is_whitespace() => {}
+
+			| HTMLTreeToken::Comment(data) => self.insert_comment(data, None),
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::StartTag { name: "head", .. } => {
+				let head = self.insert_html_element(token);
+				self.head_element.replace(head);
+				self.insertion_mode = HTMLInsertionMode::InHead;
+			}
+
+			| HTMLTreeToken::EndTag { name }
+				if !matches!(name, "head" | "body" | "html" | "br") =>
+			{
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| _ => {
+				let head = self.insert_html_element_named("head");
+				self.head_element.replace(head);
+				self.insertion_mode = HTMLInsertionMode::InHead;
+				self.reprocess(token);
+			}
+		}
+	}
+
+	fn handle_in_head_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character(ch) if token.is_whitespace() => {
+				self.insert_character(ch);
+			}
+
+			| HTMLTreeToken::Comment(data) => self.insert_comment(data, None),
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "base" | "basefont" | "bgsound" | "link" | "meta",
+				..
+			} => {
+				self.insert_html_element(token);
+				self.stack_of_open_elements.pop();
+			}
+
+			| HTMLTreeToken::StartTag { name: "title", .. } => {
+				self.parse_generic_text_element(
+					token,
+					HTMLTokenizerState::Rcdata,
+				);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "noframes" | "style",
+				..
+			} => {
+				self.parse_generic_text_element(
+					token,
+					HTMLTokenizerState::Rawtext,
+				);
+			}
+
+			// NOTE: le scripting n'est pas supporté, le contenu de
+			// `<noscript>` est analysé comme du balisage.
+			| HTMLTreeToken::StartTag {
+				name: "noscript", ..
+			} => {
+				self.insert_html_element(token);
+				self.insertion_mode = HTMLInsertionMode::InHeadNoscript;
+			}
+
+			| HTMLTreeToken::StartTag { name: "script", .. } => {
+				let place = self.appropriate_place_for_inserting_node(None);
+				let attributes = Self::attributes_for_token(token);
+				let script = self.tree.create_element(
+					HTMLNamespace::Html,
+					"script",
+					attributes,
+				);
+				self.insert_node_at(place, script);
+				self.stack_of_open_elements.push(script);
+				self.switch_tokenizer_state(HTMLTokenizerState::ScriptData);
+				self.original_insertion_mode = self.insertion_mode;
+				self.insertion_mode = HTMLInsertionMode::Text;
+			}
+
+			| HTMLTreeToken::EndTag { name: "head" } => {
+				self.stack_of_open_elements.pop();
+				self.insertion_mode = HTMLInsertionMode::AfterHead;
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "template", ..
+			} => {
+				self.insert_html_element(token);
+				self.push_active_formatting_marker();
+				self.frameset_ok = false;
+				self.insertion_mode = HTMLInsertionMode::InTemplate;
+				self.template_insertion_modes
+					.push(HTMLInsertionMode::InTemplate);
+			}
+
+			| HTMLTreeToken::EndTag { name: "template" } => {
+				if !self.stack_contains("template") {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+
+				self.generate_all_implied_end_tags_thoroughly();
+				if !self.current_node_is(&["template"]) {
+					self.parse_error("unexpected-end-tag");
+				}
+				self.pop_until(&["template"]);
+				self.clear_active_formatting_elements_up_to_last_marker();
+				self.template_insertion_modes.pop();
+				self.reset_insertion_mode_appropriately();
+			}
+
+			| HTMLTreeToken::StartTag { name: "head", .. } => {
+				self.parse_error("unexpected-start-tag");
+			}
+
+			| HTMLTreeToken::EndTag { name }
+				if !matches!(name, "body" | "html" | "br") =>
+			{
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| _ => {
+				self.stack_of_open_elements.pop();
+				self.insertion_mode = HTMLInsertionMode::AfterHead;
+				self.reprocess(token);
+			}
+		}
+	}
+
+	fn handle_in_head_noscript_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	)
+	{
+		match token {
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::EndTag { name: "noscript" } => {
+				self.stack_of_open_elements.pop();
+				self.insertion_mode = HTMLInsertionMode::InHead;
+			}
+
+			| HTMLTreeToken::Comment(_)
+			| HTMLTreeToken::StartTag {
+				name:
+					"basefont" | "bgsound" | "link" | "meta" | "noframes" | "style",
+				..
+			} => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+			| _ if token.is_whitespace() => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "head" | "noscript",
+				..
+			} => {
+				self.parse_error("unexpected-start-tag");
+			}
+
+			| HTMLTreeToken::EndTag { name } if name != "br" => {
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| _ => {
+				self.parse_error("unexpected-token-in-noscript");
+				self.stack_of_open_elements.pop();
+				self.insertion_mode = HTMLInsertionMode::InHead;
+				self.reprocess(token);
+			}
+		}
+	}
+
+	fn handle_after_head_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character(ch) if token.is_whitespace() => {
+				self.insert_character(ch);
+			}
+
+			| HTMLTreeToken::Comment(data) => self.insert_comment(data, None),
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::StartTag { name: "body", .. } => {
+				self.insert_html_element(token);
+				self.frameset_ok = false;
+				self.insertion_mode = HTMLInsertionMode::InBody;
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "frameset", ..
+			} => {
+				self.insert_html_element(token);
+				self.insertion_mode = HTMLInsertionMode::InFrameset;
+			}
+
+			| HTMLTreeToken::StartTag {
+				name:
+					"base" | "basefont" | "bgsound" | "link" | "meta" | "noframes"
+					| "script" | "style" | "template" | "title",
+				..
+			} => {
+				self.parse_error("unexpected-start-tag-after-head");
+
+				let head = self.head_element.expect("l'élément head");
+				self.stack_of_open_elements.push(head);
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+				self.remove_from_stack(head);
+			}
+
+			| HTMLTreeToken::EndTag { name: "template" } => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+
+			| HTMLTreeToken::StartTag { name: "head", .. } => {
+				self.parse_error("unexpected-start-tag");
+			}
+
+			| HTMLTreeToken::EndTag { name }
+				if !matches!(name, "body" | "html" | "br") =>
+			{
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| _ => {
+				self.insert_html_element_named("body");
+				self.insertion_mode = HTMLInsertionMode::InBody;
+				self.reprocess(token);
+			}
+		}
+	}
+}
@@ -0,0 +1,202 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use sky_lang::UnitCodePointExt;
+
+use crate::syntax::parser::HTMLScope;
+use crate::syntax::{HTMLInsertionMode, HTMLParser, HTMLTreeToken};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLParserSelectRules
+{
+	fn handle_in_select_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_in_select_in_table_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	);
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<Input: Iterator> HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	/// Ferme l'élément `<select>` et réinitialise le mode d'insertion.
+	/// Retourne `false` si aucun `<select>` n'est dans la portée select.
+	fn close_select(&mut self) -> bool
+	{
+		if !self.has_element_in_scope(&["select"], HTMLScope::Select) {
+			return false;
+		}
+		self.pop_until(&["select"]);
+		self.reset_insertion_mode_appropriately();
+		true
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLParserSelectRules for HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_in_select_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character('\0') => {
+				self.parse_error("unexpected-null-character");
+			}
+
+			| HTMLTreeToken::Character(ch) => self.insert_character(ch),
+
+			| HTMLTreeToken::Comment(data) => self.insert_comment(data, None),
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::StartTag { name: "option", .. } => {
+				if self.current_node_is(&["option"]) {
+					self.stack_of_open_elements.pop();
+				}
+				self.insert_html_element(token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "optgroup", ..
+			} => {
+				if self.current_node_is(&["option"]) {
+					self.stack_of_open_elements.pop();
+				}
+				if self.current_node_is(&["optgroup"]) {
+					self.stack_of_open_elements.pop();
+				}
+				self.insert_html_element(token);
+			}
+
+			| HTMLTreeToken::StartTag { name: "hr", .. } => {
+				if self.current_node_is(&["option"]) {
+					self.stack_of_open_elements.pop();
+				}
+				if self.current_node_is(&["optgroup"]) {
+					self.stack_of_open_elements.pop();
+				}
+				self.insert_html_element(token);
+				self.stack_of_open_elements.pop();
+			}
+
+			| HTMLTreeToken::EndTag { name: "optgroup" } => {
+				let len = self.stack_of_open_elements.len();
+				if self.current_node_is(&["option"])
+					&& len >= 2 && self.is_html_element_named(
+					self.stack_of_open_elements[len - 2],
+					&["optgroup"],
+				) {
+					self.stack_of_open_elements.pop();
+				}
+				if self.current_node_is(&["optgroup"]) {
+					self.stack_of_open_elements.pop();
+				} else {
+					self.parse_error("unexpected-end-tag");
+				}
+			}
+
+			| HTMLTreeToken::EndTag { name: "option" } => {
+				if self.current_node_is(&["option"]) {
+					self.stack_of_open_elements.pop();
+				} else {
+					self.parse_error("unexpected-end-tag");
+				}
+			}
+
+			| HTMLTreeToken::EndTag { name: "select" } => {
+				if !self.close_select() {
+					self.parse_error("unexpected-end-tag");
+				}
+			}
+
+			| HTMLTreeToken::StartTag { name: "select", .. } => {
+				self.parse_error("unexpected-select-in-select");
+				self.close_select();
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "input" | "keygen" | "textarea",
+				..
+			} => {
+				self.parse_error("unexpected-start-tag-in-select");
+				if self.close_select() {
+					self.reprocess(token);
+				}
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "script" | "template",
+				..
+			}
+			| HTMLTreeToken::EndTag { name: "template" } => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+
+			| HTMLTreeToken::EndOfStream => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| _ => self.parse_error("unexpected-token-in-select"),
+		}
+	}
+
+	fn handle_in_select_in_table_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	)
+	{
+		match token {
+			| HTMLTreeToken::StartTag {
+				name:
+					"caption" | "table" | "tbody" | "tfoot" | "thead" | "tr" | "td"
+					| "th",
+				..
+			} => {
+				self.parse_error("unexpected-table-element-in-select");
+				self.close_select();
+				self.reprocess(token);
+			}
+
+			| HTMLTreeToken::EndTag {
+				name:
+					name @ ("caption" | "table" | "tbody" | "tfoot" | "thead" | "tr"
+					| "td" | "th"),
+			} => {
+				self.parse_error("unexpected-table-element-in-select");
+				if !self.has_element_in_scope(&[name], HTMLScope::Table) {
+					return;
+				}
+				self.close_select();
+				self.reprocess(token);
+			}
+
+			| _ => self.handle_in_select_insertion_mode(token),
+		}
+	}
+}
@@ -0,0 +1,548 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use sky_lang::UnitCodePointExt;
+
+use crate::syntax::parser::HTMLScope;
+use crate::syntax::{HTMLInsertionMode, HTMLParser, HTMLTreeToken};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLParserTableRules
+{
+	fn handle_in_table_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_in_table_text_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_in_caption_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_in_column_group_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	);
+
+	fn handle_in_table_body_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_in_row_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+
+	fn handle_in_cell_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+}
+
+// -------- //
+// Constant //
+// -------- //
+
+const TABLE_CONTEXT: [&str; 3] = ["table", "template", "html"];
+
+const TABLE_BODY_CONTEXT: [&str; 5] =
+	["tbody", "tfoot", "thead", "template", "html"];
+
+const TABLE_ROW_CONTEXT: [&str; 3] = ["tr", "template", "html"];
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<Input: Iterator> HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	/// Règles "anything else" du mode d'insertion "in table": le jeton est
+	/// traité selon les règles "in body", avec le foster parenting.
+	fn handle_anything_else_in_table(&mut self, token: HTMLTreeToken<'_>)
+	{
+		self.parse_error("foster-parenting");
+		self.foster_parenting = true;
+		self.process_using_rules(HTMLInsertionMode::InBody, token);
+		self.foster_parenting = false;
+	}
+
+	/// Ferme l'élément `<table>` et réinitialise le mode d'insertion.
+	/// Retourne `false` si aucun `<table>` n'est dans la portée tableau.
+	fn close_table(&mut self) -> bool
+	{
+		if !self.has_element_in_scope(&["table"], HTMLScope::Table) {
+			self.parse_error("unexpected-end-tag");
+			return false;
+		}
+		self.pop_until(&["table"]);
+		self.reset_insertion_mode_appropriately();
+		true
+	}
+
+	/// Ferme l'élément `<caption>`. Retourne `false` si aucun `<caption>`
+	/// n'est dans la portée tableau.
+	fn close_caption(&mut self) -> bool
+	{
+		if !self.has_element_in_scope(&["caption"], HTMLScope::Table) {
+			self.parse_error("unexpected-end-tag");
+			return false;
+		}
+		self.generate_implied_end_tags(None);
+		if !self.current_node_is(&["caption"]) {
+			self.parse_error("unexpected-end-tag");
+		}
+		self.pop_until(&["caption"]);
+		self.clear_active_formatting_elements_up_to_last_marker();
+		self.insertion_mode = HTMLInsertionMode::InTable;
+		true
+	}
+
+	/// Ferme l'élément `<tr>`. Retourne `false` si aucun `<tr>` n'est dans la
+	/// portée tableau.
+	fn close_row(&mut self) -> bool
+	{
+		if !self.has_element_in_scope(&["tr"], HTMLScope::Table) {
+			self.parse_error("unexpected-end-tag");
+			return false;
+		}
+		self.clear_stack_back_to(&TABLE_ROW_CONTEXT);
+		self.stack_of_open_elements.pop();
+		self.insertion_mode = HTMLInsertionMode::InTableBody;
+		true
+	}
+
+	/// Ferme la cellule (`<td>` ou `<th>`) courante.
+	fn close_cell(&mut self)
+	{
+		self.generate_implied_end_tags(None);
+		if !self.current_node_is(&["td", "th"]) {
+			self.parse_error("unexpected-cell-end");
+		}
+		self.pop_until(&["td", "th"]);
+		self.clear_active_formatting_elements_up_to_last_marker();
+		self.insertion_mode = HTMLInsertionMode::InRow;
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLParserTableRules for HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_in_table_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character(_)
+				if self.current_node_is(&[
+					"table", "tbody", "template", "tfoot", "thead", "tr",
+				]) =>
+			{
+				self.pending_table_character_tokens.clear();
+				self.original_insertion_mode = self.insertion_mode;
+				self.insertion_mode = HTMLInsertionMode::InTableText;
+				self.reprocess(token);
+			}
+
+			| HTMLTreeToken::Comment(data) => self.insert_comment(data, None),
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "caption", ..
+			} => {
+				self.clear_stack_back_to(&TABLE_CONTEXT);
+				self.push_active_formatting_marker();
+				self.insert_html_element(token);
+				self.insertion_mode = HTMLInsertionMode::InCaption;
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "colgroup", ..
+			} => {
+				self.clear_stack_back_to(&TABLE_CONTEXT);
+				self.insert_html_element(token);
+				self.insertion_mode = HTMLInsertionMode::InColumnGroup;
+			}
+
+			| HTMLTreeToken::StartTag { name: "col", .. } => {
+				self.clear_stack_back_to(&TABLE_CONTEXT);
+				self.insert_html_element_named("colgroup");
+				self.insertion_mode = HTMLInsertionMode::InColumnGroup;
+				self.reprocess(token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "tbody" | "tfoot" | "thead",
+				..
+			} => {
+				self.clear_stack_back_to(&TABLE_CONTEXT);
+				self.insert_html_element(token);
+				self.insertion_mode = HTMLInsertionMode::InTableBody;
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "td" | "th" | "tr",
+				..
+			} => {
+				self.clear_stack_back_to(&TABLE_CONTEXT);
+				self.insert_html_element_named("tbody");
+				self.insertion_mode = HTMLInsertionMode::InTableBody;
+				self.reprocess(token);
+			}
+
+			| HTMLTreeToken::StartTag { name: "table", .. } => {
+				self.parse_error("unexpected-start-tag");
+				if self.close_table() {
+					self.reprocess(token);
+				}
+			}
+
+			| HTMLTreeToken::EndTag { name: "table" } => {
+				self.close_table();
+			}
+
+			| HTMLTreeToken::EndTag {
+				name:
+					"body" | "caption" | "col" | "colgroup" | "html" | "tbody"
+					| "td" | "tfoot" | "th" | "thead" | "tr",
+			} => {
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "style" | "script" | "template",
+				..
+			}
+			| HTMLTreeToken::EndTag { name: "template" } => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+
+			| HTMLTreeToken::StartTag { name: "input", .. }
+				if token
+					.attribute("type")
+					.is_some_and(|ty| ty.eq_ignore_ascii_case("hidden")) =>
+			{
+				self.parse_error("unexpected-start-tag");
+				self.insert_html_element(token);
+				self.stack_of_open_elements.pop();
+			}
+
+			| HTMLTreeToken::StartTag { name: "form", .. } => {
+				self.parse_error("unexpected-start-tag");
+				if self.stack_contains("template")
+					|| self.form_element.is_some()
+				{
+					return;
+				}
+				let form = self.insert_html_element(token);
+				self.form_element.replace(form);
+				self.stack_of_open_elements.pop();
+			}
+
+			| HTMLTreeToken::EndOfStream => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| _ => self.handle_anything_else_in_table(token),
+		}
+	}
+
+	fn handle_in_table_text_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character('\0') => {
+				self.parse_error("unexpected-null-character");
+			}
+
+			| HTMLTreeToken::Character(ch) => {
+				self.pending_table_character_tokens.push(ch);
+			}
+
+			| _ => {
+				let pending =
+					std::mem::take(&mut self.pending_table_character_tokens);
+
+				let is_whitespace = pending
+					.chars()
+					.all(|ch| HTMLTreeToken::Character(ch).is_whitespace());
+
+				for ch in pending.chars() {
+					if is_whitespace {
+						self.insert_character(ch);
+					} else {
+						self.handle_anything_else_in_table(
+							HTMLTreeToken::Character(ch),
+						);
+					}
+				}
+
+				self.insertion_mode = self.original_insertion_mode;
+				self.reprocess(token);
+			}
+		}
+	}
+
+	fn handle_in_caption_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::EndTag { name: "caption" } => {
+				self.close_caption();
+			}
+
+			| HTMLTreeToken::StartTag {
+				name:
+					"caption" | "col" | "colgroup" | "tbody" | "td" | "tfoot" | "th"
+					| "thead" | "tr",
+				..
+			}
+			| HTMLTreeToken::EndTag { name: "table" } => {
+				if self.close_caption() {
+					self.reprocess(token);
+				}
+			}
+
+			| HTMLTreeToken::EndTag {
+				name:
+					"body" | "col" | "colgroup" | "html" | "tbody" | "td" | "tfoot"
+					| "th" | "thead" | "tr",
+			} => {
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| _ => self.process_using_rules(HTMLInsertionMode::InBody, token),
+		}
+	}
+
+	fn handle_in_column_group_insertion_mode(
+		&mut self,
+		token: HTMLTreeToken<'_>,
+	)
+	{
+		match token {
+			| HTMLTreeToken::Character(ch) if token.is_whitespace() => {
+				self.insert_character(ch);
+			}
+
+			| HTMLTreeToken::Comment(data) => self.insert_comment(data, None),
+
+			| HTMLTreeToken::Doctype { .. } => {
+				self.parse_error("unexpected-doctype");
+			}
+
+			| HTMLTreeToken::StartTag { name: "html", .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::StartTag { name: "col", .. } => {
+				self.insert_html_element(token);
+				self.stack_of_open_elements.pop();
+			}
+
+			| HTMLTreeToken::EndTag { name: "colgroup" } => {
+				if !self.current_node_is(&["colgroup"]) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.stack_of_open_elements.pop();
+				self.insertion_mode = HTMLInsertionMode::InTable;
+			}
+
+			| HTMLTreeToken::EndTag { name: "col" } => {
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "template", ..
+			}
+			| HTMLTreeToken::EndTag { name: "template" } => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+
+			| HTMLTreeToken::EndOfStream => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| _ => {
+				if !self.current_node_is(&["colgroup"]) {
+					self.parse_error("unexpected-token-in-column-group");
+					return;
+				}
+				self.stack_of_open_elements.pop();
+				self.insertion_mode = HTMLInsertionMode::InTable;
+				self.reprocess(token);
+			}
+		}
+	}
+
+	fn handle_in_table_body_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::StartTag { name: "tr", .. } => {
+				self.clear_stack_back_to(&TABLE_BODY_CONTEXT);
+				self.insert_html_element(token);
+				self.insertion_mode = HTMLInsertionMode::InRow;
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "th" | "td", ..
+			} => {
+				self.parse_error("unexpected-cell-in-table-body");
+				self.clear_stack_back_to(&TABLE_BODY_CONTEXT);
+				self.insert_html_element_named("tr");
+				self.insertion_mode = HTMLInsertionMode::InRow;
+				self.reprocess(token);
+			}
+
+			| HTMLTreeToken::EndTag {
+				name: name @ ("tbody" | "tfoot" | "thead"),
+			} => {
+				if !self.has_element_in_scope(&[name], HTMLScope::Table) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.clear_stack_back_to(&TABLE_BODY_CONTEXT);
+				self.stack_of_open_elements.pop();
+				self.insertion_mode = HTMLInsertionMode::InTable;
+			}
+
+			| HTMLTreeToken::StartTag {
+				name:
+					"caption" | "col" | "colgroup" | "tbody" | "tfoot" | "thead",
+				..
+			}
+			| HTMLTreeToken::EndTag { name: "table" } => {
+				if !self.has_element_in_scope(
+					&["tbody", "thead", "tfoot"],
+					HTMLScope::Table,
+				) {
+					self.parse_error("unexpected-token-in-table-body");
+					return;
+				}
+				self.clear_stack_back_to(&TABLE_BODY_CONTEXT);
+				self.stack_of_open_elements.pop();
+				self.insertion_mode = HTMLInsertionMode::InTable;
+				self.reprocess(token);
+			}
+
+			| HTMLTreeToken::EndTag {
+				name:
+					"body" | "caption" | "col" | "colgroup" | "html" | "td" | "th"
+					| "tr",
+			} => {
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| _ => self.process_using_rules(HTMLInsertionMode::InTable, token),
+		}
+	}
+
+	fn handle_in_row_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::StartTag {
+				name: "th" | "td", ..
+			} => {
+				self.clear_stack_back_to(&TABLE_ROW_CONTEXT);
+				self.insert_html_element(token);
+				self.insertion_mode = HTMLInsertionMode::InCell;
+				self.push_active_formatting_marker();
+			}
+
+			| HTMLTreeToken::EndTag { name: "tr" } => {
+				self.close_row();
+			}
+
+			| HTMLTreeToken::StartTag {
+				name:
+					"caption" | "col" | "colgroup" | "tbody" | "tfoot" | "thead"
+					| "tr",
+				..
+			}
+			| HTMLTreeToken::EndTag { name: "table" } => {
+				if self.close_row() {
+					self.reprocess(token);
+				}
+			}
+
+			| HTMLTreeToken::EndTag {
+				name: name @ ("tbody" | "tfoot" | "thead"),
+			} => {
+				if !self.has_element_in_scope(&[name], HTMLScope::Table) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				if self.close_row() {
+					self.reprocess(token);
+				}
+			}
+
+			| HTMLTreeToken::EndTag {
+				name:
+					"body" | "caption" | "col" | "colgroup" | "html" | "td" | "th",
+			} => {
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| _ => self.process_using_rules(HTMLInsertionMode::InTable, token),
+		}
+	}
+
+	fn handle_in_cell_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::EndTag {
+				name: name @ ("td" | "th"),
+			} => {
+				if !self.has_element_in_scope(&[name], HTMLScope::Table) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.generate_implied_end_tags(None);
+				if !self.current_node_is(&[name]) {
+					self.parse_error("unexpected-end-tag");
+				}
+				self.pop_until(&[name]);
+				self.clear_active_formatting_elements_up_to_last_marker();
+				self.insertion_mode = HTMLInsertionMode::InRow;
+			}
+
+			| HTMLTreeToken::StartTag {
+				name:
+					"caption" | "col" | "colgroup" | "tbody" | "td" | "tfoot" | "th"
+					| "thead" | "tr",
+				..
+			} => {
+				if !self.has_element_in_scope(&["td", "th"], HTMLScope::Table) {
+					self.parse_error("unexpected-start-tag");
+					return;
+				}
+				self.close_cell();
+				self.reprocess(token);
+			}
+
+			| HTMLTreeToken::EndTag {
+				name: "body" | "caption" | "col" | "colgroup" | "html",
+			} => {
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| HTMLTreeToken::EndTag {
+				name: name @ ("table" | "tbody" | "tfoot" | "thead" | "tr"),
+			} => {
+				if !self.has_element_in_scope(&[name], HTMLScope::Table) {
+					self.parse_error("unexpected-end-tag");
+					return;
+				}
+				self.close_cell();
+				self.reprocess(token);
+			}
+
+			| _ => self.process_using_rules(HTMLInsertionMode::InBody, token),
+		}
+	}
+}
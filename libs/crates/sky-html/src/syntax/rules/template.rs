@@ -0,0 +1,132 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use sky_lang::UnitCodePointExt;
+
+use crate::syntax::{HTMLInsertionMode, HTMLParser, HTMLTreeToken};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait HTMLParserTemplateRules
+{
+	fn handle_in_template_insertion_mode(&mut self, token: HTMLTreeToken<'_>);
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<Input: Iterator> HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	/// Remplace le mode d'insertion du modèle courant par le mode donné,
+	/// puis retraite le jeton dans ce mode.
+	fn switch_template_insertion_mode(
+		&mut self,
+		mode: HTMLInsertionMode,
+		token: HTMLTreeToken<'_>,
+	)
+	{
+		self.template_insertion_modes.pop();
+		self.template_insertion_modes.push(mode);
+		self.insertion_mode = mode;
+		self.reprocess(token);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Input: Iterator> HTMLParserTemplateRules for HTMLParser<Input>
+where
+	Input::Item: UnitCodePointExt,
+{
+	fn handle_in_template_insertion_mode(&mut self, token: HTMLTreeToken<'_>)
+	{
+		match token {
+			| HTMLTreeToken::Character(_)
+			| HTMLTreeToken::Comment(_)
+			| HTMLTreeToken::Doctype { .. } => {
+				self.process_using_rules(HTMLInsertionMode::InBody, token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name:
+					"base" | "basefont" | "bgsound" | "link" | "meta" | "noframes"
+					| "script" | "style" | "template" | "title",
+				..
+			}
+			| HTMLTreeToken::EndTag { name: "template" } => {
+				self.process_using_rules(HTMLInsertionMode::InHead, token);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "caption" | "colgroup" | "tbody" | "tfoot" | "thead",
+				..
+			} => {
+				self.switch_template_insertion_mode(
+					HTMLInsertionMode::InTable,
+					token,
+				);
+			}
+
+			| HTMLTreeToken::StartTag { name: "col", .. } => {
+				self.switch_template_insertion_mode(
+					HTMLInsertionMode::InColumnGroup,
+					token,
+				);
+			}
+
+			| HTMLTreeToken::StartTag { name: "tr", .. } => {
+				self.switch_template_insertion_mode(
+					HTMLInsertionMode::InTableBody,
+					token,
+				);
+			}
+
+			| HTMLTreeToken::StartTag {
+				name: "td" | "th", ..
+			} => {
+				self.switch_template_insertion_mode(
+					HTMLInsertionMode::InRow,
+					token,
+				);
+			}
+
+			| HTMLTreeToken::StartTag { .. } => {
+				self.switch_template_insertion_mode(
+					HTMLInsertionMode::InBody,
+					token,
+				);
+			}
+
+			| HTMLTreeToken::EndTag { .. } => {
+				self.parse_error("unexpected-end-tag");
+			}
+
+			| HTMLTreeToken::EndOfStream => {
+				if !self.stack_contains("template") {
+					self.stop_parsing();
+					return;
+				}
+				self.parse_error("eof-in-template");
+				self.pop_until(&["template"]);
+				self.clear_active_formatting_elements_up_to_last_marker();
+				self.template_insertion_modes.pop();
+				self.reset_insertion_mode_appropriately();
+				self.reprocess(token);
+			}
+		}
+	}
+}
@@ -0,0 +1,115 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::lexical::{HTMLLexeme, HTMLToken, TagState};
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Vue d'un jeton, telle que manipulée par les règles de construction de
+/// l'arbre.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+pub(crate) enum HTMLTreeToken<'a>
+{
+	Character(char),
+	Comment(&'a str),
+	Doctype
+	{
+		name: Option<&'a str>,
+		public_id: Option<&'a str>,
+		system_id: Option<&'a str>,
+		force_quirks: bool,
+	},
+	StartTag
+	{
+		name: &'a str,
+		attributes: &'a [(String, String)],
+		self_closing: bool,
+	},
+	EndTag
+	{
+		name: &'a str,
+	},
+	EndOfStream,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<'a> HTMLTreeToken<'a>
+{
+	/// Vérifie que le jeton est un caractère d'espacement au sens de la
+	/// construction de l'arbre.
+	pub(crate) const fn is_whitespace(&self) -> bool
+	{
+		matches!(self, Self::Character('\t' | '\n' | '\x0C' | '\r' | ' '))
+	}
+
+	/// Valeur de l'attribut de la balise de début.
+	pub(crate) fn attribute(&self, name: &str) -> Option<&'a str>
+	{
+		let Self::StartTag { attributes, .. } = self else {
+			return None;
+		};
+
+		attributes
+			.iter()
+			.find(|(attr_name, _)| attr_name == name)
+			.map(|(_, value)| value.as_str())
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<'a> From<&'a HTMLToken> for HTMLTreeToken<'a>
+{
+	fn from(token: &'a HTMLToken) -> Self
+	{
+		match token.lexeme() {
+			| HTMLLexeme::Character(ch) => Self::Character(*ch),
+			| HTMLLexeme::Comment(data) => Self::Comment(data),
+			| HTMLLexeme::Doctype {
+				name,
+				public_id,
+				system_id,
+				force_quirks,
+			} => {
+				Self::Doctype {
+					name: name.as_deref(),
+					public_id: public_id.as_deref(),
+					system_id: system_id.as_deref(),
+					force_quirks: *force_quirks,
+				}
+			}
+			| HTMLLexeme::Tag {
+				state: TagState::Closed,
+				name,
+				..
+			} => Self::EndTag { name },
+			| HTMLLexeme::Tag {
+				state,
+				name,
+				attributes,
+			} => {
+				Self::StartTag {
+					name,
+					attributes,
+					self_closing: *state == TagState::SelfClosed,
+				}
+			}
+			| HTMLLexeme::EndOfStream => Self::EndOfStream,
+		}
+	}
+}
@@ -0,0 +1,533 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Compare les arbres construits par l'analyseur natif et par html5ever sur
+//! les pages de `pages/` et sur les fixtures de construction de l'arbre de
+//! html5lib-tests (voir `tests/html5lib/README.md`).
+//!
+//! Les deux arbres sont représentés au format `#document` de html5lib, la
+//! première différence est rapportée avec l'emplacement, dans la source, du
+//! nœud construit par l'analyseur natif.
+
+#![cfg(feature = "html5ever")]
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::{env, fs, panic};
+
+use sky_html::{
+	HTMLDocument,
+	HTMLDocumentFragment,
+	HTMLEngine,
+	HTMLFragmentContext,
+	HTMLNamespace,
+	HTMLNodeId,
+	HTMLTree,
+};
+
+// -------- //
+// Constant //
+// -------- //
+
+const PAGES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../../pages");
+
+const HTML5LIB_DIR: &str =
+	concat!(env!("CARGO_MANIFEST_DIR"), "/tests/html5lib");
+
+/// Dossiers des fixtures, relatifs à [HTML5LIB_DIR]: les fichiers d'origine
+/// de html5lib-tests (voir `vendor.sh`), puis nos propres cas.
+const FIXTURES_DIRS: [&str; 2] =
+	["tree-construction", "local/tree-construction"];
+
+/// Échecs et divergences connus, un par ligne au format `fichier #cas:
+/// moteur`, où `moteur` est `html5ever`, `natif` ou `divergence`. Définir
+/// `SKY_HTML5LIB_BLESS=1` réécrit la liste à partir des échecs constatés.
+const KNOWN_FAILURES: &str = concat!(
+	env!("CARGO_MANIFEST_DIR"),
+	"/tests/html5lib/known-failures/tree-construction.txt"
+);
+
+/// En-têtes de section des fichiers `*.dat`, hormis `#data`.
+const SECTIONS: [&str; 6] = [
+	"#errors",
+	"#new-errors",
+	"#document-fragment",
+	"#script-off",
+	"#script-on",
+	"#document",
+];
+
+/// Nombre maximal d'échecs détaillés dans la sortie.
+const MAX_REPORTED_FAILURES: usize = 20;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Cas de test d'un fichier `*.dat`.
+struct TestCase
+{
+	file: String,
+	index: usize,
+	data: String,
+	document: Vec<String>,
+	/// Élément de contexte d'un cas `#document-fragment`.
+	fragment: Option<HTMLFragmentContext>,
+	/// Le cas suppose que les scripts sont activés (`#script-on`).
+	scripting: bool,
+}
+
+/// Arbre construit par un moteur d'analyse.
+enum Parsed
+{
+	Document(HTMLDocument),
+	Fragment(HTMLDocumentFragment),
+}
+
+#[derive(Default)]
+struct FileSummary
+{
+	html5ever: usize,
+	native: usize,
+	divergences: usize,
+	total: usize,
+	skipped: usize,
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+fn files_with_extension(dir: impl AsRef<Path>, extension: &str)
+	-> Vec<PathBuf>
+{
+	let dir = dir.as_ref();
+	let mut files: Vec<_> = fs::read_dir(dir)
+		.unwrap_or_else(|err| panic!("{}: {err}", dir.display()))
+		.filter_map(Result::ok)
+		.map(|entry| entry.path())
+		.filter(|path| path.extension().is_some_and(|ext| ext == extension))
+		.collect();
+	files.sort();
+	files
+}
+
+/// Charge la liste des échecs connus. Les lignes vides et les commentaires
+/// (`#`) sont ignorés.
+fn load_known_failures(path: &str) -> BTreeSet<String>
+{
+	let source = fs::read_to_string(path)
+		.unwrap_or_else(|err| panic!("{path}: lecture impossible: {err}"));
+
+	source
+		.lines()
+		.map(str::trim)
+		.filter(|line| !line.is_empty() && !line.starts_with('#'))
+		.map(ToOwned::to_owned)
+		.collect()
+}
+
+/// Réécrit la liste des échecs connus, en conservant son en-tête.
+fn write_known_failures(path: &str, failing: &BTreeSet<String>)
+{
+	let source = fs::read_to_string(path).unwrap_or_default();
+	let mut output: String = source
+		.lines()
+		.take_while(|line| line.starts_with('#'))
+		.map(|line| format!("{line}\n"))
+		.collect();
+	for failure in failing {
+		output.push_str(failure);
+		output.push('\n');
+	}
+	fs::write(path, output)
+		.unwrap_or_else(|err| panic!("{path}: écriture impossible: {err}"));
+}
+
+/// Fichiers de fixtures, avec leur chemin relatif à [HTML5LIB_DIR]. Un dossier
+/// absent (ex: fixtures d'origine non encore importées) est ignoré.
+fn fixture_files(dirs: &[&str], extension: &str) -> Vec<(String, PathBuf)>
+{
+	dirs.iter()
+		.map(|dir| (dir, Path::new(HTML5LIB_DIR).join(dir)))
+		.filter(|(_, path)| path.is_dir())
+		.flat_map(|(dir, path)| {
+			files_with_extension(path, extension)
+				.into_iter()
+				.map(move |path| {
+					let name = path.file_name().unwrap().to_string_lossy();
+					(format!("{dir}/{name}"), path)
+				})
+		})
+		.collect()
+}
+
+fn load_test_cases(file: &str, path: &Path) -> Vec<TestCase>
+{
+	let file = file.to_owned();
+	let content = fs::read_to_string(path)
+		.unwrap_or_else(|err| panic!("{file}: lecture impossible: {err}"));
+
+	format!("\n{content}")
+		.split("\n#data\n")
+		.skip(1)
+		.enumerate()
+		.map(|(index, chunk)| {
+			let mut sections = vec![("#data", Vec::new())];
+			for line in chunk.split('\n') {
+				if SECTIONS.contains(&line) {
+					sections.push((line, Vec::new()));
+				} else if let Some((_, lines)) = sections.last_mut() {
+					lines.push(line);
+				}
+			}
+
+			let section = |name: &str| {
+				sections
+					.iter()
+					.find(|(section, _)| *section == name)
+					.map(|(_, lines)| lines.as_slice())
+			};
+
+			let mut document: Vec<String> = section("#document")
+				.unwrap_or_default()
+				.iter()
+				.map(|line| line.to_string())
+				.collect();
+			while document.last().is_some_and(String::is_empty) {
+				document.pop();
+			}
+
+			TestCase {
+				file: file.clone(),
+				index: index + 1,
+				data: section("#data").unwrap_or_default().join("\n"),
+				document,
+				fragment: section("#document-fragment")
+					.and_then(|lines| lines.first())
+					.map(|line| fragment_context(line)),
+				scripting: section("#script-on").is_some(),
+			}
+		})
+		.collect()
+}
+
+/// Élément de contexte d'un cas `#document-fragment`, ex: `td`, `svg path`.
+fn fragment_context(line: &str) -> HTMLFragmentContext
+{
+	match line.split_once(' ') {
+		| Some(("svg", name)) => {
+			HTMLFragmentContext::new(HTMLNamespace::Svg, name)
+		}
+		| Some(("math", name)) => {
+			HTMLFragmentContext::new(HTMLNamespace::MathML, name)
+		}
+		| _ => HTMLFragmentContext::html(line),
+	}
+}
+
+/// Analyse l'entrée avec le moteur donné, comme un document ou comme un
+/// fragment. Une panique de l'analyseur est rapportée comme une erreur.
+fn parse(
+	input: &str,
+	fragment: Option<&HTMLFragmentContext>,
+	engine: HTMLEngine,
+) -> Result<Parsed, String>
+{
+	panic::catch_unwind(|| {
+		let mut input = input.as_bytes();
+		match fragment {
+			| Some(context) => {
+				HTMLDocumentFragment::from_slice(context, &mut input, engine)
+					.map(Parsed::Fragment)
+			}
+			| None => {
+				HTMLDocument::from_slice(&mut input, engine)
+					.map(Parsed::Document)
+			}
+		}
+	})
+	.map_err(|_| format!("panique de l'analyseur ({engine})"))?
+	.map_err(|err| format!("analyse ({engine}): {err}"))
+}
+
+fn first_difference<T: PartialEq>(left: &[T], right: &[T]) -> Option<usize>
+{
+	(0..left.len().max(right.len())).find(|&i| left.get(i) != right.get(i))
+}
+
+/// Première différence entre la représentation attendue et celle de l'arbre
+/// construit.
+fn compare_with_expected(expected: &[String], parsed: &Parsed)
+	-> Option<String>
+{
+	let actual = parsed
+		.dump_lines()
+		.into_iter()
+		.flat_map(|(_, line)| {
+			// NOTE: un texte peut s'étendre sur plusieurs lignes.
+			line.split('\n').map(str::to_owned).collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+	let index = first_difference(expected, &actual)?;
+	Some(format!(
+		"ligne {}: attendu {:?}, obtenu {:?}",
+		index + 1,
+		expected.get(index),
+		actual.get(index)
+	))
+}
+
+/// Première différence structurelle entre les arbres des deux moteurs, avec
+/// l'emplacement dans la source du nœud construit par l'analyseur natif.
+fn compare_engines(native: &Parsed, html5ever: &Parsed) -> Option<String>
+{
+	let native_lines = native.dump_lines();
+	let html5ever_lines = html5ever.dump_lines();
+
+	let index = first_difference(
+		&native_lines
+			.iter()
+			.map(|(_, line)| line)
+			.collect::<Vec<_>>(),
+		&html5ever_lines
+			.iter()
+			.map(|(_, line)| line)
+			.collect::<Vec<_>>(),
+	)?;
+
+	// NOTE: lorsque l'arbre natif est plus court, le dernier nœud construit
+	// est le plus proche de la différence.
+	let location = native_lines
+		.get(index)
+		.or(native_lines.last())
+		.and_then(|(id, _)| native.tree()[*id].location())
+		.map_or_else(|| "emplacement inconnu".to_owned(), |l| l.to_string());
+
+	Some(format!(
+		"{location}\n      natif:     {:?}\n      html5ever: {:?}",
+		native_lines.get(index).map(|(_, line)| line),
+		html5ever_lines.get(index).map(|(_, line)| line),
+	))
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Parsed
+{
+	fn tree(&self) -> &HTMLTree
+	{
+		match self {
+			| Self::Document(document) => document.tree(),
+			| Self::Fragment(fragment) => fragment.tree(),
+		}
+	}
+
+	/// Lignes de la représentation de l'arbre, au format de la section
+	/// `#document` des fichiers `*.dat`.
+	fn dump_lines(&self) -> Vec<(HTMLNodeId, String)>
+	{
+		match self {
+			| Self::Document(document) => {
+				document
+					.tree()
+					.dump_children_lines(document.tree().document())
+			}
+			| Self::Fragment(fragment) => {
+				fragment.tree().dump_children_lines(fragment.root())
+			}
+		}
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[test]
+fn pages_differential()
+{
+	let pages = files_with_extension(PAGES_DIR, "html");
+	assert!(!pages.is_empty(), "aucune page dans {PAGES_DIR}");
+
+	let mut divergences = Vec::new();
+
+	for path in &pages {
+		let name = path.file_name().unwrap().to_string_lossy().into_owned();
+		let input = fs::read_to_string(path)
+			.unwrap_or_else(|err| panic!("{name}: lecture impossible: {err}"));
+
+		let native = parse(&input, None, HTMLEngine::Native).unwrap();
+		let html5ever = parse(&input, None, HTMLEngine::Html5ever).unwrap();
+
+		if let Some(reason) = compare_engines(&native, &html5ever) {
+			println!("DIVERGENCE {name}: {reason}");
+			divergences.push(name);
+		}
+	}
+
+	assert!(
+		divergences.is_empty(),
+		"arbres différents pour: {}",
+		divergences.join(", ")
+	);
+}
+
+#[test]
+fn html5lib_tree_construction_differential()
+{
+	let files = fixture_files(&FIXTURES_DIRS, "dat");
+	assert!(!files.is_empty(), "aucune fixture dans {HTML5LIB_DIR}");
+
+	let mut summary: BTreeMap<String, FileSummary> = BTreeMap::new();
+	let mut failures = Vec::new();
+	let mut failing = BTreeSet::new();
+
+	// NOTE: les paniques sont comptabilisées comme des échecs, on évite
+	// d'encombrer la sortie avec leur message.
+	let default_hook = panic::take_hook();
+	panic::set_hook(Box::new(|_| {}));
+
+	for case in files
+		.iter()
+		.flat_map(|(file, path)| load_test_cases(file, path))
+	{
+		let entry = summary.entry(case.file.clone()).or_default();
+		let name = format!("{} #{}", case.file, case.index);
+
+		if case.scripting {
+			entry.skipped += 1;
+			println!(
+				"IGNORÉ {name}: #script-on, les scripts ne sont pas exécutés"
+			);
+			continue;
+		}
+
+		entry.total += 1;
+
+		let trees = [HTMLEngine::Html5ever, HTMLEngine::Native].map(|engine| {
+			(engine, parse(&case.data, case.fragment.as_ref(), engine))
+		});
+
+		for (engine, parsed) in &trees {
+			let reason = match parsed {
+				| Ok(parsed) => {
+					let Some(reason) =
+						compare_with_expected(&case.document, parsed)
+					else {
+						match engine {
+							| HTMLEngine::Html5ever => entry.html5ever += 1,
+							| HTMLEngine::Native => entry.native += 1,
+						}
+						continue;
+					};
+					reason
+				}
+				| Err(err) => err.to_owned(),
+			};
+			failing.insert(match engine {
+				| HTMLEngine::Html5ever => format!("{name}: html5ever"),
+				| HTMLEngine::Native => format!("{name}: natif"),
+			});
+			failures.push(format!(
+				"ÉCHEC {name} ({engine})\n      entrée: {:?}\n      {reason}",
+				case.data
+			));
+		}
+
+		if let [(_, Ok(html5ever)), (_, Ok(native))] = &trees {
+			if let Some(reason) = compare_engines(native, html5ever) {
+				entry.divergences += 1;
+				failing.insert(format!("{name}: divergence"));
+				failures.push(format!(
+					"DIVERGENCE {name}\n      entrée: {:?}\n      {reason}",
+					case.data
+				));
+			}
+		}
+	}
+
+	panic::set_hook(default_hook);
+
+	for failure in failures.iter().take(MAX_REPORTED_FAILURES) {
+		println!("{failure}");
+	}
+	if failures.len() > MAX_REPORTED_FAILURES {
+		println!("… {} autres échecs", failures.len() - MAX_REPORTED_FAILURES);
+	}
+
+	println!();
+	println!(
+		"{:<40} {:>10} {:>10} {:>12} {:>8}",
+		"fichier", "html5ever", "natif", "divergences", "ignorés"
+	);
+	let mut total = FileSummary::default();
+	for (file, result) in &summary {
+		println!(
+			"{file:<40} {:>10} {:>10} {:>12} {:>8}",
+			format!("{}/{}", result.html5ever, result.total),
+			format!("{}/{}", result.native, result.total),
+			result.divergences,
+			result.skipped
+		);
+		total.html5ever += result.html5ever;
+		total.native += result.native;
+		total.divergences += result.divergences;
+		total.total += result.total;
+		total.skipped += result.skipped;
+	}
+	println!(
+		"{:<40} {:>10} {:>10} {:>12} {:>8}",
+		"total",
+		format!("{}/{}", total.html5ever, total.total),
+		format!("{}/{}", total.native, total.total),
+		total.divergences,
+		total.skipped
+	);
+
+	if !files
+		.iter()
+		.any(|(file, _)| file.starts_with(FIXTURES_DIRS[0]))
+	{
+		println!(
+			"\nATTENTION: les fixtures de html5lib-tests ne sont pas \
+			 importées (voir tests/html5lib/README.md), seuls nos propres cas \
+			 ont été comparés: ce récapitulatif ne mesure pas la conformité."
+		);
+	}
+
+	assert!(total.total > 0, "aucun cas exécuté");
+
+	if env::var_os("SKY_HTML5LIB_BLESS").is_some() {
+		write_known_failures(KNOWN_FAILURES, &failing);
+		return;
+	}
+
+	let known_failures = load_known_failures(KNOWN_FAILURES);
+	let new_failures: Vec<_> = failing.difference(&known_failures).collect();
+	let fixed: Vec<_> = known_failures.difference(&failing).collect();
+
+	for failure in &new_failures {
+		println!("NOUVEL ÉCHEC {failure}");
+	}
+	for failure in &fixed {
+		println!("CORRIGÉ {failure}");
+	}
+
+	assert!(
+		new_failures.is_empty() && fixed.is_empty(),
+		"{} nouveaux échecs ou divergences, {} connus désormais corrigés: \
+		 mettre à jour {KNOWN_FAILURES}",
+		new_failures.len(),
+		fixed.len()
+	);
+}
@@ -0,0 +1,30 @@
+#!/bin/sh
+# Importe, sans les modifier, les fixtures de html5lib-tests à la révision
+# donnée, puis régénère la liste des cas en échec connus.
+#
+# Usage: tests/html5lib/vendor.sh <commit> [dépôt]
+#
+# Le dépôt est, par défaut, celui de GitHub; un clone local peut être donné à
+# la place, ex: pour importer les fixtures sans accès au réseau.
+
+set -eu
+
+REVISION="${1:?usage: $0 <commit de html5lib-tests> [dépôt]}"
+REPOSITORY="${2:-https://github.com/html5lib/html5lib-tests.git}"
+HTML5LIB_DIR="$(cd "$(dirname "$0")" && pwd)"
+CHECKOUT="$(mktemp -d)"
+trap 'rm -rf "$CHECKOUT"' EXIT
+
+git clone --quiet "$REPOSITORY" "$CHECKOUT"
+git -C "$CHECKOUT" checkout --quiet "$REVISION"
+
+rm -rf "$HTML5LIB_DIR/tokenizer" "$HTML5LIB_DIR/tree-construction"
+mkdir -p "$HTML5LIB_DIR/tokenizer" "$HTML5LIB_DIR/tree-construction"
+cp "$CHECKOUT"/tokenizer/*.test "$HTML5LIB_DIR/tokenizer/"
+cp "$CHECKOUT"/tree-construction/*.dat "$HTML5LIB_DIR/tree-construction/"
+
+git -C "$CHECKOUT" rev-parse HEAD > "$HTML5LIB_DIR/UPSTREAM_COMMIT"
+
+cd "$HTML5LIB_DIR/../.."
+SKY_HTML5LIB_BLESS=1 cargo test --test html5lib_tokenizer
+SKY_HTML5LIB_BLESS=1 cargo test --test differential
@@ -0,0 +1,536 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Exécute les fixtures du tokenizer de html5lib-tests (voir
+//! `tests/html5lib/README.md`) et affiche un récapitulatif par état initial.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use serde_json::{Map, Value};
+use sky_html::{HTMLLexeme, HTMLTokenizer, HTMLTokenizerState, TagState};
+
+// -------- //
+// Constant //
+// -------- //
+
+const HTML5LIB_DIR: &str =
+	concat!(env!("CARGO_MANIFEST_DIR"), "/tests/html5lib");
+
+/// Dossiers des fixtures, relatifs à [HTML5LIB_DIR]: les fichiers d'origine
+/// de html5lib-tests (voir `vendor.sh`), puis nos propres cas.
+const FIXTURES_DIRS: [&str; 2] = ["tokenizer", "local/tokenizer"];
+
+/// Cas en échec connus, un par ligne au format `fichier: description`. Définir
+/// `SKY_HTML5LIB_BLESS=1` réécrit la liste à partir des échecs constatés.
+const KNOWN_FAILURES: &str = concat!(
+	env!("CARGO_MANIFEST_DIR"),
+	"/tests/html5lib/known-failures/tokenizer.txt"
+);
+
+/// Nombre maximal d'échecs détaillés dans la sortie.
+const MAX_REPORTED_FAILURES: usize = 20;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Cas de test, pour un état initial donné.
+struct TestCase
+{
+	file: String,
+	description: String,
+	initial_state: String,
+	last_start_tag: Option<String>,
+	input: String,
+	output: Value,
+	errors: Vec<String>,
+}
+
+/// Résultat d'exécution du tokenizer, au format html5lib.
+struct TestOutput
+{
+	output: Value,
+	errors: Vec<String>,
+}
+
+#[derive(Default)]
+struct StateSummary
+{
+	passed: usize,
+	failed: usize,
+	skipped: usize,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+enum TestResult
+{
+	Passed,
+	Failed(String),
+	Skipped(String),
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+fn initial_state(name: &str) -> Option<HTMLTokenizerState>
+{
+	Some(match name {
+		| "Data state" => HTMLTokenizerState::Data,
+		| "PLAINTEXT state" => HTMLTokenizerState::Plaintext,
+		| "RCDATA state" => HTMLTokenizerState::Rcdata,
+		| "RAWTEXT state" => HTMLTokenizerState::Rawtext,
+		| "Script data state" => HTMLTokenizerState::ScriptData,
+		| "CDATA section state" => HTMLTokenizerState::CdataSection,
+		| _ => return None,
+	})
+}
+
+/// Décode les séquences `\uXXXX` des tests `doubleEscaped`. Les surrogates
+/// isolés ne peuvent pas être représentés par une [String]: le test est
+/// alors ignoré.
+fn unescape(input: &str) -> Option<String>
+{
+	let mut output = String::with_capacity(input.len());
+	let mut chars = input.chars();
+
+	while let Some(ch) = chars.next() {
+		if ch != '\\' {
+			output.push(ch);
+			continue;
+		}
+
+		match chars.next() {
+			| Some('u') => {
+				let hex: String = chars.by_ref().take(4).collect();
+				let code = u32::from_str_radix(&hex, 16).ok()?;
+				output.push(char::from_u32(code)?);
+			}
+			| Some(other) => {
+				output.push('\\');
+				output.push(other);
+			}
+			| None => output.push('\\'),
+		}
+	}
+
+	Some(output)
+}
+
+fn unescape_value(value: &Value) -> Option<Value>
+{
+	Some(match value {
+		| Value::String(s) => Value::String(unescape(s)?),
+		| Value::Array(items) => {
+			Value::Array(
+				items.iter().map(unescape_value).collect::<Option<_>>()?,
+			)
+		}
+		| Value::Object(map) => {
+			let mut output = Map::new();
+			for (key, value) in map {
+				output.insert(unescape(key)?, unescape_value(value)?);
+			}
+			Value::Object(output)
+		}
+		| _ => value.clone(),
+	})
+}
+
+/// Charge la liste des cas en échec connus: `(fichier, description)`. Les
+/// lignes vides et les commentaires (`#`) sont ignorés.
+fn load_known_failures(path: &str) -> BTreeSet<(String, String)>
+{
+	let source = fs::read_to_string(path)
+		.unwrap_or_else(|err| panic!("{path}: lecture impossible: {err}"));
+
+	source
+		.lines()
+		.map(str::trim)
+		.filter(|line| !line.is_empty() && !line.starts_with('#'))
+		.map(|line| {
+			let (file, description) =
+				line.split_once(": ").unwrap_or_else(|| {
+					panic!(
+						"{path}: ligne invalide, `fichier: description` \
+						 attendu: {line}"
+					)
+				});
+			(file.to_owned(), description.to_owned())
+		})
+		.collect()
+}
+
+/// Réécrit la liste des cas en échec connus, en conservant son en-tête.
+fn write_known_failures(path: &str, failing: &BTreeSet<(String, String)>)
+{
+	let source = fs::read_to_string(path).unwrap_or_default();
+	let mut output: String = source
+		.lines()
+		.take_while(|line| line.starts_with('#'))
+		.map(|line| format!("{line}\n"))
+		.collect();
+	for (file, description) in failing {
+		output.push_str(&format!("{file}: {description}\n"));
+	}
+	fs::write(path, output)
+		.unwrap_or_else(|err| panic!("{path}: écriture impossible: {err}"));
+}
+
+/// Fichiers de fixtures, avec leur chemin relatif à [HTML5LIB_DIR]. Un dossier
+/// absent (ex: fixtures d'origine non encore importées) est ignoré.
+fn fixture_files(dirs: &[&str], extension: &str) -> Vec<(String, PathBuf)>
+{
+	let mut files: Vec<_> = dirs
+		.iter()
+		.filter_map(|dir| {
+			let entries =
+				fs::read_dir(Path::new(HTML5LIB_DIR).join(dir)).ok()?;
+			Some(entries.filter_map(Result::ok).map(move |entry| {
+				let name = entry.file_name().to_string_lossy().into_owned();
+				(format!("{dir}/{name}"), entry.path())
+			}))
+		})
+		.flatten()
+		.filter(|(_, path)| {
+			path.extension().is_some_and(|ext| ext == extension)
+		})
+		.collect();
+	files.sort();
+	files
+}
+
+fn load_test_cases(file: &str, path: &Path) -> Vec<Result<TestCase, TestCase>>
+{
+	let file = file.to_owned();
+	let source = fs::read_to_string(path).unwrap();
+	let json: Value = serde_json::from_str(&source)
+		.unwrap_or_else(|err| panic!("{file}: JSON invalide: {err}"));
+
+	let mut cases = Vec::new();
+
+	let tests = json["tests"].as_array().cloned().unwrap_or_default();
+	for test in tests {
+		let description =
+			test["description"].as_str().unwrap_or_default().to_owned();
+		let double_escaped = test["doubleEscaped"].as_bool().unwrap_or(false);
+
+		let input = test["input"].as_str().unwrap_or_default();
+		let (input, output) = if double_escaped {
+			(unescape(input), unescape_value(&test["output"]))
+		} else {
+			(Some(input.to_owned()), Some(test["output"].clone()))
+		};
+
+		let errors: Vec<String> = test["errors"]
+			.as_array()
+			.map(|errors| {
+				errors
+					.iter()
+					.filter_map(|err| err["code"].as_str())
+					.map(ToOwned::to_owned)
+					.collect()
+			})
+			.unwrap_or_default();
+
+		let initial_states = test["initialStates"]
+			.as_array()
+			.map(|states| {
+				states
+					.iter()
+					.filter_map(|state| state.as_str())
+					.map(ToOwned::to_owned)
+					.collect()
+			})
+			.unwrap_or_else(|| vec!["Data state".to_owned()]);
+
+		for initial_state in initial_states {
+			let case = TestCase {
+				file: file.clone(),
+				description: description.clone(),
+				initial_state,
+				last_start_tag: test["lastStartTag"].as_str().map(Into::into),
+				input: input.clone().unwrap_or_default(),
+				output: output.clone().unwrap_or_default(),
+				errors: errors.clone(),
+			};
+
+			if input.is_some() && output.is_some() {
+				cases.push(Ok(case));
+			} else {
+				cases.push(Err(case));
+			}
+		}
+	}
+
+	cases
+}
+
+/// Exécute le tokenizer et convertit les jetons émis au format html5lib. Les
+/// caractères consécutifs sont fusionnés en un seul jeton.
+fn tokenize(case: &TestCase, state: HTMLTokenizerState) -> TestOutput
+{
+	let mut tokenizer = HTMLTokenizer::new(case.input.chars());
+	tokenizer.switch_state(state);
+	tokenizer.set_in_foreign_content(state == HTMLTokenizerState::CdataSection);
+	if let Some(last_start_tag) = case.last_start_tag.as_deref() {
+		tokenizer.set_last_start_tag_name(last_start_tag);
+	}
+
+	let mut output: Vec<Value> = Vec::new();
+
+	for token in tokenizer.by_ref().filter_map(Result::ok) {
+		let value = match token.lexeme() {
+			| HTMLLexeme::Character(ch) => {
+				if let Some(Value::Array(last)) = output.last_mut() {
+					if last[0] == "Character" {
+						let mut data = last[1].as_str().unwrap().to_owned();
+						data.push(*ch);
+						last[1] = Value::from(data);
+						continue;
+					}
+				}
+				Value::from(vec![
+					Value::from("Character"),
+					Value::from(ch.to_string()),
+				])
+			}
+
+			| HTMLLexeme::Comment(data) => {
+				Value::from(vec![Value::from("Comment"), Value::from(&**data)])
+			}
+
+			| HTMLLexeme::Doctype {
+				name,
+				public_id,
+				system_id,
+				force_quirks,
+			} => {
+				Value::from(vec![
+					Value::from("DOCTYPE"),
+					Value::from(name.clone()),
+					Value::from(public_id.clone()),
+					Value::from(system_id.clone()),
+					Value::from(!force_quirks),
+				])
+			}
+
+			| HTMLLexeme::Tag {
+				state: TagState::Closed,
+				name,
+				..
+			} => Value::from(vec![Value::from("EndTag"), Value::from(&**name)]),
+
+			| HTMLLexeme::Tag {
+				state,
+				name,
+				attributes,
+			} => {
+				let mut map = Map::new();
+				for (name, value) in attributes {
+					map.insert(name.clone(), Value::from(&**value));
+				}
+
+				let mut tag = vec![
+					Value::from("StartTag"),
+					Value::from(&**name),
+					Value::Object(map),
+				];
+				if *state == TagState::SelfClosed {
+					tag.push(Value::from(true));
+				}
+				Value::from(tag)
+			}
+
+			| HTMLLexeme::EndOfStream => break,
+		};
+
+		output.push(value);
+	}
+
+	let errors = tokenizer
+		.take_diagnostics()
+		.iter()
+		.map(|err| err.variant().code().to_owned())
+		.collect();
+
+	TestOutput {
+		output: Value::from(output),
+		errors,
+	}
+}
+
+fn run(case: &TestCase) -> TestResult
+{
+	let Some(state) = initial_state(&case.initial_state) else {
+		return TestResult::Skipped(format!(
+			"état initial non supporté: {}",
+			case.initial_state
+		));
+	};
+
+	let result =
+		panic::catch_unwind(AssertUnwindSafe(|| tokenize(case, state)));
+	let got = match result {
+		| Ok(got) => got,
+		| Err(_) => return TestResult::Failed("panique".to_owned()),
+	};
+
+	let mut reasons = Vec::new();
+	if got.output != case.output {
+		reasons.push(format!(
+			"jetons attendus: {}\n      jetons obtenus:  {}",
+			case.output, got.output
+		));
+	}
+	if got.errors != case.errors {
+		reasons.push(format!(
+			"erreurs attendues: {:?}\n      erreurs obtenues:  {:?}",
+			case.errors, got.errors
+		));
+	}
+
+	if reasons.is_empty() {
+		TestResult::Passed
+	} else {
+		TestResult::Failed(reasons.join("\n      "))
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[test]
+fn html5lib_tokenizer_conformance()
+{
+	let files = fixture_files(&FIXTURES_DIRS, "test");
+	assert!(!files.is_empty(), "aucune fixture dans {HTML5LIB_DIR}");
+
+	let mut summary: BTreeMap<String, StateSummary> = BTreeMap::new();
+	let mut failures = Vec::new();
+
+	// NOTE: les paniques sont comptabilisées comme des échecs, on évite
+	// d'encombrer la sortie avec leur message.
+	let default_hook = panic::take_hook();
+	panic::set_hook(Box::new(|_| {}));
+
+	for case in files
+		.iter()
+		.flat_map(|(file, path)| load_test_cases(file, path))
+	{
+		let (case, result) = match case {
+			| Ok(case) => {
+				let result = run(&case);
+				(case, result)
+			}
+			| Err(case) => {
+				(case, TestResult::Skipped("surrogate isolé".to_owned()))
+			}
+		};
+
+		let entry = summary.entry(case.initial_state.clone()).or_default();
+		match result {
+			| TestResult::Passed => entry.passed += 1,
+			| TestResult::Skipped(reason) => {
+				entry.skipped += 1;
+				println!(
+					"IGNORÉ {} — {} ({}): {reason}",
+					case.file, case.description, case.initial_state
+				);
+			}
+			| TestResult::Failed(reason) => {
+				entry.failed += 1;
+				failures.push((case, reason));
+			}
+		}
+	}
+
+	panic::set_hook(default_hook);
+
+	for (case, reason) in failures.iter().take(MAX_REPORTED_FAILURES) {
+		println!(
+			"ÉCHEC {} — {} ({})\n      entrée: {:?}\n      {reason}",
+			case.file, case.description, case.initial_state, case.input
+		);
+	}
+	if failures.len() > MAX_REPORTED_FAILURES {
+		println!("… {} autres échecs", failures.len() - MAX_REPORTED_FAILURES);
+	}
+
+	println!();
+	println!(
+		"{:<24} {:>8} {:>8} {:>8}",
+		"état", "succès", "échecs", "ignorés"
+	);
+	let mut total = StateSummary::default();
+	for (state, result) in &summary {
+		println!(
+			"{state:<24} {:>8} {:>8} {:>8}",
+			result.passed, result.failed, result.skipped
+		);
+		total.passed += result.passed;
+		total.failed += result.failed;
+		total.skipped += result.skipped;
+	}
+	println!(
+		"{:<24} {:>8} {:>8} {:>8}",
+		"total", total.passed, total.failed, total.skipped
+	);
+
+	if !files
+		.iter()
+		.any(|(file, _)| file.starts_with(FIXTURES_DIRS[0]))
+	{
+		println!(
+			"\nATTENTION: les fixtures de html5lib-tests ne sont pas \
+			 importées (voir tests/html5lib/README.md), seuls nos propres cas \
+			 ont été exécutés: ce récapitulatif ne mesure pas la conformité."
+		);
+	}
+
+	assert!(total.passed + total.failed > 0, "aucun cas exécuté");
+
+	// NOTE: un cas est identifié par son fichier et sa description, il est en
+	// échec si au moins l'un de ses états initiaux l'est.
+	let failing: BTreeSet<_> = failures
+		.iter()
+		.map(|(case, _)| (case.file.clone(), case.description.clone()))
+		.collect();
+	if env::var_os("SKY_HTML5LIB_BLESS").is_some() {
+		write_known_failures(KNOWN_FAILURES, &failing);
+		return;
+	}
+	let known_failures = load_known_failures(KNOWN_FAILURES);
+
+	let new_failures: Vec<_> = failing.difference(&known_failures).collect();
+	let fixed: Vec<_> = known_failures.difference(&failing).collect();
+
+	for (file, description) in &new_failures {
+		println!("NOUVEL ÉCHEC {file}: {description}");
+	}
+	for (file, description) in &fixed {
+		println!("CORRIGÉ {file}: {description}");
+	}
+
+	assert!(
+		new_failures.is_empty() && fixed.is_empty(),
+		"{} nouveaux cas en échec, {} cas connus désormais réussis: mettre à \
+		 jour {KNOWN_FAILURES}",
+		new_failures.len(),
+		fixed.len()
+	);
+}
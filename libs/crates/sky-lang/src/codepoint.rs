@@ -57,6 +57,15 @@ pub trait UnitCodePointExt:
 	fn is__whitespace(&self) -> bool;
 
 	fn unit(&self) -> char;
+
+	/// Unité d'un saut de ligne (LF), ex: pour normaliser les fins de ligne.
+	fn line_feed() -> Self;
+
+	/// Nombre d'octets occupés par l'unité dans la source.
+	fn byte_len(&self) -> usize
+	{
+		self.unit().len_utf8()
+	}
 }
 
 // -------------- //
@@ -130,12 +139,19 @@ impl UnitCodePointExt for char
 		self.is_whitespace()
 	}
 
+	fn line_feed() -> Self
+	{
+		'\n'
+	}
+
 	fn unit(&self) -> char
 	{
 		*self
 	}
 }
 
+// NOTE: chaque octet est traité comme un point de code (Latin-1). Pour des
+// octets UTF-8, décoder le flux avec [crate::Utf8Decoder].
 impl UnitCodePointExt for u8
 {
 	fn is(&self, ch: char) -> bool
@@ -203,8 +219,18 @@ impl UnitCodePointExt for u8
 		self.is_ascii_whitespace()
 	}
 
+	fn line_feed() -> Self
+	{
+		b'\n'
+	}
+
 	fn unit(&self) -> char
 	{
 		*self as char
 	}
+
+	fn byte_len(&self) -> usize
+	{
+		1
+	}
 }
@@ -0,0 +1,118 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use serde_json::{json, Value};
+
+use super::{Diagnostic, DiagnosticLabel};
+use crate::location::Position;
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+/// Représentation structurée des diagnostics, ex: pour l'interface ou des
+/// outils externes.
+impl Diagnostic
+{
+	/// Représentation JSON du diagnostic, ex:
+	///
+	/// ```json
+	/// {
+	///   "severity": "error",
+	///   "code": "eof-in-tag",
+	///   "message": "Fin du flux dans la balise",
+	///   "labels": [
+	///     {
+	///       "primary": true,
+	///       "message": null,
+	///       "start": { "offset": 8, "line": 1, "column": 9 },
+	///       "end": { "offset": 8, "line": 1, "column": 9 }
+	///     }
+	///   ],
+	///   "notes": []
+	/// }
+	/// ```
+	pub fn to_json(&self) -> Value
+	{
+		json!({
+			"severity": self.severity.name(),
+			"code": self.code,
+			"message": self.message,
+			"labels": self.labels.iter().map(label_to_json).collect::<Vec<_>>(),
+			"notes": self.notes,
+		})
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+fn label_to_json(label: &DiagnosticLabel) -> Value
+{
+	json!({
+		"primary": label.primary,
+		"message": (!label.message.is_empty()).then_some(&label.message),
+		"start": position_to_json(label.location.start()),
+		"end": position_to_json(label.location.end()),
+	})
+}
+
+fn position_to_json(position: Position) -> Value
+{
+	json!({
+		"offset": position.offset(),
+		"line": position.line(),
+		"column": position.column(),
+	})
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::location::Location;
+
+	#[test]
+	fn test_to_json()
+	{
+		let mut end = Position::new();
+		end.advance('<').advance('p');
+
+		let diagnostic = Diagnostic::error("Balise non fermée")
+			.with_code("unclosed-tag")
+			.with_primary_label(Location::between(Position::new(), end), "ici")
+			.with_note("ajoutez `>`");
+
+		assert_eq!(
+			diagnostic.to_json(),
+			json!({
+				"severity": "error",
+				"code": "unclosed-tag",
+				"message": "Balise non fermée",
+				"labels": [{
+					"primary": true,
+					"message": "ici",
+					"start": { "offset": 0, "line": 1, "column": 1 },
+					"end": { "offset": 2, "line": 1, "column": 3 },
+				}],
+				"notes": ["ajoutez `>`"],
+			})
+		);
+
+		let diagnostic = Diagnostic::warning("Attention");
+		assert_eq!(diagnostic.to_json()["code"], Value::Null);
+		assert_eq!(diagnostic.to_json()["labels"], json!([]));
+	}
+}
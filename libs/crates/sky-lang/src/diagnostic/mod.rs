@@ -0,0 +1,245 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod json;
+mod render;
+
+use std::fmt;
+
+pub use self::render::DiagnosticRenderer;
+use crate::location::Location;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Diagnostic d'un analyseur: une erreur, un avertissement, etc., accompagné
+/// des emplacements de la source qu'il concerne.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct Diagnostic
+{
+	severity: DiagnosticSeverity,
+	/// Code du diagnostic, ex: `eof-in-tag`.
+	code: Option<String>,
+	message: String,
+	labels: Vec<DiagnosticLabel>,
+	notes: Vec<String>,
+}
+
+/// Emplacement de la source annoté d'un diagnostic.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct DiagnosticLabel
+{
+	location: Location,
+	/// Message affiché sous l'extrait de la source, peut être vide.
+	message: String,
+	/// L'emplacement principal est la cause du diagnostic, les emplacements
+	/// secondaires apportent du contexte.
+	primary: bool,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticSeverity
+{
+	Info,
+	Warning,
+	Error,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Diagnostic
+{
+	pub fn new(severity: DiagnosticSeverity, message: impl Into<String>)
+		-> Self
+	{
+		Self {
+			severity,
+			code: None,
+			message: message.into(),
+			labels: Vec::new(),
+			notes: Vec::new(),
+		}
+	}
+
+	pub fn error(message: impl Into<String>) -> Self
+	{
+		Self::new(DiagnosticSeverity::Error, message)
+	}
+
+	pub fn warning(message: impl Into<String>) -> Self
+	{
+		Self::new(DiagnosticSeverity::Warning, message)
+	}
+
+	pub fn info(message: impl Into<String>) -> Self
+	{
+		Self::new(DiagnosticSeverity::Info, message)
+	}
+
+	pub fn with_code(mut self, code: impl Into<String>) -> Self
+	{
+		self.code.replace(code.into());
+		self
+	}
+
+	/// Ajoute l'emplacement à l'origine du diagnostic.
+	pub fn with_primary_label(
+		mut self,
+		location: Location,
+		message: impl Into<String>,
+	) -> Self
+	{
+		self.labels.push(DiagnosticLabel {
+			location,
+			message: message.into(),
+			primary: true,
+		});
+		self
+	}
+
+	/// Ajoute un emplacement apportant du contexte, ex: la balise ouvrante
+	/// d'un élément mal fermé.
+	pub fn with_secondary_label(
+		mut self,
+		location: Location,
+		message: impl Into<String>,
+	) -> Self
+	{
+		self.labels.push(DiagnosticLabel {
+			location,
+			message: message.into(),
+			primary: false,
+		});
+		self
+	}
+
+	pub fn with_note(mut self, note: impl Into<String>) -> Self
+	{
+		self.notes.push(note.into());
+		self
+	}
+}
+
+impl Diagnostic
+{
+	pub const fn severity(&self) -> DiagnosticSeverity
+	{
+		self.severity
+	}
+
+	pub fn code(&self) -> Option<&str>
+	{
+		self.code.as_deref()
+	}
+
+	pub fn message(&self) -> &str
+	{
+		&self.message
+	}
+
+	pub fn labels(&self) -> &[DiagnosticLabel]
+	{
+		&self.labels
+	}
+
+	pub fn notes(&self) -> &[String]
+	{
+		&self.notes
+	}
+
+	/// Emplacement principal du diagnostic, à défaut le premier emplacement.
+	pub fn location(&self) -> Option<Location>
+	{
+		self.labels
+			.iter()
+			.find(|label| label.primary)
+			.or_else(|| self.labels.first())
+			.map(|label| label.location)
+	}
+}
+
+impl DiagnosticLabel
+{
+	pub const fn location(&self) -> Location
+	{
+		self.location
+	}
+
+	pub fn message(&self) -> &str
+	{
+		&self.message
+	}
+
+	pub const fn is_primary(&self) -> bool
+	{
+		self.primary
+	}
+}
+
+impl DiagnosticSeverity
+{
+	/// Identifiant de la sévérité, ex: pour la représentation JSON.
+	pub const fn name(&self) -> &'static str
+	{
+		match self {
+			| Self::Info => "info",
+			| Self::Warning => "warning",
+			| Self::Error => "error",
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for DiagnosticSeverity
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		let label = match self {
+			| Self::Info => "info",
+			| Self::Warning => "avertissement",
+			| Self::Error => "erreur",
+		};
+		write!(f, "{label}")
+	}
+}
+
+// EXAMPLE(output): erreur[eof-in-tag]: Fin du flux dans la balise, à la
+// position Ln 1, Col 9
+impl fmt::Display for Diagnostic
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{}", self.severity)?;
+		if let Some(code) = self.code.as_deref() {
+			write!(f, "[{code}]")?;
+		}
+		write!(f, ": {}", self.message)?;
+		if let Some(location) = self.location() {
+			write!(f, ", à la position {location}")?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,343 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ops::Range;
+
+use super::{Diagnostic, DiagnosticLabel, DiagnosticSeverity};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Largeur d'une tabulation dans les extraits de la source.
+const TAB_WIDTH: usize = 4;
+
+/// Style ANSI de la gouttière et des emplacements secondaires.
+const GUTTER_STYLE: &str = "1;34";
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Affiche les diagnostics accompagnés d'extraits de la source, ex:
+///
+/// ```text
+/// erreur[eof-in-tag]: Fin du flux dans la balise
+///  --> index.html:2:5
+///   |
+/// 1 | <div>
+///   | ----- élément parent
+/// 2 |     <p id=a
+///   |     ^^ balise ouverte ici
+///   |
+///   = note: ajoutez `>`
+/// ```
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+pub struct DiagnosticRenderer<'a>
+{
+	source: &'a str,
+	/// Nom de la source, ex: le nom du fichier ou l'URL.
+	origin: Option<&'a str>,
+	/// Colore la sortie avec les séquences d'échappement ANSI.
+	colored: bool,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<'a> DiagnosticRenderer<'a>
+{
+	pub const fn new(source: &'a str) -> Self
+	{
+		Self {
+			source,
+			origin: None,
+			colored: false,
+		}
+	}
+
+	pub fn with_origin(mut self, origin: &'a str) -> Self
+	{
+		self.origin.replace(origin);
+		self
+	}
+
+	pub fn with_colors(mut self, colored: bool) -> Self
+	{
+		self.colored = colored;
+		self
+	}
+}
+
+impl DiagnosticRenderer<'_>
+{
+	/// Affiche le diagnostic, chaque ligne terminée par un saut de ligne.
+	pub fn render(&self, diagnostic: &Diagnostic) -> String
+	{
+		let severity_style = match diagnostic.severity() {
+			| DiagnosticSeverity::Info => "1;36",
+			| DiagnosticSeverity::Warning => "1;33",
+			| DiagnosticSeverity::Error => "1;31",
+		};
+
+		let header = match diagnostic.code() {
+			| Some(code) => format!("{}[{code}]", diagnostic.severity()),
+			| None => diagnostic.severity().to_string(),
+		};
+		let mut lines = vec![format!(
+			"{}{}",
+			self.paint(severity_style, &header),
+			self.paint("1", &format!(": {}", diagnostic.message()))
+		)];
+
+		let mut labels = diagnostic.labels().iter().collect::<Vec<_>>();
+		labels.sort_by_key(|label| {
+			let start = label.location().start();
+			(start.line(), start.column(), !label.is_primary())
+		});
+
+		let width = labels
+			.last()
+			.map_or(1, |label| label.location().line().to_string().len());
+		let pad = " ".repeat(width);
+
+		if let Some(location) = diagnostic.location() {
+			let origin = self
+				.origin
+				.map(|origin| format!("{origin}:"))
+				.unwrap_or_default();
+			lines.push(format!(
+				"{pad}{} {origin}{}:{}",
+				self.paint(GUTTER_STYLE, "-->"),
+				location.line(),
+				location.column()
+			));
+			lines.push(format!("{pad} {}", self.paint(GUTTER_STYLE, "|")));
+		}
+
+		let mut previous_line = None;
+		for label in labels.iter() {
+			let line = label.location().line();
+			if previous_line != Some(line) {
+				if previous_line.is_some_and(|previous| line > previous + 1) {
+					lines.push(self.paint(GUTTER_STYLE, "..."));
+				}
+				let bounds =
+					self.line_bounds(label.location().start().offset());
+				lines.push(format!(
+					"{} {}",
+					self.paint(GUTTER_STYLE, &format!("{line:>width$} |")),
+					self.source[bounds].replace('\t', &" ".repeat(TAB_WIDTH))
+				));
+				previous_line.replace(line);
+			}
+			lines.push(format!(
+				"{pad} {} {}",
+				self.paint(GUTTER_STYLE, "|"),
+				self.underline(label, severity_style)
+			));
+		}
+
+		if !diagnostic.notes().is_empty() {
+			if !labels.is_empty() {
+				lines.push(format!("{pad} {}", self.paint(GUTTER_STYLE, "|")));
+			}
+			for note in diagnostic.notes() {
+				lines.push(format!(
+					"{pad} {} note: {note}",
+					self.paint(GUTTER_STYLE, "=")
+				));
+			}
+		}
+
+		lines.into_iter().fold(String::new(), |mut output, line| {
+			output.push_str(line.trim_end());
+			output.push('\n');
+			output
+		})
+	}
+
+	/// Affiche les diagnostics, séparés par une ligne vide.
+	pub fn render_all<'d>(
+		&self,
+		diagnostics: impl IntoIterator<Item = &'d Diagnostic>,
+	) -> String
+	{
+		diagnostics
+			.into_iter()
+			.map(|diagnostic| self.render(diagnostic))
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
+	/// Soulignement de l'emplacement sous l'extrait de sa première ligne,
+	/// suivi de son message.
+	fn underline(&self, label: &DiagnosticLabel, severity_style: &str)
+		-> String
+	{
+		let location = label.location();
+		let start = self.char_boundary(location.start().offset());
+		let bounds = self.line_bounds(start);
+		let end = self.char_boundary(location.end().offset());
+		let end = end.clamp(start, bounds.end);
+
+		let (mark, style) = if label.is_primary() {
+			("^", severity_style)
+		} else {
+			("-", GUTTER_STYLE)
+		};
+
+		let mut underline =
+			mark.repeat(display_width(&self.source[start..end]).max(1));
+		if !label.message().is_empty() {
+			underline.push(' ');
+			underline.push_str(label.message());
+		}
+
+		format!(
+			"{}{}",
+			" ".repeat(display_width(&self.source[bounds.start..start])),
+			self.paint(style, &underline)
+		)
+	}
+
+	/// Intervalle d'octets de la ligne contenant le décalage, sans sa fin de
+	/// ligne.
+	fn line_bounds(&self, offset: usize) -> Range<usize>
+	{
+		let offset = self.char_boundary(offset);
+		let start = self.source[..offset]
+			.rfind(['\n', '\r'])
+			.map_or(0, |index| index + 1);
+		let end = self.source[offset..]
+			.find(['\n', '\r'])
+			.map_or(self.source.len(), |index| offset + index);
+		start..end
+	}
+
+	/// Décalage valide dans la source, au plus proche du décalage donné, ex:
+	/// pour un emplacement obtenu sur un flux d'octets.
+	fn char_boundary(&self, offset: usize) -> usize
+	{
+		let mut offset = offset.min(self.source.len());
+		while !self.source.is_char_boundary(offset) {
+			offset -= 1;
+		}
+		offset
+	}
+
+	fn paint(&self, style: &str, text: &str) -> String
+	{
+		if self.colored {
+			format!("\x1b[{style}m{text}\x1b[0m")
+		} else {
+			text.to_owned()
+		}
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Largeur à l'affichage d'un extrait de la source.
+fn display_width(text: &str) -> usize
+{
+	text.chars()
+		.map(|ch| if ch == '\t' { TAB_WIDTH } else { 1 })
+		.sum()
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::location::{Location, Position};
+
+	/// Emplacement de l'intervalle d'octets dans la source.
+	fn location(source: &str, range: Range<usize>) -> Location
+	{
+		let mut start = Position::new();
+		for ch in source[..range.start].chars() {
+			start.advance(ch);
+		}
+		let mut end = start;
+		for ch in source[range].chars() {
+			end.advance(ch);
+		}
+		Location::between(start, end)
+	}
+
+	#[test]
+	fn test_render()
+	{
+		let source = "<div>\n\t<p id=a\n</div>";
+		let diagnostic = Diagnostic::error("Balise non fermée")
+			.with_code("eof-in-tag")
+			.with_primary_label(location(source, 7..9), "balise ouverte ici")
+			.with_secondary_label(location(source, 0..5), "élément parent")
+			.with_note("ajoutez `>`");
+
+		let renderer =
+			DiagnosticRenderer::new(source).with_origin("index.html");
+		assert_eq!(
+			renderer.render(&diagnostic),
+			"erreur[eof-in-tag]: Balise non fermée\n --> index.html:2:2\n  \
+			 |\n1 | <div>\n  | ----- élément parent\n2 |     <p id=a\n  |     \
+			 ^^ balise ouverte ici\n  |\n  = note: ajoutez `>`\n"
+		);
+
+		let colored = renderer.with_colors(true).render(&diagnostic);
+		assert!(colored.starts_with("\x1b[1;31merreur[eof-in-tag]\x1b[0m"));
+		assert!(colored.contains("\x1b[1;34m-----"));
+	}
+
+	#[test]
+	fn test_render_distant_lines()
+	{
+		let source = "a\nb\nc\nd";
+		let diagnostic = Diagnostic::warning("Attention")
+			.with_primary_label(location(source, 6..7), "")
+			.with_secondary_label(location(source, 0..1), "début");
+
+		assert_eq!(
+			DiagnosticRenderer::new(source).render(&diagnostic),
+			"avertissement: Attention\n --> 4:1\n  |\n1 | a\n  | - \
+			 début\n...\n4 | d\n  | ^\n"
+		);
+	}
+
+	#[test]
+	fn test_render_end_of_source()
+	{
+		let source = "<p\r\n<b";
+		let diagnostic = Diagnostic::error("Fin du flux dans la balise")
+			.with_primary_label(location(source, 6..6), "")
+			.with_primary_label(location(source, 0..4), "");
+
+		assert_eq!(
+			DiagnosticRenderer::new(source).render(&diagnostic),
+			"erreur: Fin du flux dans la balise\n --> 2:3\n  |\n1 | <p\n  | \
+			 ^^\n2 | <b\n  |   ^\n"
+		);
+
+		let diagnostic = Diagnostic::info("Sans emplacement").with_note("note");
+		assert_eq!(
+			DiagnosticRenderer::new(source).render(&diagnostic),
+			"info: Sans emplacement\n  = note: note\n"
+		);
+	}
+}
@@ -9,9 +9,13 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 mod codepoint;
+mod diagnostic;
 mod location;
+mod source;
 mod stream;
 
 pub use self::codepoint::*;
+pub use self::diagnostic::*;
 pub use self::location::*;
+pub use self::source::*;
 pub use self::stream::*;
@@ -14,100 +14,191 @@ use std::fmt;
 // Structure //
 // --------- //
 
+/// Position d'un point de code dans la source.
 #[derive(Debug)]
 #[derive(Copy, Clone)]
-pub struct Location
+#[derive(PartialEq, Eq)]
+pub struct Position
 {
-	start: usize,
-	end: usize,
-	column: usize,
+	/// Décalage en octets depuis le début de la source.
+	offset: usize,
+	/// Décalage en points de code depuis le début de la source.
+	char_offset: usize,
+	/// Numéro de ligne, à partir de 1.
 	line: usize,
+	/// Numéro de colonne, à partir de 1, compté en points de code.
+	column: usize,
+	/// Le dernier point de code est un retour chariot: un saut de ligne qui
+	/// le suit ne commence pas une nouvelle ligne.
+	after_carriage_return: bool,
+}
+
+/// Emplacement d'un élément dans la source: du premier point de code
+/// (inclus) au dernier point de code (exclus).
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub struct Location
+{
+	start: Position,
+	end: Position,
 }
 
 // -------------- //
 // Implémentation //
 // -------------- //
 
-impl Location
+impl Position
 {
 	pub const fn new() -> Self
 	{
 		Self {
-			start: 0,
-			end: 0,
-			column: 1,
+			offset: 0,
+			char_offset: 0,
 			line: 1,
+			column: 1,
+			after_carriage_return: false,
+		}
+	}
+
+	/// Position dont toutes les composantes sont connues, ex: calculée depuis
+	/// la table des lignes d'une [SourceFile](crate::SourceFile).
+	pub(crate) const fn from_parts(
+		offset: usize,
+		char_offset: usize,
+		line: usize,
+		column: usize,
+		after_carriage_return: bool,
+	) -> Self
+	{
+		Self {
+			offset,
+			char_offset,
+			line,
+			column,
+			after_carriage_return,
 		}
 	}
 }
 
-impl Location
+impl Position
 {
-	/// Incrémente une colonne de 1.
-	pub fn increment_column(&mut self) -> &mut Self
+	/// Décalage en octets depuis le début de la source.
+	pub const fn offset(&self) -> usize
 	{
-		self.column = self.column.saturating_add(1);
-		self.increment_end()
+		self.offset
 	}
 
-	/// Incrémente une colonne de N.
-	pub fn increment_column_by(&mut self, n: usize) -> &mut Self
+	/// Décalage en points de code depuis le début de la source.
+	pub const fn char_offset(&self) -> usize
 	{
-		self.column = self.column.saturating_add(n);
-		self.increment_end_by(n)
+		self.char_offset
 	}
 
-	/// Incrémente une colonne de 1.
-	pub fn increment_column_if_zeroed(&mut self) -> &mut Self
+	/// Numéro de ligne, à partir de 1.
+	pub const fn line(&self) -> usize
 	{
-		if self.column == 0 {
-			self.column = self.column.saturating_add(1);
+		self.line
+	}
+
+	/// Numéro de colonne, à partir de 1.
+	pub const fn column(&self) -> usize
+	{
+		self.column
+	}
+
+	/// Avance la position après le point de code donné.
+	///
+	/// Une tabulation compte pour une seule colonne, libre à l'affichage
+	/// d'appliquer sa propre largeur de tabulation. Les fins de ligne sont
+	/// normalisées: un retour chariot isolé compte pour un saut de ligne, et
+	/// une fin de ligne CRLF ne compte que pour un seul.
+	pub fn advance(&mut self, ch: char) -> &mut Self
+	{
+		self.advance_by(ch, ch.len_utf8())
+	}
+
+	/// Avance la position après une unité de `len` octets, ex: un octet
+	/// d'une source qui n'est pas encodée en UTF-8.
+	pub fn advance_by(&mut self, ch: char, len: usize) -> &mut Self
+	{
+		self.offset = self.offset.saturating_add(len);
+		self.char_offset = self.char_offset.saturating_add(1);
+
+		let after_carriage_return =
+			std::mem::replace(&mut self.after_carriage_return, ch == '\r');
+
+		match ch {
+			| '\n' if after_carriage_return => {}
+			| '\n' | '\r' => {
+				self.line = self.line.saturating_add(1);
+				self.column = 1;
+			}
+			| _ => {
+				self.column = self.column.saturating_add(1);
+			}
 		}
+
 		self
 	}
+}
 
-	/// Incrémente la fin de 1.
-	pub fn increment_end(&mut self) -> &mut Self
+impl Location
+{
+	pub const fn new() -> Self
 	{
-		self.end = self.end.saturating_add(1);
-		self
+		Self {
+			start: Position::new(),
+			end: Position::new(),
+		}
 	}
 
-	/// Incrémente la fin de N.
-	pub fn increment_end_by(&mut self, n: usize) -> &mut Self
+	/// Emplacement vide à la position donnée.
+	pub const fn at(position: Position) -> Self
 	{
-		self.end = self.end.saturating_add(n);
-		self
+		Self {
+			start: position,
+			end: position,
+		}
 	}
 
-	/// Incrémente une ligne de 1.
-	pub fn increment_line(&mut self) -> &mut Self
+	/// Emplacement compris entre deux positions.
+	pub const fn between(start: Position, end: Position) -> Self
 	{
-		self.line = self.line.saturating_add(1);
-		self.increment_end()
+		Self { start, end }
 	}
+}
 
-	/// Incrémente une ligne de N.
-	pub fn increment_line_by(&mut self, n: usize) -> &mut Self
+impl Location
+{
+	/// Position du premier point de code.
+	pub const fn start(&self) -> Position
 	{
-		self.line = self.line.saturating_add(n);
-		self.increment_end_by(n)
+		self.start
 	}
 
-	/// Incrémente une ligne de 1 si la ligne vaut zero.
-	pub fn increment_line_if_zeroed(&mut self) -> &mut Self
+	/// Position suivant le dernier point de code.
+	pub const fn end(&self) -> Position
 	{
-		if self.line == 0 {
-			self.line = self.line.saturating_add(1);
-		}
-		self
+		self.end
 	}
 
-	/// Réinitialise la colonne.
-	pub fn reset_column(&mut self) -> &mut Self
+	/// Numéro de ligne du premier point de code.
+	pub const fn line(&self) -> usize
 	{
-		self.column = 1;
-		self
+		self.start.line
+	}
+
+	/// Numéro de colonne du premier point de code.
+	pub const fn column(&self) -> usize
+	{
+		self.start.column
+	}
+
+	/// Intervalle d'octets de l'emplacement dans la source.
+	pub const fn range(&self) -> std::ops::Range<usize>
+	{
+		self.start.offset..self.end.offset
 	}
 }
 
@@ -115,16 +206,19 @@ impl Location
 // Implémentation // -> Interface
 // -------------- //
 
+impl Default for Position
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
 impl Default for Location
 {
 	fn default() -> Self
 	{
-		Self {
-			start: 0,
-			end: 0,
-			column: 1,
-			line: 1,
-		}
+		Self::new()
 	}
 }
 
@@ -133,10 +227,49 @@ impl fmt::Display for Location
 {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
 	{
-		let ln = format!("Ln {}", if self.line == 0 { 1 } else { self.line });
-		let col =
-			format!("Col {}", if self.column == 0 { 1 } else { self.column });
-		let ln_col = format!("{ln}, {col}");
-		write!(f, "{}", ln_col)
+		write!(f, "Ln {}, Col {}", self.line(), self.column())
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_position_advance()
+	{
+		let mut position = Position::new();
+		for ch in "a\tb\r\né".chars() {
+			position.advance(ch);
+		}
+
+		assert_eq!(position.offset(), 7);
+		assert_eq!(position.char_offset(), 6);
+		assert_eq!(position.line(), 2);
+		assert_eq!(position.column(), 2);
+
+		let mut position = Position::new();
+		for ch in "a\rb\n\r\r\nc".chars() {
+			position.advance(ch);
+		}
+		assert_eq!(position.line(), 5);
+		assert_eq!(position.column(), 2);
+	}
+
+	#[test]
+	fn test_location_display()
+	{
+		let mut end = Position::new();
+		end.advance('\n').advance('x');
+
+		let location = Location::between(Position::new(), end);
+		assert_eq!(location.to_string(), "Ln 1, Col 1");
+		assert_eq!(location.range(), 0..2);
+		assert_eq!(Location::at(end).to_string(), "Ln 2, Col 2");
 	}
 }
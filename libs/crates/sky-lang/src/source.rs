@@ -0,0 +1,287 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ops::Range;
+
+use crate::location::{Location, Position};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Texte d'une source accompagné de la table de ses débuts de ligne, pour
+/// retrouver la position d'un décalage (et inversement) en O(log n).
+///
+/// Les fins de ligne sont celles de [Position::advance]: `\n`, `\r` et
+/// `\r\n`.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct SourceFile
+{
+	text: String,
+	/// Débuts de ligne, triés par décalage. La première ligne commence au
+	/// décalage 0.
+	lines: Vec<LineStart>,
+}
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+struct LineStart
+{
+	/// Décalage en octets.
+	offset: usize,
+	/// Décalage en points de code.
+	char_offset: usize,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl SourceFile
+{
+	pub fn new(text: impl Into<String>) -> Self
+	{
+		let text = text.into();
+
+		let mut lines = vec![LineStart {
+			offset: 0,
+			char_offset: 0,
+		}];
+		let mut chars = text.char_indices().enumerate().peekable();
+		while let Some((char_offset, (offset, ch))) = chars.next() {
+			let (char_offset, offset) = match ch {
+				| '\r' if chars.next_if(|(_, (_, ch))| *ch == '\n').is_some() => {
+					(char_offset + 2, offset + 2)
+				}
+				| '\r' | '\n' => (char_offset + 1, offset + 1),
+				| _ => continue,
+			};
+			lines.push(LineStart {
+				offset,
+				char_offset,
+			});
+		}
+
+		Self { text, lines }
+	}
+}
+
+impl SourceFile
+{
+	pub fn text(&self) -> &str
+	{
+		&self.text
+	}
+
+	/// Nombre de lignes, une source vide ou terminée par une fin de ligne
+	/// comprenant une dernière ligne vide.
+	pub fn line_count(&self) -> usize
+	{
+		self.lines.len()
+	}
+
+	/// Intervalle d'octets de la ligne (à partir de 1), sans sa fin de ligne.
+	pub fn line_range(&self, line: usize) -> Option<Range<usize>>
+	{
+		let start = self.lines.get(line.checked_sub(1)?)?.offset;
+		let end = self
+			.lines
+			.get(line)
+			.map_or(self.text.len(), |next| next.offset);
+		let content = self.text[start..end].trim_end_matches(['\r', '\n']);
+		Some(start..start + content.len())
+	}
+
+	/// Texte de la ligne (à partir de 1), sans sa fin de ligne.
+	pub fn line(&self, line: usize) -> Option<&str>
+	{
+		self.line_range(line).map(|range| &self.text[range])
+	}
+
+	/// Position du décalage en octets, `None` s'il est hors de la source ou
+	/// au milieu d'un point de code.
+	pub fn position(&self, offset: usize) -> Option<Position>
+	{
+		if !self.text.is_char_boundary(offset) {
+			return None;
+		}
+
+		let index =
+			self.lines.partition_point(|line| line.offset <= offset) - 1;
+		let line_start = self.lines[index];
+		let before = &self.text[line_start.offset..offset];
+		let char_offset = line_start.char_offset + before.chars().count();
+		let after_carriage_return = self.text[..offset].ends_with('\r');
+
+		// NOTE: entre le `\r` et le `\n` d'une fin de ligne CRLF, la position
+		// est déjà sur la ligne suivante, comme pour [Position::advance].
+		let (line, column) = if before.ends_with('\r') {
+			(index + 2, 1)
+		} else {
+			(index + 1, before.chars().count() + 1)
+		};
+
+		Some(Position::from_parts(
+			offset,
+			char_offset,
+			line,
+			column,
+			after_carriage_return,
+		))
+	}
+
+	/// Emplacement de l'intervalle d'octets.
+	pub fn location(&self, range: Range<usize>) -> Option<Location>
+	{
+		if range.start > range.end {
+			return None;
+		}
+		Some(Location::between(
+			self.position(range.start)?,
+			self.position(range.end)?,
+		))
+	}
+
+	/// Décalage en octets de la position (ligne et colonne à partir de 1, la
+	/// colonne comptée en points de code). La colonne peut désigner la fin de
+	/// la ligne.
+	pub fn offset_at(&self, line: usize, column: usize) -> Option<usize>
+	{
+		let range = self.line_range(line)?;
+		let mut offsets = self.text[range.clone()]
+			.char_indices()
+			.map(|(offset, _)| range.start + offset)
+			.chain([range.end]);
+		offsets.nth(column.checked_sub(1)?)
+	}
+
+	/// Colonne (à partir de 1) du décalage en octets, comptée en unités
+	/// UTF-16, ex: pour les positions des éditeurs ou des outils de
+	/// développement.
+	pub fn utf16_column(&self, offset: usize) -> Option<usize>
+	{
+		let position = self.position(offset)?;
+		if position.column() == 1 {
+			return Some(1);
+		}
+		let line_start = self.lines[position.line() - 1].offset;
+		Some(self.text[line_start..offset].encode_utf16().count() + 1)
+	}
+
+	/// Décalage en octets de la position, la colonne (à partir de 1) étant
+	/// comptée en unités UTF-16. `None` si la colonne tombe au milieu d'une
+	/// paire de substitution.
+	pub fn offset_at_utf16(&self, line: usize, column: usize) -> Option<usize>
+	{
+		let range = self.line_range(line)?;
+		let mut units = column.checked_sub(1)?;
+		for (offset, ch) in self.text[range.clone()].char_indices() {
+			if units == 0 {
+				return Some(range.start + offset);
+			}
+			units = units.checked_sub(ch.len_utf16())?;
+		}
+		(units == 0).then_some(range.end)
+	}
+
+	/// Extrait de la source compris dans l'emplacement.
+	pub fn slice(&self, location: Location) -> Option<&str>
+	{
+		self.text.get(location.range())
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	const SOURCE: &str = "<p>\r\nhé\r\n\tx\r\r\n𝄞a\n";
+
+	#[test]
+	fn test_lines()
+	{
+		let source = SourceFile::new(SOURCE);
+		assert_eq!(source.line_count(), 6);
+		assert_eq!(source.line(1), Some("<p>"));
+		assert_eq!(source.line(2), Some("hé"));
+		assert_eq!(source.line(3), Some("\tx"));
+		assert_eq!(source.line(4), Some(""));
+		assert_eq!(source.line(5), Some("𝄞a"));
+		assert_eq!(source.line(6), Some(""));
+		assert_eq!(source.line(0), None);
+		assert_eq!(source.line(7), None);
+
+		assert_eq!(SourceFile::new("").line_count(), 1);
+	}
+
+	#[test]
+	fn test_position_matches_advance()
+	{
+		let source = SourceFile::new(SOURCE);
+
+		let mut expected = Position::new();
+		for (offset, ch) in SOURCE.char_indices() {
+			assert_eq!(source.position(offset), Some(expected), "{offset}");
+			expected.advance(ch);
+		}
+		assert_eq!(source.position(SOURCE.len()), Some(expected));
+
+		assert_eq!(source.position(SOURCE.len() + 1), None);
+		assert_eq!(source.position(7), None);
+	}
+
+	#[test]
+	fn test_offset_at()
+	{
+		let source = SourceFile::new(SOURCE);
+		assert_eq!(source.offset_at(2, 1), Some(5));
+		assert_eq!(source.offset_at(2, 3), Some(8));
+		assert_eq!(source.offset_at(2, 4), None);
+		assert_eq!(source.offset_at(5, 2), Some(19));
+
+		for offset in [0, 6, 13, 19, 20] {
+			let position = source.position(offset).unwrap();
+			assert_eq!(
+				source.offset_at(position.line(), position.column()),
+				Some(offset)
+			);
+		}
+	}
+
+	#[test]
+	fn test_utf16_column()
+	{
+		let source = SourceFile::new(SOURCE);
+		assert_eq!(source.utf16_column(8), Some(3));
+		assert_eq!(source.utf16_column(19), Some(3));
+		assert_eq!(source.position(19).map(|p| p.column()), Some(2));
+
+		assert_eq!(source.offset_at_utf16(5, 3), Some(19));
+		assert_eq!(source.offset_at_utf16(5, 2), None);
+		assert_eq!(source.offset_at_utf16(5, 4), Some(20));
+		assert_eq!(source.offset_at_utf16(5, 5), None);
+	}
+
+	#[test]
+	fn test_slice()
+	{
+		let source = SourceFile::new(SOURCE);
+		let location = source.location(5..8).unwrap();
+		assert_eq!(source.slice(location), Some("hé"));
+		assert_eq!((location.line(), location.column()), (2, 1));
+		assert_eq!(source.location(Range { start: 8, end: 5 }), None);
+	}
+}
@@ -0,0 +1,265 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2024, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Décode un flux d'octets UTF-8 en points de code, au fur et à mesure de la
+/// lecture. Les séquences invalides sont remplacées par U+FFFD, selon
+/// l'algorithme de décodage UTF-8 du standard Encoding; la marque d'ordre des
+/// octets (BOM) initiale est ignorée.
+///
+/// Voir <https://encoding.spec.whatwg.org/#utf-8-decoder>
+#[derive(Debug)]
+pub struct Utf8Decoder<Bytes: Iterator<Item = u8>>
+{
+	bytes: Bytes,
+	/// Octet lu mais pas encore décodé, remis dans le flux après une
+	/// séquence invalide.
+	pending: Option<u8>,
+	/// Position, en octets, du prochain point de code à décoder.
+	offset: usize,
+}
+
+/// Octets d'un lecteur, ex: un fichier ou le corps d'une réponse HTTP. La
+/// lecture s'arrête à la première erreur, conservée pour être consultée.
+#[derive(Debug)]
+pub struct ReadBytes<R>
+{
+	bytes: io::Bytes<io::BufReader<R>>,
+	error: Option<io::Error>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<Bytes: Iterator<Item = u8>> Utf8Decoder<Bytes>
+{
+	pub fn new(bytes: Bytes) -> Self
+	{
+		Self {
+			bytes,
+			pending: Default::default(),
+			offset: Default::default(),
+		}
+	}
+
+	/// Position, en octets, du prochain point de code à décoder.
+	pub const fn offset(&self) -> usize
+	{
+		self.offset
+	}
+
+	fn next_byte(&mut self) -> Option<u8>
+	{
+		let byte = self.pending.take().or_else(|| self.bytes.next())?;
+		self.offset += 1;
+		Some(byte)
+	}
+
+	fn restore_byte(&mut self, byte: u8)
+	{
+		self.pending.replace(byte);
+		self.offset -= 1;
+	}
+
+	fn decode_next(&mut self) -> Option<char>
+	{
+		let first = self.next_byte()?;
+
+		let (bits, bytes_needed, mut lower, mut upper) = match first {
+			| 0x00..=0x7F => return Some(first as char),
+			| 0xC2..=0xDF => (first & 0x1F, 1, 0x80, 0xBF),
+			| 0xE0 => (first & 0x0F, 2, 0xA0, 0xBF),
+			| 0xED => (first & 0x0F, 2, 0x80, 0x9F),
+			| 0xE1..=0xEF => (first & 0x0F, 2, 0x80, 0xBF),
+			| 0xF0 => (first & 0x07, 3, 0x90, 0xBF),
+			| 0xF4 => (first & 0x07, 3, 0x80, 0x8F),
+			| 0xF1..=0xF3 => (first & 0x07, 3, 0x80, 0xBF),
+			| _ => return Some(char::REPLACEMENT_CHARACTER),
+		};
+		let mut code_point = bits as u32;
+
+		for _ in 0..bytes_needed {
+			let Some(byte) = self.next_byte() else {
+				return Some(char::REPLACEMENT_CHARACTER);
+			};
+			if !(lower..=upper).contains(&byte) {
+				self.restore_byte(byte);
+				return Some(char::REPLACEMENT_CHARACTER);
+			}
+			(lower, upper) = (0x80, 0xBF);
+			code_point = (code_point << 6) | (byte & 0x3F) as u32;
+		}
+
+		Some(char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER))
+	}
+}
+
+impl<R: io::Read> Utf8Decoder<ReadBytes<R>>
+{
+	/// Décode les octets du lecteur, lus par blocs.
+	pub fn from_reader(reader: R) -> Self
+	{
+		Self::new(ReadBytes::new(reader))
+	}
+
+	/// Erreur de lecture ayant interrompu le décodage.
+	pub fn take_error(&mut self) -> Option<io::Error>
+	{
+		self.bytes.error.take()
+	}
+}
+
+impl<R: io::Read> ReadBytes<R>
+{
+	pub fn new(reader: R) -> Self
+	{
+		Self {
+			bytes: io::Read::bytes(io::BufReader::new(reader)),
+			error: Default::default(),
+		}
+	}
+
+	/// Erreur de lecture ayant interrompu la lecture.
+	pub fn take_error(&mut self) -> Option<io::Error>
+	{
+		self.error.take()
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<Bytes: Iterator<Item = u8>> Iterator for Utf8Decoder<Bytes>
+{
+	type Item = char;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let at_start = self.offset == 0;
+		let ch = self.decode_next()?;
+		if at_start && ch == '\u{FEFF}' {
+			return self.decode_next();
+		}
+		Some(ch)
+	}
+}
+
+impl<R: io::Read> Iterator for ReadBytes<R>
+{
+	type Item = u8;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.error.is_some() {
+			return None;
+		}
+
+		match self.bytes.next()? {
+			| Ok(byte) => Some(byte),
+			| Err(err) => {
+				self.error.replace(err);
+				None
+			}
+		}
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::{InputStream, UnitCodePointExt};
+
+	fn decode(bytes: &[u8]) -> String
+	{
+		Utf8Decoder::new(bytes.iter().copied()).collect()
+	}
+
+	#[test]
+	fn test_decode_valid_sequences()
+	{
+		assert_eq!(decode("Héllo €𝄞".as_bytes()), "Héllo €𝄞");
+		assert_eq!(decode(b"\xEF\xBB\xBFa\xEF\xBB\xBF"), "a\u{FEFF}");
+	}
+
+	#[test]
+	fn test_decode_invalid_sequences()
+	{
+		// NOTE: chaque sous-partie maximale d'une séquence invalide est
+		// remplacée par un seul U+FFFD.
+		assert_eq!(decode(b"a\x80b"), "a\u{FFFD}b");
+		assert_eq!(decode(b"\xF0\x9F\x92"), "\u{FFFD}");
+		assert_eq!(decode(b"\xF0\x9F\x92a"), "\u{FFFD}a");
+		assert_eq!(decode(b"\xE0\x80\xAF"), "\u{FFFD}\u{FFFD}\u{FFFD}");
+		assert_eq!(decode(b"\xED\xA0\x80"), "\u{FFFD}\u{FFFD}\u{FFFD}");
+		assert_eq!(decode(b"\xC0\xAF\xFF"), "\u{FFFD}\u{FFFD}\u{FFFD}");
+		assert_eq!(decode(b"\xF4\x90\x80\x80"), "\u{FFFD}".repeat(4));
+	}
+
+	#[test]
+	fn test_offset()
+	{
+		let mut decoder =
+			Utf8Decoder::new(b"a\xC3\xA9\x80\xE2\x82\xAC\xE2".iter().copied());
+		let mut offsets = Vec::new();
+		while let Some(ch) = decoder.next() {
+			offsets.push((ch, decoder.offset()));
+		}
+		assert_eq!(
+			offsets,
+			[
+				('a', 1),
+				('é', 3),
+				('\u{FFFD}', 4),
+				('€', 7),
+				('\u{FFFD}', 8)
+			]
+		);
+	}
+
+	#[test]
+	fn test_from_reader()
+	{
+		let mut decoder = Utf8Decoder::from_reader("Ça va?".as_bytes());
+		assert_eq!(decoder.by_ref().collect::<String>(), "Ça va?");
+		assert!(decoder.take_error().is_none());
+
+		struct Failing;
+		impl io::Read for Failing
+		{
+			fn read(&mut self, _: &mut [u8]) -> io::Result<usize>
+			{
+				Err(io::Error::other("échec"))
+			}
+		}
+		let mut decoder = Utf8Decoder::from_reader(Failing);
+		assert_eq!(decoder.next(), None);
+		assert_eq!(decoder.take_error().unwrap().to_string(), "échec");
+	}
+
+	#[test]
+	fn test_input_stream()
+	{
+		let mut input_stream = InputStream::new(Utf8Decoder::new("é!".bytes()));
+		assert!(input_stream.consume_next().unwrap().is__non_ascii());
+		assert_eq!(input_stream.consume_next(), Some('!'));
+	}
+}
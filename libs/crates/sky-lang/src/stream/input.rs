@@ -9,24 +9,63 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use std::collections::VecDeque;
+use std::iter::Peekable;
 use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::codepoint::UnitCodePointExt;
+use crate::location::{Location, Position};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// Nombre d'éléments déjà consommés conservés, en dehors des points de
+/// reprise, pour [InputStream::rollback_once].
+const HISTORY_LENGTH: usize = 8;
+
+/// Identifiant du prochain point de reprise, unique pour tous les flux.
+static NEXT_CHECKPOINT_ID: AtomicUsize = AtomicUsize::new(0);
 
 // --------- //
 // Structure //
 // --------- //
 
+/// Flux d'éléments lus depuis une source.
+///
+/// Les fins de ligne sont normalisées: une paire CRLF ou un CR isolé sont lus
+/// comme un seul LF. Les positions restent celles de la source d'origine.
 #[derive(Debug)]
 pub struct InputStream<Input: Iterator>
 {
-	source: Input,
-	source_idx: usize,
+	source: Peekable<Input>,
+	/// Éléments lus depuis la source: les derniers éléments consommés (ainsi
+	/// que tous ceux qui suivent le plus ancien point de reprise), puis les
+	/// éléments lus par anticipation.
+	buffer: VecDeque<Input::Item>,
+	/// Nombre d'éléments retirés du début du tampon.
+	buffer_offset: usize,
+	/// Nombre d'éléments consommés: index du prochain élément.
+	index: usize,
+	/// Positions, dans la source, des éléments du tampon.
+	positions: VecDeque<Position>,
+	/// Position, dans la source, après le dernier élément lu.
+	source_position: Position,
 	current_item: Option<Input::Item>,
-	temporary_list: VecDeque<Input::Item>,
-	look_ahead_offset: u8,
-	recycle_list: Vec<Input::Item>,
-	should_continue_peek: usize,
+	/// Identifiants et index des points de reprise actifs, du plus ancien au
+	/// plus récent.
+	checkpoints: Vec<(usize, usize)>,
+}
+
+/// Point de reprise du flux, voir [InputStream::mark].
+#[derive(Debug)]
+#[must_use]
+pub struct InputCheckpoint
+{
+	id: usize,
+	index: usize,
+	position: Position,
+	depth: usize,
 }
 
 // -------------- //
@@ -38,56 +77,125 @@ impl<Input: Iterator> InputStream<Input>
 	pub fn new(input: Input) -> Self
 	{
 		Self {
-			source: input,
-			source_idx: 0,
+			source: input.peekable(),
+			buffer: Default::default(),
+			buffer_offset: Default::default(),
+			index: Default::default(),
+			positions: Default::default(),
+			source_position: Default::default(),
 			current_item: Default::default(),
-			temporary_list: Default::default(),
-			look_ahead_offset: Default::default(),
-			recycle_list: Default::default(),
-			should_continue_peek: Default::default(),
+			checkpoints: Default::default(),
 		}
 	}
 }
 
 impl<Input: Iterator> InputStream<Input>
 {
-	fn decrement_look_ahead(&mut self)
+	/// Position du prochain élément dans la source.
+	pub fn position(&self) -> Position
 	{
-		self.look_ahead_offset = self.look_ahead_offset.saturating_sub(1);
+		self.positions
+			.get(self.cursor())
+			.copied()
+			.unwrap_or(self.source_position)
 	}
 
-	fn enqueue(&mut self)
+	/// Emplacement des éléments consommés depuis le point de reprise.
+	pub fn location_since(&self, checkpoint: &InputCheckpoint) -> Location
 	{
-		if let Some(item) = self.source.next() {
-			self.temporary_list.push_back(item);
-		}
+		Location::between(checkpoint.position, self.position())
 	}
 
-	fn fill(&mut self, required_elements: u8)
+	/// Position du prochain élément dans le tampon.
+	fn cursor(&self) -> usize
 	{
-		let stored_elements = self.temporary_list.len();
-		if stored_elements <= required_elements as usize {
-			for _ in stored_elements..=required_elements as usize {
-				self.enqueue();
-			}
+		self.index - self.buffer_offset
+	}
+
+	/// Retire du tampon les éléments consommés qui ne peuvent plus être
+	/// restitués. L'élément qui précède le plus ancien élément restituable
+	/// est conservé: il redevient l'élément courant après un retour en
+	/// arrière.
+	fn trim(&mut self)
+	{
+		let history_start = self.index.saturating_sub(HISTORY_LENGTH);
+		let keep_from = self
+			.checkpoints
+			.first()
+			.map_or(history_start, |(_, index)| history_start.min(*index))
+			.saturating_sub(1);
+
+		let excess = keep_from.saturating_sub(self.buffer_offset);
+		self.buffer.drain(..excess);
+		self.positions.drain(..excess);
+		self.buffer_offset += excess;
+	}
+
+	/// Crée un point de reprise à la position actuelle. Les points de reprise
+	/// peuvent être imbriqués; le flux conserve les éléments consommés depuis
+	/// le plus ancien d'entre eux.
+	pub fn mark(&mut self) -> InputCheckpoint
+	{
+		let id = NEXT_CHECKPOINT_ID.fetch_add(1, Ordering::Relaxed);
+		self.checkpoints.push((id, self.index));
+		InputCheckpoint {
+			id,
+			index: self.index,
+			position: self.position(),
+			depth: self.checkpoints.len() - 1,
 		}
 	}
 
-	fn take_or_next(&mut self) -> Option<Input::Item>
+	/// Abandonne le point de reprise (et ceux créés après lui), sans revenir
+	/// en arrière. Un point de reprise déjà abandonné, ou qui appartient à un
+	/// autre flux, est ignoré.
+	pub fn release(&mut self, checkpoint: InputCheckpoint)
 	{
-		if self.temporary_list.is_empty() {
-			self.source_idx += 1;
-			return self.source.next();
+		if !self.is_active(&checkpoint) {
+			return;
 		}
-		self.temporary_list.pop_front()
+		self.checkpoints.truncate(checkpoint.depth);
+		self.trim();
+	}
+
+	/// Vérifie que le point de reprise est actif dans ce flux.
+	fn is_active(&self, checkpoint: &InputCheckpoint) -> bool
+	{
+		self.checkpoints
+			.get(checkpoint.depth)
+			.is_some_and(|(id, _)| *id == checkpoint.id)
 	}
 }
 
 impl<Input> InputStream<Input>
 where
 	Input: Iterator,
-	Input::Item: Clone,
+	Input::Item: UnitCodePointExt,
 {
+	/// Lit, si besoin, les `n` prochains éléments de la source, et retourne le
+	/// nombre d'éléments disponibles (moins de `n` à la fin du flux).
+	fn fill(&mut self, n: usize) -> usize
+	{
+		while self.buffer.len() - self.cursor() < n {
+			let Some(item) = self.source.next() else {
+				break;
+			};
+			self.positions.push_back(self.source_position);
+			self.source_position
+				.advance_by(item.unit(), item.byte_len());
+
+			if !item.is('\r') {
+				self.buffer.push_back(item);
+				continue;
+			}
+			if let Some(lf) = self.source.next_if(|next| next.is('\n')) {
+				self.source_position.advance_by(lf.unit(), lf.byte_len());
+			}
+			self.buffer.push_back(UnitCodePointExt::line_feed());
+		}
+		n.min(self.buffer.len() - self.cursor())
+	}
+
 	pub fn advance(&mut self, n: usize)
 	{
 		for _ in 0..=n {
@@ -97,13 +205,41 @@ where
 
 	pub fn consume_next(&mut self) -> Option<Input::Item>
 	{
-		let next_item = self.take_or_next();
-		self.decrement_look_ahead();
+		let next_item =
+			(self.fill(1) == 1).then(|| self.buffer[self.cursor()].clone());
+		if next_item.is_some() {
+			self.index += 1;
+			self.trim();
+		}
 		self.current_item.clone_from(&next_item);
-		self.recycle_list.extend(next_item.clone());
 		next_item
 	}
 
+	/// Consomme le prochain élément s'il satisfait le prédicat.
+	pub fn consume_if(
+		&mut self,
+		predicate: impl FnOnce(&Input::Item) -> bool,
+	) -> Option<Input::Item>
+	{
+		if !self.peek_n(1).first().is_some_and(predicate) {
+			return None;
+		}
+		self.consume_next()
+	}
+
+	/// Consomme les prochains éléments tant qu'ils satisfont le prédicat.
+	pub fn consume_while(
+		&mut self,
+		mut predicate: impl FnMut(&Input::Item) -> bool,
+	) -> Vec<Input::Item>
+	{
+		let mut items = Vec::new();
+		while let Some(item) = self.consume_if(&mut predicate) {
+			items.push(item);
+		}
+		items
+	}
+
 	pub fn current(&self) -> Option<&Input::Item>
 	{
 		self.current_item.as_ref()
@@ -115,8 +251,6 @@ where
 	}
 
 	pub fn is_peek_char(&mut self, expected_char: char) -> bool
-	where
-		Input::Item: UnitCodePointExt,
 	{
 		self.peek_next()
 			.filter(|item| item.is(expected_char))
@@ -124,8 +258,6 @@ where
 	}
 
 	pub fn is_peek_chars(&mut self, rng_chars: RangeInclusive<char>) -> bool
-	where
-		Input::Item: UnitCodePointExt,
 	{
 		self.peek_next()
 			.filter(|item| rng_chars.contains(&item.unit()))
@@ -136,8 +268,6 @@ where
 		&mut self,
 		expected_item: Input::Item,
 	) -> Option<Input::Item>
-	where
-		Input::Item: UnitCodePointExt,
 	{
 		self.peek_next()
 			.filter(|item| item.is(expected_item.unit()))
@@ -145,43 +275,105 @@ where
 
 	pub fn peek_next(&mut self) -> Option<Input::Item>
 	{
-		self.fill(self.look_ahead_offset);
-		self.temporary_list
-			.get(self.look_ahead_offset as usize)
-			.cloned()
+		self.peek_n(1).first().cloned()
 	}
 
-	pub fn peek_n(&mut self, n: usize) -> Option<String>
-	where
-		Input::Item: UnitCodePointExt,
+	/// Les `n` prochains éléments, sans les consommer (moins de `n` à la fin
+	/// du flux).
+	pub fn peek_n(&mut self, n: usize) -> &[Input::Item]
 	{
-		if self.should_continue_peek == 0 {
-			self.fill(n as u8);
-		}
+		let available = self.fill(n);
+		let cursor = self.cursor();
+		&self.buffer.make_contiguous()[cursor..cursor + available]
+	}
 
-		self.should_continue_peek = n;
+	/// Vérifie que les prochains éléments correspondent à la séquence, sans
+	/// les consommer.
+	pub fn starts_with(&mut self, sequence: &str) -> bool
+	{
+		self.starts_with_by(sequence, |item, ch| item.unit() == ch)
+	}
 
-		let stru = self
-			.temporary_list
-			.iter()
-			.take(n)
-			.map(|item| item.unit())
-			.collect::<String>();
+	/// Variante de [InputStream::starts_with] insensible à la casse ASCII, ex:
+	/// pour `DOCTYPE`.
+	pub fn starts_with_ignore_ascii_case(&mut self, sequence: &str) -> bool
+	{
+		self.starts_with_by(sequence, |item, ch| {
+			item.unit().eq_ignore_ascii_case(&ch)
+		})
+	}
 
-		Option::from(stru)
+	fn starts_with_by(
+		&mut self,
+		sequence: &str,
+		eq: impl Fn(&Input::Item, char) -> bool,
+	) -> bool
+	{
+		let count = sequence.chars().count();
+		let items = self.peek_n(count);
+		items.len() == count
+			&& items
+				.iter()
+				.zip(sequence.chars())
+				.all(|(item, ch)| eq(item, ch))
 	}
 
-	pub fn reset_peek(&mut self)
+	/// Revient au point de reprise, qui est abandonné (ainsi que ceux créés
+	/// après lui).
+	///
+	/// Retourne `false`, sans modifier le flux, si le point de reprise a déjà
+	/// été abandonné ou s'il appartient à un autre flux.
+	#[must_use]
+	pub fn reset(&mut self, checkpoint: InputCheckpoint) -> bool
 	{
-		self.should_continue_peek = 0;
+		if !self.is_active(&checkpoint) {
+			return false;
+		}
+		self.checkpoints.truncate(checkpoint.depth);
+		self.rewind(checkpoint.index);
+		true
 	}
 
-	pub fn rollback_once(&mut self)
+	/// Éléments consommés depuis le point de reprise.
+	pub fn consumed_since(
+		&mut self,
+		checkpoint: &InputCheckpoint,
+	) -> &[Input::Item]
 	{
-		let Some(last) = self.recycle_list.pop() else {
-			return;
-		};
-		self.temporary_list.push_front(last);
+		let start = checkpoint.index - self.buffer_offset;
+		let cursor = self.cursor();
+		&self.buffer.make_contiguous()[start..cursor]
+	}
+
+	/// Remet le dernier élément consommé dans le flux.
+	///
+	/// Seuls les [HISTORY_LENGTH] derniers éléments consommés (et ceux qui
+	/// suivent un point de reprise actif) sont conservés: au-delà, ou si rien
+	/// n'a été consommé, l'appel retourne `false` sans modifier le flux.
+	#[must_use]
+	pub fn rollback_once(&mut self) -> bool
+	{
+		// NOTE: le premier élément du tampon n'est restituable qu'au début du
+		// flux, sinon il ne sert que d'élément courant.
+		let first_restorable = usize::from(self.buffer_offset > 0);
+		if self.cursor() <= first_restorable {
+			return false;
+		}
+		self.rewind(self.index - 1);
+		true
+	}
+
+	/// Revient à l'élément d'index donné, encore présent dans le tampon.
+	fn rewind(&mut self, index: usize)
+	{
+		self.index = index;
+		self.current_item = self.previous_item();
+	}
+
+	fn previous_item(&self) -> Option<Input::Item>
+	{
+		let cursor = self.cursor().checked_sub(1)?;
+		self.buffer.get(cursor).cloned()
 	}
 }
 
@@ -303,11 +495,193 @@ mod tests
 		assert_eq!(input_stream.consume_next(), Some('i'));
 		assert_eq!(input_stream.consume_next(), Some('S'));
 
-		input_stream.rollback_once(); // NOTE: effet
-		input_stream.rollback_once(); // NOTE: effet
+		assert!(input_stream.rollback_once());
+		assert!(input_stream.rollback_once());
 		assert_eq!(input_stream.consume_next(), Some('i'));
 		assert_eq!(input_stream.consume_next(), Some('S'));
 		assert_eq!(input_stream.consume_next(), Some('y'));
 		assert_eq!(input_stream.consume_next(), Some('X'));
+
+		let mut input_stream = InputStream::new("ab".chars());
+		assert!(!input_stream.rollback_once());
+		assert_eq!(input_stream.consume_next(), Some('a'));
+	}
+
+	#[test]
+	fn test_rollback_beyond_history()
+	{
+		let source = "0123456789";
+		let mut input_stream = InputStream::new(source.chars());
+		input_stream.advance(9);
+
+		for _ in 0..HISTORY_LENGTH {
+			assert!(input_stream.rollback_once());
+		}
+		assert!(!input_stream.rollback_once());
+		assert_eq!(input_stream.current(), Some(&'1'));
+		assert_eq!(input_stream.position().offset(), 2);
+		assert_eq!(input_stream.consume_next(), Some('2'));
+
+		// NOTE: un point de reprise actif conserve les éléments qui le
+		// suivent.
+		let mut input_stream = InputStream::new(source.chars());
+		let checkpoint = input_stream.mark();
+		input_stream.advance(9);
+		for _ in 0..10 {
+			assert!(input_stream.rollback_once());
+		}
+		assert!(!input_stream.rollback_once());
+		input_stream.release(checkpoint);
+	}
+
+	#[test]
+	fn test_peek_n()
+	{
+		let source = "a".repeat(300) + "b";
+		let mut input_stream = InputStream::new(source.chars());
+		assert_eq!(input_stream.consume_next(), Some('a'));
+
+		let peeked = input_stream.peek_n(300);
+		assert_eq!(peeked.len(), 300);
+		assert_eq!(peeked.last(), Some(&'b'));
+		assert_eq!(input_stream.peek_n(1000).len(), 300);
+		assert_eq!(input_stream.consume_next(), Some('a'));
+	}
+
+	#[test]
+	fn test_checkpoints()
+	{
+		let mut input_stream = InputStream::new("<!DOCTYPE html>".chars());
+		let outer = input_stream.mark();
+		assert_eq!(input_stream.consume_next(), Some('<'));
+
+		let inner = input_stream.mark();
+		assert_eq!(input_stream.consume_while(|ch| *ch != ' ').len(), 8);
+		assert!(input_stream.reset(inner));
+		assert_eq!(input_stream.current(), Some(&'<'));
+		assert_eq!(input_stream.consume_if(|ch| *ch == '!'), Some('!'));
+		assert_eq!(input_stream.consume_if(|ch| *ch == '!'), None);
+
+		input_stream.advance(20);
+		assert!(input_stream.reset(outer));
+		assert_eq!(input_stream.current(), None);
+		assert_eq!(input_stream.consume_next(), Some('<'));
+	}
+
+	#[test]
+	fn test_reset_invalid_checkpoint()
+	{
+		let mut input_stream = InputStream::new(SOURCE.chars());
+		let outer = input_stream.mark();
+		let inner = input_stream.mark();
+		input_stream.advance(2);
+		input_stream.release(outer);
+		assert!(!input_stream.reset(inner));
+		assert_eq!(input_stream.current(), Some(&'l'));
+
+		let mut other_stream = InputStream::new(SOURCE.chars());
+		let foreign = other_stream.mark();
+		let checkpoint = input_stream.mark();
+		input_stream.release(foreign);
+		assert_eq!(input_stream.consume_next(), Some('l'));
+		assert!(input_stream.reset(checkpoint));
+		assert_eq!(input_stream.current(), Some(&'l'));
+		assert_eq!(input_stream.position().offset(), 3);
+
+		let checkpoint = other_stream.mark();
+		assert!(!input_stream.reset(checkpoint));
+		assert_eq!(input_stream.current(), Some(&'l'));
+	}
+
+	#[test]
+	fn test_starts_with()
+	{
+		let mut input_stream = InputStream::new("<![cdata[x".chars());
+		input_stream.consume_next();
+		assert!(input_stream.starts_with("![cdata["));
+		assert!(!input_stream.starts_with("![CDATA["));
+		assert!(input_stream.starts_with_ignore_ascii_case("![CDATA["));
+		assert!(!input_stream.starts_with("![cdata[xy"));
+		assert_eq!(input_stream.consume_next(), Some('!'));
+	}
+
+	#[test]
+	fn test_bounded_memory()
+	{
+		let source = "x".repeat(10_000);
+		let mut input_stream = InputStream::new(source.chars());
+		while input_stream.consume_next().is_some() {}
+		assert!(input_stream.buffer.len() <= HISTORY_LENGTH + 1);
+
+		let mut input_stream = InputStream::new(source.chars());
+		let checkpoint = input_stream.mark();
+		input_stream.advance(999);
+		assert_eq!(input_stream.buffer.len(), 1000);
+		input_stream.release(checkpoint);
+		assert_eq!(input_stream.buffer.len(), HISTORY_LENGTH + 1);
+	}
+
+	#[test]
+	fn test_position()
+	{
+		let mut input_stream = InputStream::new("é\r\nb".chars());
+		input_stream.advance(1);
+		let position = input_stream.position();
+		assert_eq!(position.offset(), 4);
+		assert_eq!(position.char_offset(), 3);
+		assert_eq!((position.line(), position.column()), (2, 1));
+
+		assert!(input_stream.rollback_once());
+		assert_eq!(input_stream.position().offset(), 2);
+		assert_eq!(input_stream.position().line(), 1);
+
+		let mut input_stream = InputStream::new("é".bytes());
+		input_stream.consume_next();
+		assert_eq!(input_stream.position().offset(), 1);
+	}
+
+	#[test]
+	fn test_newline_normalization()
+	{
+		let mut input_stream = InputStream::new("a\r\nb\rc\n".chars());
+		assert!(input_stream.starts_with("a\nb\nc"));
+		assert_eq!(
+			input_stream.consume_while(|_| true),
+			['a', '\n', 'b', '\n', 'c', '\n']
+		);
+		assert_eq!(input_stream.position().offset(), 7);
+		assert_eq!(input_stream.position().line(), 4);
+
+		for _ in 0..3 {
+			assert!(input_stream.rollback_once());
+		}
+		assert_eq!(input_stream.current(), Some(&'b'));
+		assert_eq!(input_stream.position().offset(), 4);
+
+		let mut input_stream = InputStream::new("\r\r\n".bytes());
+		assert_eq!(input_stream.consume_while(|_| true), [b'\n', b'\n']);
+		assert_eq!(input_stream.position().offset(), 3);
+		assert_eq!(input_stream.position().line(), 3);
+	}
+
+	#[test]
+	fn test_location_since()
+	{
+		let mut input_stream = InputStream::new("<p>\nBonjour".chars());
+		input_stream.advance(3);
+		let checkpoint = input_stream.mark();
+		input_stream.advance(6);
+		assert_eq!(
+			input_stream.consumed_since(&checkpoint),
+			['B', 'o', 'n', 'j', 'o', 'u', 'r']
+		);
+
+		let location = input_stream.location_since(&checkpoint);
+		assert_eq!(location.range(), 4..11);
+		assert_eq!((location.line(), location.column()), (2, 1));
+
+		assert!(input_stream.reset(checkpoint));
+		assert_eq!(input_stream.position().offset(), 4);
+		assert_eq!(input_stream.current(), Some(&'\n'));
 	}
 }
@@ -8,6 +8,8 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+mod decoder;
 mod input;
 
-pub use self::input::InputStream;
+pub use self::decoder::{ReadBytes, Utf8Decoder};
+pub use self::input::{InputCheckpoint, InputStream};